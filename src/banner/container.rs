@@ -3,7 +3,7 @@ use crate::{
     config::Configuration,
     event_handlers::Handles,
     utils::{logged_request, status_colorizer},
-    VERSION,
+    DEFAULT_MAX_BODY_SIZE, SIMILARITY_THRESHOLD, VERSION,
 };
 use anyhow::{bail, Result};
 use console::{style, Emoji};
@@ -42,11 +42,17 @@ pub struct Banner {
     threads: BannerEntry,
 
     /// represents Configuration.wordlist
-    wordlist: BannerEntry,
+    wordlist: Vec<BannerEntry>,
 
     /// represents Configuration.timeout
     timeout: BannerEntry,
 
+    /// represents Configuration.connect_timeout
+    connect_timeout: BannerEntry,
+
+    /// represents Configuration.read_timeout
+    read_timeout: BannerEntry,
+
     /// represents Configuration.user_agent
     user_agent: BannerEntry,
 
@@ -56,9 +62,18 @@ pub struct Banner {
     /// represents Configuration.proxy
     proxy: BannerEntry,
 
+    /// represents Configuration.proxy_all
+    proxy_all: BannerEntry,
+
     /// represents Configuration.replay_proxy
     replay_proxy: BannerEntry,
 
+    /// represents Configuration.notify_url
+    notify_url: BannerEntry,
+
+    /// represents Configuration.notify_on
+    notify_on: BannerEntry,
+
     /// represents Configuration.replay_codes
     replay_codes: BannerEntry,
 
@@ -71,6 +86,9 @@ pub struct Banner {
     /// represents Configuration.filter_similar
     filter_similar: Vec<BannerEntry>,
 
+    /// represents Configuration.similarity_threshold
+    similarity_threshold: BannerEntry,
+
     /// represents Configuration.filter_word_count
     filter_word_count: Vec<BannerEntry>,
 
@@ -80,12 +98,24 @@ pub struct Banner {
     /// represents Configuration.filter_regex
     filter_regex: Vec<BannerEntry>,
 
+    /// represents Configuration.match_status, match_size, match_word_count, and match_line_count
+    matchers: Vec<BannerEntry>,
+
     /// represents Configuration.extract_links
     extract_links: BannerEntry,
 
+    /// represents Configuration.collect_intel
+    collect_intel: BannerEntry,
+
+    /// represents Configuration.decode_slashes
+    decode_slashes: BannerEntry,
+
     /// represents Configuration.json
     json: BannerEntry,
 
+    /// represents Configuration.output_format
+    output_format: BannerEntry,
+
     /// represents Configuration.output
     output: BannerEntry,
 
@@ -95,15 +125,39 @@ pub struct Banner {
     /// represents Configuration.extensions
     extensions: BannerEntry,
 
+    /// represents Configuration.transform_case
+    transform_case: BannerEntry,
+
     /// represents Configuration.insecure
     insecure: BannerEntry,
 
+    /// represents Configuration.insecure_hostname_mismatch
+    insecure_hostname_mismatch: BannerEntry,
+
+    /// represents Configuration.http_version
+    http_version: BannerEntry,
+
+    /// represents Configuration.basic_auth
+    basic_auth: BannerEntry,
+
+    /// represents Configuration.bearer
+    bearer: BannerEntry,
+
     /// represents Configuration.redirects
     redirects: BannerEntry,
 
     /// represents Configuration.dont_filter
     dont_filter: BannerEntry,
 
+    /// represents Configuration.ignore_wildcard_warning
+    ignore_wildcard_warning: BannerEntry,
+
+    /// represents Configuration.auto_filter
+    auto_filter: BannerEntry,
+
+    /// represents Configuration.redirects_only
+    redirects_only: BannerEntry,
+
     /// represents Configuration.queries
     queries: Vec<BannerEntry>,
 
@@ -116,6 +170,9 @@ pub struct Banner {
     /// represents Configuration.no_recursion
     no_recursion: BannerEntry,
 
+    /// represents Configuration.dont_recurse_path
+    dont_recurse_path: Vec<BannerEntry>,
+
     /// represents Configuration.scan_limit
     scan_limit: BannerEntry,
 
@@ -125,6 +182,12 @@ pub struct Banner {
     /// represents Configuration.rate_limit
     rate_limit: BannerEntry,
 
+    /// represents Configuration.max_requests_per_dir
+    max_requests_per_dir: BannerEntry,
+
+    /// represents Configuration.max_body_size
+    max_body_size: BannerEntry,
+
     /// represents Configuration.parallel
     parallel: BannerEntry,
 
@@ -134,9 +197,18 @@ pub struct Banner {
     /// represents Configuration.auto_bail
     auto_bail: BannerEntry,
 
+    /// represents Configuration.auto_throttle
+    auto_throttle: BannerEntry,
+
+    /// represents Configuration.respect_crawl_delay
+    respect_crawl_delay: BannerEntry,
+
     /// represents Configuration.url_denylist
     url_denylist: Vec<BannerEntry>,
 
+    /// represents Configuration.dont_scan_case_sensitive
+    dont_scan_case_sensitive: BannerEntry,
+
     /// current version of feroxbuster
     pub(super) version: String,
 
@@ -158,7 +230,9 @@ impl Banner {
         let mut filter_word_count = Vec::new();
         let mut filter_line_count = Vec::new();
         let mut filter_regex = Vec::new();
+        let mut matchers = Vec::new();
         let mut queries = Vec::new();
+        let mut dont_recurse_path = Vec::new();
 
         for target in tgts {
             targets.push(BannerEntry::new("🎯", "Target Url", target));
@@ -229,6 +303,40 @@ impl Banner {
             filter_regex.push(BannerEntry::new("💢", "Regex Filter", filter));
         }
 
+        if !config.match_status.is_empty() {
+            let codes: Vec<_> = config
+                .match_status
+                .iter()
+                .map(|code| status_colorizer(&code.to_string()))
+                .collect();
+
+            matchers.push(BannerEntry::new(
+                "🧲",
+                "Status Code Matchers",
+                &format!("[{}]", codes.join(", ")),
+            ));
+        }
+
+        for matcher in &config.match_size {
+            matchers.push(BannerEntry::new("🧲", "Size Matcher", &matcher.to_string()));
+        }
+
+        for matcher in &config.match_word_count {
+            matchers.push(BannerEntry::new(
+                "🧲",
+                "Word Count Matcher",
+                &matcher.to_string(),
+            ));
+        }
+
+        for matcher in &config.match_line_count {
+            matchers.push(BannerEntry::new(
+                "🧲",
+                "Line Count Matcher",
+                &matcher.to_string(),
+            ));
+        }
+
         for query in &config.queries {
             queries.push(BannerEntry::new(
                 "🤔",
@@ -237,6 +345,10 @@ impl Banner {
             ));
         }
 
+        for glob in &config.dont_recurse_path {
+            dont_recurse_path.push(BannerEntry::new("🚫", "Don't Recurse Into", glob));
+        }
+
         let volume = ["🔈", "🔉", "🔊", "📢"];
         let verbosity = if let 1..=4 = config.verbosity {
             //speaker medium volume (increasing with verbosity to loudspeaker)
@@ -268,17 +380,55 @@ impl Banner {
         );
 
         let replay_proxy = BannerEntry::new("🎥", "Replay Proxy", &config.replay_proxy);
+        let notify_url = BannerEntry::new("🔔", "Notify Url", &config.notify_url);
+        let notify_on = BannerEntry::new(
+            "🔔",
+            "Notify On",
+            &format!("[{}]", config.notify_on.join(", ")),
+        );
         let auto_tune = BannerEntry::new("🎶", "Auto Tune", &config.auto_tune.to_string());
         let auto_bail = BannerEntry::new("🪣", "Auto Bail", &config.auto_bail.to_string());
+        let auto_throttle =
+            BannerEntry::new("🐢", "Auto Throttle", &config.auto_throttle.to_string());
+        let respect_crawl_delay = BannerEntry::new(
+            "🐌",
+            "Respect Crawl-delay",
+            &config.respect_crawl_delay.to_string(),
+        );
         let cfg = BannerEntry::new("💉", "Config File", &config.config);
         let proxy = BannerEntry::new("💎", "Proxy", &config.proxy);
+        let proxy_all = BannerEntry::new("🔒", "Proxy All Requests", &config.proxy_all.to_string());
         let threads = BannerEntry::new("🚀", "Threads", &config.threads.to_string());
-        let wordlist = BannerEntry::new("📖", "Wordlist", &config.wordlist);
+        let wordlist = config
+            .wordlist
+            .iter()
+            .map(|path| BannerEntry::new("📖", "Wordlist", path))
+            .collect();
         let timeout = BannerEntry::new("💥", "Timeout (secs)", &config.timeout.to_string());
+        let connect_timeout = BannerEntry::new(
+            "💥",
+            "Connect Timeout (secs)",
+            &config.timeouts().connect.to_string(),
+        );
+        let read_timeout = BannerEntry::new(
+            "💥",
+            "Read Timeout (secs)",
+            &config.timeouts().read.to_string(),
+        );
         let user_agent = BannerEntry::new("🦡", "User-Agent", &config.user_agent);
         let extract_links =
             BannerEntry::new("🔎", "Extract Links", &config.extract_links.to_string());
+        let collect_intel =
+            BannerEntry::new("🕵", "Collect Intel", &config.collect_intel.to_string());
+        let decode_slashes =
+            BannerEntry::new("🔓", "Decode Slashes", &config.decode_slashes.to_string());
         let json = BannerEntry::new("🧔", "JSON Output", &config.json.to_string());
+        let similarity_threshold = BannerEntry::new(
+            "💢",
+            "Similarity Threshold (%)",
+            &config.similarity_threshold.to_string(),
+        );
+        let output_format = BannerEntry::new("📑", "Output Format", &config.output_format);
         let output = BannerEntry::new("💾", "Output File", &config.output);
         let debug_log = BannerEntry::new("🪲", "Debugging Log", &config.debug_log);
         let extensions = BannerEntry::new(
@@ -286,15 +436,59 @@ impl Banner {
             "Extensions",
             &format!("[{}]", config.extensions.join(", ")),
         );
+        let transform_case = BannerEntry::new(
+            "🔠",
+            "Transform Case",
+            &format!("[{}]", config.transform_case.join(", ")),
+        );
         let insecure = BannerEntry::new("🔓", "Insecure", &config.insecure.to_string());
+        let insecure_hostname_mismatch = BannerEntry::new(
+            "🔏",
+            "Insecure Hostname Mismatch",
+            &config.insecure_hostname_mismatch.to_string(),
+        );
+        let http_version = BannerEntry::new("🌐", "HTTP Version", &config.http_version);
+
+        // only the username is shown, the password/token never is
+        let username = config
+            .basic_auth
+            .as_str()
+            .split(':')
+            .next()
+            .unwrap_or_default();
+        let basic_auth = BannerEntry::new("🔑", "Basic Auth", &format!("{}:********", username));
+        let bearer = BannerEntry::new("🔑", "Bearer Token", "********");
         let redirects = BannerEntry::new("📍", "Follow Redirects", &config.redirects.to_string());
         let dont_filter =
             BannerEntry::new("🤪", "Filter Wildcards", &(!config.dont_filter).to_string());
+        let ignore_wildcard_warning = BannerEntry::new(
+            "🙈",
+            "Ignore Wildcard Warning",
+            &config.ignore_wildcard_warning.to_string(),
+        );
+        let auto_filter = BannerEntry::new("🎲", "Auto Filter", &config.auto_filter.to_string());
+        let redirects_only =
+            BannerEntry::new("🔀", "Redirects Only", &config.redirects_only.to_string());
+        let dont_scan_case_sensitive = BannerEntry::new(
+            "🔠",
+            "Don't Scan Case Sensitive",
+            &config.dont_scan_case_sensitive.to_string(),
+        );
         let add_slash = BannerEntry::new("🪓", "Add Slash", &config.add_slash.to_string());
         let time_limit = BannerEntry::new("🕖", "Time Limit", &config.time_limit);
         let parallel = BannerEntry::new("🛤", "Parallel Scans", &config.parallel.to_string());
         let rate_limit =
             BannerEntry::new("🚧", "Requests per Second", &config.rate_limit.to_string());
+        let max_requests_per_dir = BannerEntry::new(
+            "🧱",
+            "Max Requests per Directory",
+            &config.max_requests_per_dir.to_string(),
+        );
+        let max_body_size = BannerEntry::new(
+            "📦",
+            "Max Body Size (bytes)",
+            &config.max_body_size.to_string(),
+        );
 
         Self {
             targets,
@@ -303,35 +497,59 @@ impl Banner {
             wordlist,
             filter_status,
             timeout,
+            connect_timeout,
+            read_timeout,
             user_agent,
             auto_bail,
             auto_tune,
+            auto_throttle,
+            respect_crawl_delay,
             proxy,
+            proxy_all,
             replay_codes,
             replay_proxy,
+            notify_url,
+            notify_on,
             headers,
             filter_size,
             filter_similar,
+            similarity_threshold,
             filter_word_count,
             filter_line_count,
             filter_regex,
+            matchers,
             extract_links,
+            collect_intel,
+            decode_slashes,
             parallel,
             json,
+            output_format,
             queries,
             output,
             debug_log,
             extensions,
+            transform_case,
             insecure,
+            insecure_hostname_mismatch,
+            http_version,
+            basic_auth,
+            bearer,
             dont_filter,
+            ignore_wildcard_warning,
+            auto_filter,
+            redirects_only,
             redirects,
             verbosity,
             add_slash,
             no_recursion,
+            dont_recurse_path,
             rate_limit,
+            max_requests_per_dir,
+            max_body_size,
             scan_limit,
             time_limit,
             url_denylist,
+            dont_scan_case_sensitive,
             config: cfg,
             version: VERSION.to_string(),
             update_status: UpdateStatus::Unknown,
@@ -426,8 +644,14 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", denied_url)?;
         }
 
+        if config.dont_scan_case_sensitive {
+            writeln!(&mut writer, "{}", self.dont_scan_case_sensitive)?;
+        }
+
         writeln!(&mut writer, "{}", self.threads)?;
-        writeln!(&mut writer, "{}", self.wordlist)?;
+        for wordlist in &self.wordlist {
+            writeln!(&mut writer, "{}", wordlist)?;
+        }
         writeln!(&mut writer, "{}", self.status_codes)?;
 
         if !config.filter_status.is_empty() {
@@ -437,6 +661,14 @@ by Ben "epi" Risher {}                 ver: {}"#,
         }
 
         writeln!(&mut writer, "{}", self.timeout)?;
+
+        if config.connect_timeout > 0 || config.read_timeout > 0 {
+            // only interesting when they differ from --timeout; show both so it's clear which
+            // one fell back to --timeout
+            writeln!(&mut writer, "{}", self.connect_timeout)?;
+            writeln!(&mut writer, "{}", self.read_timeout)?;
+        }
+
         writeln!(&mut writer, "{}", self.user_agent)?;
 
         // followed by the maybe printed or variably displayed values
@@ -448,6 +680,10 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.proxy)?;
         }
 
+        if config.proxy_all {
+            writeln!(&mut writer, "{}", self.proxy_all)?;
+        }
+
         if !config.replay_proxy.is_empty() {
             // i include replay codes logic here because in config.rs, replay codes are set to the
             // value in status codes, meaning it's never empty
@@ -455,10 +691,26 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.replay_codes)?;
         }
 
+        if !config.notify_url.is_empty() {
+            writeln!(&mut writer, "{}", self.notify_url)?;
+
+            if !config.notify_on.is_empty() {
+                writeln!(&mut writer, "{}", self.notify_on)?;
+            }
+        }
+
         for header in &self.headers {
             writeln!(&mut writer, "{}", header)?;
         }
 
+        if !config.basic_auth.is_empty() {
+            writeln!(&mut writer, "{}", self.basic_auth)?;
+        }
+
+        if !config.bearer.is_empty() {
+            writeln!(&mut writer, "{}", self.bearer)?;
+        }
+
         for filter in &self.filter_size {
             writeln!(&mut writer, "{}", filter)?;
         }
@@ -467,6 +719,11 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", filter)?;
         }
 
+        if !config.filter_similar.is_empty() && config.similarity_threshold != SIMILARITY_THRESHOLD
+        {
+            writeln!(&mut writer, "{}", self.similarity_threshold)?;
+        }
+
         for filter in &self.filter_word_count {
             writeln!(&mut writer, "{}", filter)?;
         }
@@ -479,14 +736,31 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", filter)?;
         }
 
+        for matcher in &self.matchers {
+            writeln!(&mut writer, "{}", matcher)?;
+        }
+
         if config.extract_links {
             writeln!(&mut writer, "{}", self.extract_links)?;
         }
 
+        if config.collect_intel {
+            writeln!(&mut writer, "{}", self.collect_intel)?;
+        }
+
+        if config.decode_slashes {
+            writeln!(&mut writer, "{}", self.decode_slashes)?;
+        }
+
         if config.json {
             writeln!(&mut writer, "{}", self.json)?;
         }
 
+        if !matches!(config.output_format.as_str(), "text" | "json") {
+            // json already has its own entry
+            writeln!(&mut writer, "{}", self.output_format)?;
+        }
+
         for query in &self.queries {
             writeln!(&mut writer, "{}", query)?;
         }
@@ -503,8 +777,19 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.extensions)?;
         }
 
+        if !config.transform_case.is_empty() {
+            writeln!(&mut writer, "{}", self.transform_case)?;
+        }
+
         if config.insecure {
             writeln!(&mut writer, "{}", self.insecure)?;
+        } else if config.insecure_hostname_mismatch {
+            // --insecure already skips the hostname check, no need to show both
+            writeln!(&mut writer, "{}", self.insecure_hostname_mismatch)?;
+        }
+
+        if config.http_version != "auto" {
+            writeln!(&mut writer, "{}", self.http_version)?;
         }
 
         if config.auto_bail {
@@ -513,6 +798,12 @@ by Ben "epi" Risher {}                 ver: {}"#,
         if config.auto_tune {
             writeln!(&mut writer, "{}", self.auto_tune)?;
         }
+        if config.auto_throttle {
+            writeln!(&mut writer, "{}", self.auto_throttle)?;
+        }
+        if config.respect_crawl_delay {
+            writeln!(&mut writer, "{}", self.respect_crawl_delay)?;
+        }
 
         if config.redirects {
             writeln!(&mut writer, "{}", self.redirects)?;
@@ -522,6 +813,18 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.dont_filter)?;
         }
 
+        if config.ignore_wildcard_warning {
+            writeln!(&mut writer, "{}", self.ignore_wildcard_warning)?;
+        }
+
+        if config.auto_filter {
+            writeln!(&mut writer, "{}", self.auto_filter)?;
+        }
+
+        if config.redirects_only {
+            writeln!(&mut writer, "{}", self.redirects_only)?;
+        }
+
         if let 1..=4 = config.verbosity {
             writeln!(&mut writer, "{}", self.verbosity)?;
         }
@@ -532,6 +835,10 @@ by Ben "epi" Risher {}                 ver: {}"#,
 
         writeln!(&mut writer, "{}", self.no_recursion)?;
 
+        for glob in &self.dont_recurse_path {
+            writeln!(&mut writer, "{}", glob)?;
+        }
+
         if config.scan_limit > 0 {
             writeln!(&mut writer, "{}", self.scan_limit)?;
         }
@@ -544,6 +851,14 @@ by Ben "epi" Risher {}                 ver: {}"#,
             writeln!(&mut writer, "{}", self.rate_limit)?;
         }
 
+        if config.max_requests_per_dir > 0 {
+            writeln!(&mut writer, "{}", self.max_requests_per_dir)?;
+        }
+
+        if config.max_body_size != DEFAULT_MAX_BODY_SIZE {
+            writeln!(&mut writer, "{}", self.max_body_size)?;
+        }
+
         if !config.time_limit.is_empty() {
             writeln!(&mut writer, "{}", self.time_limit)?;
         }
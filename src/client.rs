@@ -1,34 +1,208 @@
-use anyhow::Result;
-use reqwest::header::HeaderMap;
+use anyhow::{bail, Result};
+use native_tls::TlsConnector;
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT_ENCODING, AUTHORIZATION};
 use reqwest::{redirect::Policy, Client, Proxy};
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::convert::TryInto;
+use std::future::Future;
 use std::time::Duration;
 
+/// maximum number of redirects followed for a single request when --redirects is used
+const MAX_REDIRECTS: usize = 10;
+
+tokio::task_local! {
+    /// number of redirects followed by the most recent request sent from within
+    /// `with_redirect_hops`; the client's redirect policy runs while the request's future is
+    /// polled, i.e. in the task that sent the request, so each request gets its own count
+    static REDIRECT_HOPS: Cell<usize>;
+}
+
+/// run the given future, which sends a request, returning its output along with the number of
+/// redirects the client followed to get the response
+///
+/// the count is 0 when no redirects were followed (or the client doesn't follow them), and is
+/// only meaningful when the response's url differs from the one requested
+pub async fn with_redirect_hops<F: Future>(future: F) -> (F::Output, usize) {
+    REDIRECT_HOPS
+        .scope(Cell::new(0), async move {
+            let output = future.await;
+            (output, REDIRECT_HOPS.with(Cell::get))
+        })
+        .await
+}
+
+/// redirect policy used when --redirects is enabled; follows up to `MAX_REDIRECTS` redirects
+/// while recording the length of the chain, so the final response can report its hop count
+fn follow_redirects_policy() -> Policy {
+    let limited = Policy::limited(MAX_REDIRECTS);
+
+    Policy::custom(move |attempt| {
+        // previous holds every url visited so far, including the one that was requested; there's
+        // nothing to record into when the request wasn't sent from within with_redirect_hops
+        let visited = attempt.previous().len();
+        REDIRECT_HOPS
+            .try_with(|hops| hops.set(visited))
+            .unwrap_or_default();
+
+        limited.redirect(attempt)
+    })
+}
+
+/// How much of a server's TLS certificate is verified before a request is sent
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TlsVerification {
+    /// the certificate chain and its hostname are both verified
+    Full,
+
+    /// the certificate chain is verified, but its hostname doesn't need to match the target's
+    /// (--insecure-hostname-mismatch)
+    AcceptInvalidHostnames,
+
+    /// nothing is verified (--insecure)
+    AcceptInvalidCerts,
+}
+
+/// Number of seconds the client waits before giving up on a request
+///
+/// a single number of seconds converts into a `Timeouts` where both values are the same, which is
+/// how --timeout has always behaved
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Timeouts {
+    /// seconds allowed for establishing a connection (--connect-timeout)
+    pub connect: u64,
+
+    /// seconds allowed for the entire request, from sending it to reading the end of the
+    /// response's body (--read-timeout)
+    pub read: u64,
+}
+
+/// implementation of From<u64> for Timeouts
+impl From<u64> for Timeouts {
+    /// use the same number of seconds for both timeouts
+    fn from(seconds: u64) -> Self {
+        Self {
+            connect: seconds,
+            read: seconds,
+        }
+    }
+}
+
+/// Credentials that are sent as a default `Authorization` header with each request
+///
+/// Debug is purposefully not derived, so the credentials can't end up in a log message
+#[derive(Clone, Copy)]
+pub enum Auth<'a> {
+    /// `user:pass` (--basic-auth), base64 encoded before being sent
+    Basic(&'a str),
+
+    /// token (--bearer), sent as-is
+    Bearer(&'a str),
+}
+
+/// given the user's credentials, create the value of an `Authorization` header
+///
+/// the value is marked as sensitive, which keeps it out of the client's Debug output
+fn authorization_header(auth: Auth) -> Result<HeaderValue> {
+    let raw = match auth {
+        Auth::Basic(credentials) => {
+            // a username w/o a password is still valid, but it needs the trailing colon
+            let credentials = if credentials.contains(':') {
+                credentials.to_string()
+            } else {
+                format!("{}:", credentials)
+            };
+
+            format!("Basic {}", base64::encode(credentials))
+        }
+        Auth::Bearer(token) => format!("Bearer {}", token),
+    };
+
+    let mut value = HeaderValue::from_str(&raw)?;
+    value.set_sensitive(true);
+
+    Ok(value)
+}
+
 /// Create and return an instance of [reqwest::Client](https://docs.rs/reqwest/latest/reqwest/struct.Client.html)
+///
+/// `http_version` is one of `auto`, `1.1`, `2`, or `2-prior-knowledge`
+#[allow(clippy::too_many_arguments)]
 pub fn initialize(
-    timeout: u64,
+    timeouts: Timeouts,
     user_agent: &str,
     redirects: bool,
-    insecure: bool,
+    tls: TlsVerification,
+    http_version: &str,
     headers: &HashMap<String, String>,
+    auth: Option<Auth>,
     proxy: Option<&str>,
 ) -> Result<Client> {
     let policy = if redirects {
-        Policy::limited(10)
+        follow_redirects_policy()
     } else {
         Policy::none()
     };
 
-    let header_map: HeaderMap = headers.try_into()?;
+    let mut header_map: HeaderMap = headers.try_into()?;
+
+    if !header_map.contains_key(ACCEPT_ENCODING) {
+        // gzip and brotli are decompressed by the client itself, deflate is handled when the
+        // response's body is read; a user-supplied Accept-Encoding takes precedence
+        header_map.insert(
+            ACCEPT_ENCODING,
+            HeaderValue::from_static("gzip, deflate, br"),
+        );
+    }
+
+    if let Some(credentials) = auth {
+        // --basic-auth/--bearer take precedence over an Authorization header passed via --headers
+        header_map.insert(AUTHORIZATION, authorization_header(credentials)?);
+    }
+
+    let accept_invalid_certs = tls == TlsVerification::AcceptInvalidCerts;
+    let accept_invalid_hostnames = tls != TlsVerification::Full;
 
     let client = Client::builder()
-        .timeout(Duration::new(timeout, 0))
+        .connect_timeout(Duration::new(timeouts.connect, 0))
+        .timeout(Duration::new(timeouts.read, 0))
         .user_agent(user_agent)
-        .danger_accept_invalid_certs(insecure)
+        .danger_accept_invalid_certs(accept_invalid_certs)
+        .danger_accept_invalid_hostnames(accept_invalid_hostnames)
+        .gzip(true)
+        .brotli(true)
         .default_headers(header_map)
         .redirect(policy);
 
+    // TLS connector that only offers the given protocols during the handshake (ALPN)
+    let tls_offering = |protocols: &[&str]| {
+        TlsConnector::builder()
+            .danger_accept_invalid_certs(accept_invalid_certs)
+            .danger_accept_invalid_hostnames(accept_invalid_hostnames)
+            .request_alpns(protocols)
+            .build()
+    };
+
+    let client = match http_version {
+        // no protocols are offered during the TLS handshake, so the server picks its default;
+        // the client can't upgrade to h2 through ALPN w/ the native-tls backend, which means
+        // HTTP/1.1 is spoken unless HTTP/2 is explicitly asked for below
+        "auto" => client,
+        // offer only http/1.1 during the TLS handshake, so the server can't pick anything else
+        "1.1" => client.use_preconfigured_tls(tls_offering(&["http/1.1"])?),
+        "2" => {
+            // offer only h2 during the TLS handshake and speak it as soon as the connection is
+            // up; a server without HTTP/2 support results in an error for each request instead
+            // of silently falling back to HTTP/1.1. Cleartext urls use h2c w/ prior knowledge
+            client
+                .use_preconfigured_tls(tls_offering(&["h2"])?)
+                .http2_prior_knowledge()
+        }
+        // h2 is spoken immediately w/o any negotiation, mostly useful for cleartext h2c targets
+        "2-prior-knowledge" => client.http2_prior_knowledge(),
+        _ => bail!("Unsupported HTTP version: {}", http_version),
+    };
+
     if let Some(some_proxy) = proxy {
         if !some_proxy.is_empty() {
             // it's not an empty string; set the proxy
@@ -49,7 +223,17 @@ mod tests {
     /// create client with a bad proxy, expect panic
     fn client_with_bad_proxy() {
         let headers = HashMap::new();
-        initialize(0, "stuff", true, false, &headers, Some("not a valid proxy")).unwrap();
+        initialize(
+            Timeouts::from(0),
+            "stuff",
+            true,
+            TlsVerification::Full,
+            "auto",
+            &headers,
+            None,
+            Some("not a valid proxy"),
+        )
+        .unwrap();
     }
 
     #[test]
@@ -57,6 +241,158 @@ mod tests {
     fn client_with_good_proxy() {
         let headers = HashMap::new();
         let proxy = "http://127.0.0.1:8080";
-        initialize(0, "stuff", true, true, &headers, Some(proxy)).unwrap();
+        initialize(
+            Timeouts::from(0),
+            "stuff",
+            true,
+            TlsVerification::AcceptInvalidCerts,
+            "auto",
+            &headers,
+            None,
+            Some(proxy),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    /// create a client for each supported http version, expect no error
+    fn client_with_each_http_version() {
+        let headers = HashMap::new();
+
+        for version in &["auto", "1.1", "2", "2-prior-knowledge"] {
+            initialize(
+                Timeouts::from(0),
+                "stuff",
+                false,
+                TlsVerification::Full,
+                version,
+                &headers,
+                None,
+                None,
+            )
+            .unwrap();
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// auto and 1.1 should both speak HTTP/1.1, HTTP/2 is only spoken when asked for
+    async fn client_speaks_requested_http_version() {
+        use httpmock::{Method::GET, MockServer};
+        use reqwest::Version;
+
+        let srv = MockServer::start();
+
+        srv.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(200);
+        });
+
+        for (http_version, expected) in &[
+            ("auto", Version::HTTP_11),
+            ("1.1", Version::HTTP_11),
+            ("2-prior-knowledge", Version::HTTP_2),
+        ] {
+            let client = initialize(
+                Timeouts::from(5),
+                "stuff",
+                false,
+                TlsVerification::Full,
+                http_version,
+                &HashMap::new(),
+                None,
+                None,
+            )
+            .unwrap();
+
+            let response = client.get(&srv.url("/")).send().await.unwrap();
+            assert_eq!(response.version(), *expected, "{}", http_version);
+        }
+    }
+
+    #[test]
+    /// create a client for each level of tls verification, w/ and w/o a preconfigured tls
+    /// connector (http version 2), expect no error
+    fn client_with_each_tls_verification() {
+        let headers = HashMap::new();
+
+        for tls in &[
+            TlsVerification::Full,
+            TlsVerification::AcceptInvalidHostnames,
+            TlsVerification::AcceptInvalidCerts,
+        ] {
+            for version in &["auto", "2"] {
+                initialize(
+                    Timeouts::from(0),
+                    "stuff",
+                    false,
+                    *tls,
+                    version,
+                    &headers,
+                    None,
+                    None,
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    #[test]
+    /// create client with an unsupported http version, expect an error
+    fn client_with_bad_http_version() {
+        let headers = HashMap::new();
+        assert!(initialize(
+            Timeouts::from(0),
+            "stuff",
+            false,
+            TlsVerification::Full,
+            "3",
+            &headers,
+            None,
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    /// a single timeout should apply to both connecting and reading
+    fn timeouts_from_single_value() {
+        assert_eq!(
+            Timeouts::from(7),
+            Timeouts {
+                connect: 7,
+                read: 7
+            }
+        );
+
+        initialize(
+            Timeouts {
+                connect: 3,
+                read: 30,
+            },
+            "stuff",
+            false,
+            TlsVerification::Full,
+            "auto",
+            &HashMap::new(),
+            None,
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    /// basic credentials should be base64 encoded, bearer tokens sent as-is, both marked sensitive
+    fn authorization_header_encodes_credentials() {
+        let basic = authorization_header(Auth::Basic("user:pass")).unwrap();
+        assert_eq!(basic, "Basic dXNlcjpwYXNz");
+        assert!(basic.is_sensitive());
+
+        let no_password = authorization_header(Auth::Basic("user")).unwrap();
+        assert_eq!(no_password, "Basic dXNlcjo=");
+
+        let bearer = authorization_header(Auth::Bearer("token")).unwrap();
+        assert_eq!(bearer, "Bearer token");
+        assert!(bearer.is_sensitive());
+        assert!(!format!("{:?}", bearer).contains("token"));
     }
 }
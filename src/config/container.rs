@@ -1,15 +1,23 @@
 use super::utils::{
-    depth, report_and_exit, save_state, serialized_type, status_codes, threads, timeout,
-    user_agent, wordlist, OutputLevel, RequesterPolicy,
+    build_dont_scan_glob_set, build_glob_set, depth, deserialize_wordlist, http_version,
+    max_body_size, output_format, report_and_exit, save_state, serialized_type,
+    similarity_threshold, status_codes, threads, timeout, user_agent, wordlist, Credential,
+    OutputLevel, RequesterPolicy,
 };
 use crate::config::determine_output_level;
 use crate::config::utils::determine_requester_policy;
 use crate::{
-    client, parser, scan_manager::resume_scan, traits::FeroxSerialize, utils::fmt_err,
+    client::{self, Auth, Timeouts, TlsVerification},
+    event_handlers::NotifyCriterion,
+    parser,
+    scan_manager::resume_scan,
+    traits::FeroxSerialize,
+    utils::fmt_err,
     DEFAULT_CONFIG_NAME,
 };
 use anyhow::{anyhow, Context, Result};
 use clap::{value_t, ArgMatches};
+use globset::GlobSet;
 use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
 use std::{
@@ -61,9 +69,9 @@ pub struct Configuration {
     /// Name of this type of struct, used for serialization, i.e. `{"type":"configuration"}`
     pub kind: String,
 
-    /// Path to the wordlist
-    #[serde(default = "wordlist")]
-    pub wordlist: String,
+    /// Path(s) to the wordlist(s); words from every list are merged and deduplicated
+    #[serde(default = "wordlist", deserialize_with = "deserialize_wordlist")]
+    pub wordlist: Vec<String>,
 
     /// Path to the config file used
     #[serde(default)]
@@ -73,6 +81,11 @@ pub struct Configuration {
     #[serde(default)]
     pub proxy: String,
 
+    /// Send every request through `proxy`, including the ones feroxbuster makes on its own
+    /// behalf (robots.txt, --notify-url), and exit when the proxy can't be reached
+    #[serde(default)]
+    pub proxy_all: bool,
+
     /// Replay Proxy to use for requests (ex: http(s)://host:port, socks5(h)://host:port)
     #[serde(default)]
     pub replay_proxy: String,
@@ -109,6 +122,15 @@ pub struct Configuration {
     #[serde(default = "timeout")]
     pub timeout: u64,
 
+    /// Number of seconds before a connection attempt times out; 0 means use `timeout`
+    #[serde(default)]
+    pub connect_timeout: u64,
+
+    /// Number of seconds before a request, including reading its response, times out; 0 means
+    /// use `timeout`
+    #[serde(default)]
+    pub read_timeout: u64,
+
     /// Level of verbosity, equates to log level
     #[serde(default)]
     pub verbosity: u8,
@@ -133,6 +155,14 @@ pub struct Configuration {
     #[serde(default)]
     pub auto_tune: bool,
 
+    /// pause a scan when the target responds w/ 429/503, honoring any Retry-After header
+    #[serde(default)]
+    pub auto_throttle: bool,
+
+    /// slow requests to a host down to the Crawl-delay found in its robots.txt
+    #[serde(default)]
+    pub respect_crawl_delay: bool,
+
     /// more easily differentiate between the three requester policies
     #[serde(skip)]
     pub requester_policy: RequesterPolicy,
@@ -141,6 +171,10 @@ pub struct Configuration {
     #[serde(default)]
     pub json: bool,
 
+    /// Format used when writing findings to `output`: text, json, or csv
+    #[serde(default = "output_format")]
+    pub output_format: String,
+
     /// Output file to write results to (default: stdout)
     #[serde(default)]
     pub output: String,
@@ -162,14 +196,35 @@ pub struct Configuration {
     #[serde(default)]
     pub insecure: bool,
 
+    /// Accept TLS certificates whose hostname doesn't match the target's, while still
+    /// validating the rest of the certificate
+    #[serde(default)]
+    pub insecure_hostname_mismatch: bool,
+
+    /// HTTP version preference (auto, 1.1, 2, 2-prior-knowledge)
+    #[serde(default = "http_version")]
+    pub http_version: String,
+
     /// File extension(s) to search for
     #[serde(default)]
     pub extensions: Vec<String>,
 
+    /// Case variations to add for each word in the wordlist (lower, upper, capitalize, all)
+    #[serde(default)]
+    pub transform_case: Vec<String>,
+
     /// HTTP headers to be used in each request
     #[serde(default)]
     pub headers: HashMap<String, String>,
 
+    /// Credentials (user:pass) for HTTP Basic authentication
+    #[serde(default)]
+    pub basic_auth: Credential,
+
+    /// Token for HTTP Bearer authentication
+    #[serde(default)]
+    pub bearer: Credential,
+
     /// URL query parameters
     #[serde(default)]
     pub queries: Vec<(String, String)>,
@@ -178,10 +233,26 @@ pub struct Configuration {
     #[serde(default)]
     pub no_recursion: bool,
 
+    /// Glob patterns matched against a directory's path; matching directories aren't recursed into
+    #[serde(default)]
+    pub dont_recurse_path: Vec<String>,
+
+    /// Compiled version of `dont_recurse_path`
+    #[serde(skip)]
+    pub dont_recurse_globs: GlobSet,
+
     /// Extract links from html/javscript
     #[serde(default)]
     pub extract_links: bool,
 
+    /// Report email addresses and html comments found in response bodies
+    #[serde(default)]
+    pub collect_intel: bool,
+
+    /// Decode percent-encoded slashes (%2F) in extracted links before deduplicating them
+    #[serde(default)]
+    pub decode_slashes: bool,
+
     /// Append / to each request
     #[serde(default)]
     pub add_slash: bool,
@@ -206,6 +277,14 @@ pub struct Configuration {
     #[serde(default)]
     pub rate_limit: usize,
 
+    /// Number of requests permitted per directory; a limit of 0 means no limit is imposed
+    #[serde(default)]
+    pub max_requests_per_dir: usize,
+
+    /// Maximum number of bytes read from each response's body; a limit of 0 means no limit
+    #[serde(default = "max_body_size")]
+    pub max_body_size: usize,
+
     /// Filter out messages of a particular size
     #[serde(default)]
     pub filter_size: Vec<u64>,
@@ -222,10 +301,38 @@ pub struct Configuration {
     #[serde(default)]
     pub filter_regex: Vec<String>,
 
+    /// Only report messages w/ one of these status codes
+    #[serde(default)]
+    pub match_status: Vec<u16>,
+
+    /// Only report messages of one of these sizes
+    #[serde(default)]
+    pub match_size: Vec<u64>,
+
+    /// Only report messages w/ one of these word counts
+    #[serde(default)]
+    pub match_word_count: Vec<usize>,
+
+    /// Only report messages w/ one of these line counts
+    #[serde(default)]
+    pub match_line_count: Vec<usize>,
+
     /// Don't auto-filter wildcard responses
     #[serde(default)]
     pub dont_filter: bool,
 
+    /// Don't probe targets for catch-all responses/wildcard dns before scanning them
+    #[serde(default)]
+    pub ignore_wildcard_warning: bool,
+
+    /// Probe each directory before scanning it and filter responses with the probe's status/size
+    #[serde(default)]
+    pub auto_filter: bool,
+
+    /// Only report redirects; 3xx responses and responses reached by following redirects
+    #[serde(default)]
+    pub redirects_only: bool,
+
     /// Scan started from a state file, not from CLI args
     #[serde(default)]
     pub resumed: bool,
@@ -245,13 +352,38 @@ pub struct Configuration {
     #[serde(default)]
     pub time_limit: String,
 
+    /// Print the urls that would be requested, without sending any requests
+    #[serde(default)]
+    pub dry_run: bool,
+
     /// Filter out response bodies that meet a certain threshold of similarity
     #[serde(default)]
     pub filter_similar: Vec<String>,
 
-    /// URLs that should never be scanned/recursed into
+    /// Percentage of similarity at which a page is filtered by `filter_similar`
+    #[serde(default = "similarity_threshold")]
+    pub similarity_threshold: u32,
+
+    /// URLs, or glob patterns matched against a url's path, that should never be scanned/recursed
+    /// into
     #[serde(default)]
     pub url_denylist: Vec<String>,
+
+    /// Compiled version of the glob patterns in `url_denylist`
+    #[serde(skip)]
+    pub dont_scan_globs: GlobSet,
+
+    /// Match `url_denylist` glob patterns case-sensitively
+    #[serde(default)]
+    pub dont_scan_case_sensitive: bool,
+
+    /// Webhook to which findings are POSTed
+    #[serde(default)]
+    pub notify_url: String,
+
+    /// Status codes and/or url regexes that determine which findings are sent to `notify_url`
+    #[serde(default)]
+    pub notify_on: Vec<String>,
 }
 
 impl Default for Configuration {
@@ -259,8 +391,18 @@ impl Default for Configuration {
     fn default() -> Self {
         let timeout = timeout();
         let user_agent = user_agent();
-        let client = client::initialize(timeout, &user_agent, false, false, &HashMap::new(), None)
-            .expect("Could not build client");
+        let http_version = http_version();
+        let client = client::initialize(
+            Timeouts::from(timeout),
+            &user_agent,
+            false,
+            TlsVerification::Full,
+            &http_version,
+            &HashMap::new(),
+            None,
+            None,
+        )
+        .expect("Could not build client");
         let replay_client = None;
         let status_codes = status_codes();
         let replay_codes = status_codes.clone();
@@ -272,48 +414,78 @@ impl Default for Configuration {
             kind,
             client,
             timeout,
+            connect_timeout: 0,
+            read_timeout: 0,
             user_agent,
             replay_codes,
             status_codes,
             replay_client,
             requester_policy,
             dont_filter: false,
+            ignore_wildcard_warning: false,
+            auto_filter: false,
+            redirects_only: false,
             auto_bail: false,
             auto_tune: false,
+            auto_throttle: false,
+            respect_crawl_delay: false,
             silent: false,
             quiet: false,
             output_level,
             resumed: false,
             stdin: false,
             json: false,
+            output_format: output_format(),
             verbosity: 0,
             scan_limit: 0,
             parallel: 0,
             rate_limit: 0,
+            max_requests_per_dir: 0,
+            max_body_size: max_body_size(),
             add_slash: false,
             insecure: false,
+            insecure_hostname_mismatch: false,
+            http_version,
             redirects: false,
             no_recursion: false,
             extract_links: false,
+            collect_intel: false,
+            decode_slashes: false,
             save_state: true,
             proxy: String::new(),
+            proxy_all: false,
             config: String::new(),
             output: String::new(),
             debug_log: String::new(),
             target_url: String::new(),
             time_limit: String::new(),
+            dry_run: false,
             resume_from: String::new(),
             replay_proxy: String::new(),
             queries: Vec::new(),
             extensions: Vec::new(),
+            transform_case: Vec::new(),
             filter_size: Vec::new(),
             filter_regex: Vec::new(),
             url_denylist: Vec::new(),
+            dont_scan_globs: GlobSet::empty(),
+            dont_scan_case_sensitive: false,
+            notify_url: String::new(),
+            notify_on: Vec::new(),
+            dont_recurse_path: Vec::new(),
+            dont_recurse_globs: GlobSet::empty(),
             filter_line_count: Vec::new(),
             filter_word_count: Vec::new(),
             filter_status: Vec::new(),
+            match_status: Vec::new(),
+            match_size: Vec::new(),
+            match_word_count: Vec::new(),
+            match_line_count: Vec::new(),
             filter_similar: Vec::new(),
+            similarity_threshold: similarity_threshold(),
             headers: HashMap::new(),
+            basic_auth: Credential::default(),
+            bearer: Credential::default(),
             depth: depth(),
             threads: threads(),
             wordlist: wordlist(),
@@ -332,8 +504,11 @@ impl Configuration {
     /// - **config**: `None`
     /// - **threads**: `50`
     /// - **timeout**: `7` seconds
+    /// - **connect_timeout**: `0` (use timeout)
+    /// - **read_timeout**: `0` (use timeout)
     /// - **verbosity**: `0` (no logging enabled)
     /// - **proxy**: `None`
+    /// - **proxy_all**: `false`
     /// - **status_codes**: [`DEFAULT_RESPONSE_CODES`](constant.DEFAULT_RESPONSE_CODES.html)
     /// - **filter_status**: `None`
     /// - **output**: `None` (print to stdout)
@@ -342,30 +517,55 @@ impl Configuration {
     /// - **silent**: `false`
     /// - **auto_tune**: `false`
     /// - **auto_bail**: `false`
+    /// - **auto_throttle**: `false`
+    /// - **respect_crawl_delay**: `false`
     /// - **save_state**: `true`
     /// - **user_agent**: `feroxbuster/VERSION`
     /// - **insecure**: `false` (don't be insecure, i.e. don't allow invalid certs)
+    /// - **insecure_hostname_mismatch**: `false` (don't allow certs issued for other hostnames)
+    /// - **http_version**: `auto`
     /// - **extensions**: `None`
+    /// - **transform_case**: `None`
     /// - **url_denylist**: `None`
+    /// - **dont_scan_case_sensitive**: `false` (--dont-scan globs ignore case)
     /// - **filter_size**: `None`
     /// - **filter_similar**: `None`
+    /// - **similarity_threshold**: [`SIMILARITY_THRESHOLD`](constant.SIMILARITY_THRESHOLD.html)
     /// - **filter_regex**: `None`
     /// - **filter_word_count**: `None`
     /// - **filter_line_count**: `None`
+    /// - **match_status**: `None` (report any status code in status_codes)
+    /// - **match_size**: `None`
+    /// - **match_word_count**: `None`
+    /// - **match_line_count**: `None`
     /// - **headers**: `None`
+    /// - **basic_auth**: `None`
+    /// - **bearer**: `None`
     /// - **queries**: `None`
     /// - **no_recursion**: `false` (recursively scan enumerated sub-directories)
+    /// - **dont_recurse_path**: `None` (recurse into any enumerated sub-directory)
     /// - **add_slash**: `false`
     /// - **stdin**: `false`
+    /// - **collect_intel**: `false` (don't report emails/comments found in response bodies)
+    /// - **decode_slashes**: `false` (leave `%2F` encoded in extracted links)
     /// - **json**: `false`
+    /// - **output_format**: `text`
     /// - **dont_filter**: `false` (auto filter wildcard responses)
+    /// - **ignore_wildcard_warning**: `false` (warn about catch-all targets before scanning)
+    /// - **auto_filter**: `false` (don't probe each directory prior to scanning it)
+    /// - **redirects_only**: `false` (report every response, not just redirects)
     /// - **depth**: `4` (maximum recursion depth)
     /// - **scan_limit**: `0` (no limit on concurrent scans imposed)
     /// - **parallel**: `0` (no limit on parallel scans imposed)
     /// - **rate_limit**: `0` (no limit on requests per second imposed)
+    /// - **max_requests_per_dir**: `0` (no limit on requests per directory imposed)
+    /// - **max_body_size**: [`DEFAULT_MAX_BODY_SIZE`](constant.DEFAULT_MAX_BODY_SIZE.html)
     /// - **time_limit**: `None` (no limit on length of scan imposed)
+    /// - **dry_run**: `false`
     /// - **replay_proxy**: `None` (no limit on concurrent scans imposed)
     /// - **replay_codes**: [`DEFAULT_RESPONSE_CODES`](constant.DEFAULT_RESPONSE_CODES.html)
+    /// - **notify_url**: `None` (don't send findings to a webhook)
+    /// - **notify_on**: `None` (send every finding to `notify_url`)
     ///
     /// After which, any values defined in a
     /// [ferox-config.toml](constant.DEFAULT_CONFIG_NAME.html) config file will override the
@@ -432,6 +632,16 @@ impl Configuration {
             // clients aren't serialized, have to remake them from the previous config
             Self::try_rebuild_clients(&mut previous_config);
 
+            // same goes for compiled globs
+            Self::try_compile_globs(&mut previous_config);
+            Self::validate_recursion_limits(&previous_config);
+            Self::validate_proxy_all(&previous_config);
+            Self::validate_notify_on(&previous_config);
+            Self::validate_similarity_threshold(&previous_config);
+            Self::validate_collect_intel(&previous_config);
+            Self::validate_transform_case(&previous_config);
+            Self::validate_output_format(&previous_config);
+
             return Ok(previous_config);
         }
 
@@ -439,8 +649,16 @@ impl Configuration {
         // merge the cli options into the config file options and return the result
         Self::merge_config(&mut config, cli_config);
 
-        // rebuild clients is the last step in either code branch
+        // rebuild clients and compile globs are the last steps in either code branch
         Self::try_rebuild_clients(&mut config);
+        Self::try_compile_globs(&mut config);
+        Self::validate_recursion_limits(&config);
+        Self::validate_proxy_all(&config);
+        Self::validate_notify_on(&config);
+        Self::validate_similarity_threshold(&config);
+        Self::validate_collect_intel(&config);
+        Self::validate_transform_case(&config);
+        Self::validate_output_format(&config);
 
         Ok(config)
     }
@@ -500,11 +718,24 @@ impl Configuration {
         update_config_if_present!(&mut config.scan_limit, args, "scan_limit", usize);
         update_config_if_present!(&mut config.parallel, args, "parallel", usize);
         update_config_if_present!(&mut config.rate_limit, args, "rate_limit", usize);
-        update_config_if_present!(&mut config.wordlist, args, "wordlist", String);
+        update_config_if_present!(
+            &mut config.max_requests_per_dir,
+            args,
+            "max_requests_per_dir",
+            usize
+        );
+        update_config_if_present!(&mut config.max_body_size, args, "max_body_size", usize);
+        update_config_if_present!(
+            &mut config.similarity_threshold,
+            args,
+            "similarity_threshold",
+            u32
+        );
         update_config_if_present!(&mut config.output, args, "output", String);
         update_config_if_present!(&mut config.debug_log, args, "debug_log", String);
         update_config_if_present!(&mut config.time_limit, args, "time_limit", String);
         update_config_if_present!(&mut config.resume_from, args, "resume_from", String);
+        update_config_if_present!(&mut config.notify_url, args, "notify_url", String);
 
         if let Some(arg) = args.values_of("status_codes") {
             config.status_codes = arg
@@ -540,14 +771,34 @@ impl Configuration {
                 .collect();
         }
 
+        if let Some(arg) = args.values_of("wordlist") {
+            config.wordlist = arg.map(|val| val.to_string()).collect();
+        }
+
         if let Some(arg) = args.values_of("extensions") {
             config.extensions = arg.map(|val| val.to_string()).collect();
         }
 
+        if let Some(arg) = args.values_of("transform_case") {
+            config.transform_case = arg.map(|val| val.to_string()).collect();
+        }
+
         if let Some(arg) = args.values_of("url_denylist") {
             config.url_denylist = arg.map(|val| val.to_string()).collect();
         }
 
+        if args.is_present("dont_scan_case_sensitive") {
+            config.dont_scan_case_sensitive = true;
+        }
+
+        if let Some(arg) = args.values_of("notify_on") {
+            config.notify_on = arg.map(|val| val.to_string()).collect();
+        }
+
+        if let Some(arg) = args.values_of("dont_recurse_path") {
+            config.dont_recurse_path = arg.map(|val| val.to_string()).collect();
+        }
+
         if let Some(arg) = args.values_of("filter_regex") {
             config.filter_regex = arg.map(|val| val.to_string()).collect();
         }
@@ -583,6 +834,43 @@ impl Configuration {
                 .collect();
         }
 
+        if let Some(arg) = args.values_of("match_status") {
+            config.match_status = arg
+                .map(|code| {
+                    StatusCode::from_bytes(code.as_bytes())
+                        .unwrap_or_else(|e| report_and_exit(&e.to_string()))
+                        .as_u16()
+                })
+                .collect();
+        }
+
+        if let Some(arg) = args.values_of("match_size") {
+            config.match_size = arg
+                .map(|size| {
+                    size.parse::<u64>()
+                        .unwrap_or_else(|e| report_and_exit(&e.to_string()))
+                })
+                .collect();
+        }
+
+        if let Some(arg) = args.values_of("match_words") {
+            config.match_word_count = arg
+                .map(|size| {
+                    size.parse::<usize>()
+                        .unwrap_or_else(|e| report_and_exit(&e.to_string()))
+                })
+                .collect();
+        }
+
+        if let Some(arg) = args.values_of("match_lines") {
+            config.match_line_count = arg
+                .map(|size| {
+                    size.parse::<usize>()
+                        .unwrap_or_else(|e| report_and_exit(&e.to_string()))
+                })
+                .collect();
+        }
+
         if args.is_present("silent") {
             // the reason this is protected by an if statement:
             // consider a user specifying silent = true in ferox-config.toml
@@ -607,10 +895,34 @@ impl Configuration {
             config.requester_policy = RequesterPolicy::AutoBail;
         }
 
+        if args.is_present("auto_throttle") {
+            config.auto_throttle = true;
+        }
+
+        if args.is_present("respect_crawl_delay") {
+            config.respect_crawl_delay = true;
+        }
+
+        if args.is_present("dry_run") {
+            config.dry_run = true;
+        }
+
         if args.is_present("dont_filter") {
             config.dont_filter = true;
         }
 
+        if args.is_present("ignore_wildcard_warning") {
+            config.ignore_wildcard_warning = true;
+        }
+
+        if args.is_present("auto_filter") {
+            config.auto_filter = true;
+        }
+
+        if args.is_present("redirects_only") {
+            config.redirects_only = true;
+        }
+
         if args.occurrences_of("verbosity") > 0 {
             // occurrences_of returns 0 if none are found; this is protected in
             // an if block for the same reason as the quiet option
@@ -629,8 +941,27 @@ impl Configuration {
             config.extract_links = true;
         }
 
-        if args.is_present("json") {
+        if args.is_present("collect_intel") {
+            config.collect_intel = true;
+        }
+
+        if args.is_present("decode_slashes") {
+            config.decode_slashes = true;
+        }
+
+        update_config_if_present!(&mut config.output_format, args, "output_format", String);
+
+        if args.is_present("json") || config.output_format == "json" {
+            if !args.is_present("output")
+                && !args.is_present("debug_log")
+                && !args.is_present("silent")
+            {
+                // json needs somewhere to go; --silent sends it to stdout. clap can't express
+                // "requires one of these", so it's checked here instead
+                report_and_exit("--json requires --output, --debug-log, or --silent");
+            }
             config.json = true;
+            config.output_format = String::from("json");
         }
 
         if args.is_present("stdin") {
@@ -643,9 +974,17 @@ impl Configuration {
         // organizational breakpoint; all options below alter the Client configuration
         ////
         update_config_if_present!(&mut config.proxy, args, "proxy", String);
+
+        if args.is_present("proxy_all") {
+            config.proxy_all = true;
+        }
+
         update_config_if_present!(&mut config.replay_proxy, args, "replay_proxy", String);
         update_config_if_present!(&mut config.user_agent, args, "user_agent", String);
         update_config_if_present!(&mut config.timeout, args, "timeout", u64);
+        update_config_if_present!(&mut config.connect_timeout, args, "connect_timeout", u64);
+        update_config_if_present!(&mut config.read_timeout, args, "read_timeout", u64);
+        update_config_if_present!(&mut config.http_version, args, "http_version", String);
 
         if args.is_present("redirects") {
             config.redirects = true;
@@ -655,6 +994,10 @@ impl Configuration {
             config.insecure = true;
         }
 
+        if args.is_present("insecure_hostname_mismatch") {
+            config.insecure_hostname_mismatch = true;
+        }
+
         if let Some(headers) = args.values_of("headers") {
             for val in headers {
                 let mut split_val = val.split(':');
@@ -669,6 +1012,14 @@ impl Configuration {
             }
         }
 
+        if let Some(credentials) = args.value_of("basic_auth") {
+            config.basic_auth = Credential::from(credentials);
+        }
+
+        if let Some(token) = args.value_of("bearer") {
+            config.bearer = Credential::from(token);
+        }
+
         if let Some(queries) = args.values_of("queries") {
             for val in queries {
                 // same basic logic used as reading in the headers HashMap above
@@ -689,46 +1040,41 @@ impl Configuration {
     /// either the config file or command line arguments; if we have, we need to rebuild
     /// the client and store it in the config struct
     fn try_rebuild_clients(configuration: &mut Configuration) {
+        if !configuration.basic_auth.is_empty() && !configuration.bearer.is_empty() {
+            // the cli enforces this on its own, but a config file (or a mix of the two) can
+            // still end up w/ both; neither one gets to win silently
+            report_and_exit("--basic-auth and --bearer can't be used together");
+        }
+
         if !configuration.proxy.is_empty()
             || configuration.timeout != timeout()
+            || configuration.connect_timeout != 0
+            || configuration.read_timeout != 0
             || configuration.user_agent != user_agent()
             || configuration.redirects
             || configuration.insecure
+            || configuration.insecure_hostname_mismatch
+            || configuration.http_version != http_version()
             || !configuration.headers.is_empty()
+            || configuration.auth().is_some()
             || configuration.resumed
         {
-            if configuration.proxy.is_empty() {
-                configuration.client = client::initialize(
-                    configuration.timeout,
-                    &configuration.user_agent,
-                    configuration.redirects,
-                    configuration.insecure,
-                    &configuration.headers,
-                    None,
-                )
-                .expect("Could not rebuild client")
-            } else {
-                configuration.client = client::initialize(
-                    configuration.timeout,
-                    &configuration.user_agent,
-                    configuration.redirects,
-                    configuration.insecure,
-                    &configuration.headers,
-                    Some(&configuration.proxy),
-                )
-                .expect("Could not rebuild client")
-            }
+            configuration.client = configuration
+                .build_client(configuration.redirects)
+                .expect("Could not rebuild client");
         }
 
         if !configuration.replay_proxy.is_empty() {
             // only set replay_client when replay_proxy is set
             configuration.replay_client = Some(
                 client::initialize(
-                    configuration.timeout,
+                    configuration.timeouts(),
                     &configuration.user_agent,
                     configuration.redirects,
-                    configuration.insecure,
+                    configuration.tls_verification(),
+                    &configuration.http_version,
                     &configuration.headers,
+                    configuration.auth(),
                     Some(&configuration.replay_proxy),
                 )
                 .expect("Could not rebuild client"),
@@ -736,6 +1082,101 @@ impl Configuration {
         }
     }
 
+    /// unlimited recursion (--depth 0) is only allowed when the number of requests sent to each
+    /// directory is capped, otherwise a target that generates directories on the fly would keep
+    /// the scan running forever; the offending combination is reported and causes an exit
+    pub(super) fn validate_recursion_limits(configuration: &Configuration) {
+        if configuration.depth == 0
+            && !configuration.no_recursion
+            && configuration.max_requests_per_dir == 0
+        {
+            report_and_exit(
+                "--depth 0 (unlimited recursion) requires --max-requests-per-dir to be set as well",
+            );
+        }
+    }
+
+    /// --proxy-all is a promise that nothing goes out without the proxy, so it can't be used
+    /// without one; the cli enforces this on its own, but a config file can still end up w/o a
+    /// proxy
+    pub(super) fn validate_proxy_all(configuration: &Configuration) {
+        if configuration.proxy_all && configuration.proxy.is_empty() {
+            report_and_exit("--proxy-all requires --proxy to be set as well");
+        }
+    }
+
+    /// intel is only collected while extracting links, so --collect-intel on its own would do
+    /// nothing; the cli enforces this on its own, but a config file can still set it w/o
+    /// --extract-links
+    pub(super) fn validate_collect_intel(configuration: &Configuration) {
+        if configuration.collect_intel && !configuration.extract_links {
+            report_and_exit("--collect-intel requires --extract-links to be set as well");
+        }
+    }
+
+    /// the cli only accepts known --transform-case values, but a config file can hold anything;
+    /// an unknown value would silently add no variations, so it's reported and causes an exit
+    pub(super) fn validate_transform_case(configuration: &Configuration) {
+        for transform in &configuration.transform_case {
+            if !["lower", "upper", "capitalize", "all"].contains(&transform.as_str()) {
+                report_and_exit(&format!(
+                    "invalid transform_case value '{}': expected one of lower, upper, capitalize, all",
+                    transform
+                ));
+            }
+        }
+    }
+
+    /// the cli only accepts text, json, or csv for --output-format, but a config file can hold
+    /// anything; csv also needs --output, since rows only make sense in a file and there's no
+    /// csv equivalent of the terminal output. Either problem is reported and causes an exit
+    pub(super) fn validate_output_format(configuration: &Configuration) {
+        if !["text", "json", "csv"].contains(&configuration.output_format.as_str()) {
+            report_and_exit(&format!(
+                "invalid output_format value '{}': expected one of text, json, csv",
+                configuration.output_format
+            ));
+        }
+
+        if configuration.output_format == "csv" && configuration.output.is_empty() {
+            report_and_exit("--output-format csv requires --output");
+        }
+    }
+
+    /// every --notify-on value must parse, otherwise the criteria that are left could end up
+    /// empty, which would send every finding to the webhook instead of the ones asked for; an
+    /// invalid value is reported and causes an exit
+    pub(super) fn validate_notify_on(configuration: &Configuration) {
+        for raw in &configuration.notify_on {
+            if let Err(e) = NotifyCriterion::parse(raw) {
+                report_and_exit(&format!("invalid --notify-on value '{}': {}", raw, e));
+            }
+        }
+    }
+
+    /// --similarity-threshold is validated by the parser, but a value from a config file (or a
+    /// resumed scan) isn't; anything outside of 1-100 is reported and causes an exit
+    pub(super) fn validate_similarity_threshold(configuration: &Configuration) {
+        if !(1..=100).contains(&configuration.similarity_threshold) {
+            report_and_exit(&format!(
+                "similarity_threshold must be between 1 and 100, got {}",
+                configuration.similarity_threshold
+            ));
+        }
+    }
+
+    /// compile any user-provided glob patterns, an invalid pattern is reported and causes an exit
+    pub(super) fn try_compile_globs(configuration: &mut Configuration) {
+        configuration.dont_recurse_globs = build_glob_set(&configuration.dont_recurse_path)
+            .unwrap_or_else(|e| report_and_exit(&e.to_string()));
+
+        configuration.dont_scan_globs = build_dont_scan_glob_set(
+            &configuration.url_denylist,
+            configuration.dont_scan_case_sensitive,
+        )
+        .unwrap_or_else(|e| report_and_exit(&e.to_string()));
+    }
+
     /// Given a configuration file's location and an instance of `Configuration`, read in
     /// the config file if found and update the current settings with the settings found therein
     fn parse_and_merge_config(config_file: PathBuf, mut config: &mut Self) -> Result<()> {
@@ -763,28 +1204,61 @@ impl Configuration {
         //  - config
         update_if_not_default!(&mut conf.target_url, new.target_url, "");
         update_if_not_default!(&mut conf.time_limit, new.time_limit, "");
+        update_if_not_default!(&mut conf.dry_run, new.dry_run, false);
         update_if_not_default!(&mut conf.proxy, new.proxy, "");
+        update_if_not_default!(&mut conf.proxy_all, new.proxy_all, false);
         update_if_not_default!(&mut conf.verbosity, new.verbosity, 0);
         update_if_not_default!(&mut conf.silent, new.silent, false);
         update_if_not_default!(&mut conf.quiet, new.quiet, false);
         update_if_not_default!(&mut conf.auto_bail, new.auto_bail, false);
         update_if_not_default!(&mut conf.auto_tune, new.auto_tune, false);
+        update_if_not_default!(&mut conf.auto_throttle, new.auto_throttle, false);
+        update_if_not_default!(
+            &mut conf.respect_crawl_delay,
+            new.respect_crawl_delay,
+            false
+        );
         // use updated quiet/silent values to determine output level; same for requester policy
         conf.output_level = determine_output_level(conf.quiet, conf.silent);
         conf.requester_policy = determine_requester_policy(conf.auto_tune, conf.auto_bail);
         update_if_not_default!(&mut conf.output, new.output, "");
         update_if_not_default!(&mut conf.redirects, new.redirects, false);
         update_if_not_default!(&mut conf.insecure, new.insecure, false);
+        update_if_not_default!(
+            &mut conf.insecure_hostname_mismatch,
+            new.insecure_hostname_mismatch,
+            false
+        );
+        update_if_not_default!(&mut conf.http_version, new.http_version, http_version());
         update_if_not_default!(&mut conf.extract_links, new.extract_links, false);
+        update_if_not_default!(&mut conf.collect_intel, new.collect_intel, false);
+        update_if_not_default!(&mut conf.decode_slashes, new.decode_slashes, false);
         update_if_not_default!(&mut conf.extensions, new.extensions, Vec::<String>::new());
+        update_if_not_default!(
+            &mut conf.transform_case,
+            new.transform_case,
+            Vec::<String>::new()
+        );
         update_if_not_default!(
             &mut conf.url_denylist,
             new.url_denylist,
             Vec::<String>::new()
         );
+        update_if_not_default!(
+            &mut conf.dont_scan_case_sensitive,
+            new.dont_scan_case_sensitive,
+            false
+        );
         update_if_not_default!(&mut conf.headers, new.headers, HashMap::new());
+        update_if_not_default!(&mut conf.basic_auth, new.basic_auth, Credential::default());
+        update_if_not_default!(&mut conf.bearer, new.bearer, Credential::default());
         update_if_not_default!(&mut conf.queries, new.queries, Vec::new());
         update_if_not_default!(&mut conf.no_recursion, new.no_recursion, false);
+        update_if_not_default!(
+            &mut conf.dont_recurse_path,
+            new.dont_recurse_path,
+            Vec::<String>::new()
+        );
         update_if_not_default!(&mut conf.add_slash, new.add_slash, false);
         update_if_not_default!(&mut conf.stdin, new.stdin, false);
         update_if_not_default!(&mut conf.filter_size, new.filter_size, Vec::<u64>::new());
@@ -798,6 +1272,11 @@ impl Configuration {
             new.filter_similar,
             Vec::<String>::new()
         );
+        update_if_not_default!(
+            &mut conf.similarity_threshold,
+            new.similarity_threshold,
+            similarity_threshold()
+        );
         update_if_not_default!(
             &mut conf.filter_word_count,
             new.filter_word_count,
@@ -813,19 +1292,50 @@ impl Configuration {
             new.filter_status,
             Vec::<u16>::new()
         );
+        update_if_not_default!(&mut conf.match_status, new.match_status, Vec::<u16>::new());
+        update_if_not_default!(&mut conf.match_size, new.match_size, Vec::<u64>::new());
+        update_if_not_default!(
+            &mut conf.match_word_count,
+            new.match_word_count,
+            Vec::<usize>::new()
+        );
+        update_if_not_default!(
+            &mut conf.match_line_count,
+            new.match_line_count,
+            Vec::<usize>::new()
+        );
         update_if_not_default!(&mut conf.dont_filter, new.dont_filter, false);
+        update_if_not_default!(
+            &mut conf.ignore_wildcard_warning,
+            new.ignore_wildcard_warning,
+            false
+        );
+        update_if_not_default!(&mut conf.auto_filter, new.auto_filter, false);
+        update_if_not_default!(&mut conf.redirects_only, new.redirects_only, false);
         update_if_not_default!(&mut conf.scan_limit, new.scan_limit, 0);
         update_if_not_default!(&mut conf.parallel, new.parallel, 0);
         update_if_not_default!(&mut conf.rate_limit, new.rate_limit, 0);
+        update_if_not_default!(&mut conf.max_requests_per_dir, new.max_requests_per_dir, 0);
         update_if_not_default!(&mut conf.replay_proxy, new.replay_proxy, "");
+        update_if_not_default!(&mut conf.notify_url, new.notify_url, "");
+        update_if_not_default!(&mut conf.notify_on, new.notify_on, Vec::<String>::new());
         update_if_not_default!(&mut conf.debug_log, new.debug_log, "");
         update_if_not_default!(&mut conf.resume_from, new.resume_from, "");
         update_if_not_default!(&mut conf.json, new.json, false);
+        update_if_not_default!(&mut conf.output_format, new.output_format, output_format());
+
+        if conf.output_format == "json" {
+            // output_format = "json" in a config file is the same as json = true
+            conf.json = true;
+        }
 
         update_if_not_default!(&mut conf.timeout, new.timeout, timeout());
+        update_if_not_default!(&mut conf.connect_timeout, new.connect_timeout, 0);
+        update_if_not_default!(&mut conf.read_timeout, new.read_timeout, 0);
         update_if_not_default!(&mut conf.user_agent, new.user_agent, user_agent());
         update_if_not_default!(&mut conf.threads, new.threads, threads());
         update_if_not_default!(&mut conf.depth, new.depth, depth());
+        update_if_not_default!(&mut conf.max_body_size, new.max_body_size, max_body_size());
         update_if_not_default!(&mut conf.wordlist, new.wordlist, wordlist());
         update_if_not_default!(&mut conf.status_codes, new.status_codes, status_codes());
         // status_codes() is the default for replay_codes, if they're not provided
@@ -841,6 +1351,64 @@ impl Configuration {
         let config: Self = toml::from_str(content.as_str())?;
         Ok(config)
     }
+
+    /// how much of a server's certificate should be verified; --insecure takes precedence over
+    /// --insecure-hostname-mismatch, as it already skips the hostname check
+    pub fn tls_verification(&self) -> TlsVerification {
+        if self.insecure {
+            TlsVerification::AcceptInvalidCerts
+        } else if self.insecure_hostname_mismatch {
+            TlsVerification::AcceptInvalidHostnames
+        } else {
+            TlsVerification::Full
+        }
+    }
+
+    /// how long to wait on each request; --connect-timeout and --read-timeout fall back to
+    /// --timeout when they're not given
+    pub fn timeouts(&self) -> Timeouts {
+        let or_timeout = |seconds: u64| if seconds > 0 { seconds } else { self.timeout };
+
+        Timeouts {
+            connect: or_timeout(self.connect_timeout),
+            read: or_timeout(self.read_timeout),
+        }
+    }
+
+    /// create a client w/ the user's settings (timeouts, headers, proxy, etc), following
+    /// redirects or not as requested
+    ///
+    /// the scan's client and the one used to request robots.txt both come from here, so that a
+    /// --proxy can't apply to one and not the other
+    pub fn build_client(&self, redirects: bool) -> Result<Client> {
+        let proxy = if self.proxy.is_empty() {
+            None
+        } else {
+            Some(self.proxy.as_str())
+        };
+
+        client::initialize(
+            self.timeouts(),
+            &self.user_agent,
+            redirects,
+            self.tls_verification(),
+            &self.http_version,
+            &self.headers,
+            self.auth(),
+            proxy,
+        )
+    }
+
+    /// credentials that should be sent with each request, if any were provided
+    pub fn auth(&self) -> Option<Auth> {
+        if !self.basic_auth.is_empty() {
+            Some(Auth::Basic(self.basic_auth.as_str()))
+        } else if !self.bearer.is_empty() {
+            Some(Auth::Bearer(self.bearer.as_str()))
+        } else {
+            None
+        }
+    }
 }
 
 /// Implementation of FeroxMessage
@@ -856,9 +1424,10 @@ impl FeroxSerialize for Configuration {
     /// ex:
     /// {
     ///    "type":"configuration",
-    ///    "wordlist":"test",
+    ///    "wordlist":["test"],
     ///    "config":"/home/epi/.config/feroxbuster/ferox-config.toml",
     ///    "proxy":"",
+    ///    "proxy_all":false,
     ///    "replay_proxy":"",
     ///    "target_url":"https://localhost.com",
     ///    "status_codes":[
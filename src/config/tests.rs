@@ -1,6 +1,10 @@
 use super::utils::*;
 use super::*;
-use crate::{traits::FeroxSerialize, DEFAULT_CONFIG_NAME};
+use crate::{
+    client::{Auth, Timeouts, TlsVerification},
+    traits::FeroxSerialize,
+    DEFAULT_CONFIG_NAME, DEFAULT_MAX_BODY_SIZE, SIMILARITY_THRESHOLD,
+};
 use std::{collections::HashMap, fs::write};
 use tempfile::TempDir;
 
@@ -12,40 +16,67 @@ fn setup_config_test() -> Configuration {
             replay_codes = [201, 301]
             threads = 40
             timeout = 5
+            connect_timeout = 2
+            read_timeout = 30
             proxy = "http://127.0.0.1:8080"
+            proxy_all = true
             replay_proxy = "http://127.0.0.1:8081"
+            notify_url = "http://127.0.0.1:9999/webhook"
+            notify_on = ["200:admin", "403"]
             quiet = true
             silent = true
             auto_tune = true
             auto_bail = true
+            auto_throttle = true
+            respect_crawl_delay = true
             verbosity = 1
             scan_limit = 6
             parallel = 14
             rate_limit = 250
+            max_requests_per_dir = 100
+            max_body_size = 1024
             time_limit = "10m"
+            dry_run = true
             output = "/some/otherpath"
             debug_log = "/yet/anotherpath"
             resume_from = "/some/state/file"
             redirects = true
             insecure = true
+            insecure_hostname_mismatch = true
+            http_version = "2"
+            basic_auth = "admin:hunter2"
             extensions = ["html", "php", "js"]
+            transform_case = ["upper", "capitalize"]
             url_denylist = ["http://dont-scan.me", "https://also-not.me"]
+            dont_scan_case_sensitive = true
             headers = {stuff = "things", mostuff = "mothings"}
             queries = [["name","value"], ["rick", "astley"]]
             no_recursion = true
+            dont_recurse_path = ["**/static/", "/node_modules/"]
             add_slash = true
             stdin = true
             dont_filter = true
+            ignore_wildcard_warning = true
+            auto_filter = true
+            redirects_only = true
             extract_links = true
+            collect_intel = true
+            decode_slashes = true
             json = true
+            output_format = "csv"
             save_state = false
             depth = 1
             filter_size = [4120]
             filter_regex = ["^ignore me$"]
             filter_similar = ["https://somesite.com/soft404"]
+            similarity_threshold = 80
             filter_word_count = [994, 992]
             filter_line_count = [34]
             filter_status = [201]
+            match_status = [200, 401]
+            match_size = [4096]
+            match_word_count = [12]
+            match_line_count = [3, 4]
         "#;
     let tmp_dir = TempDir::new().unwrap();
     let file = tmp_dir.path().join(DEFAULT_CONFIG_NAME);
@@ -59,8 +90,10 @@ fn default_configuration() {
     let config = Configuration::default();
     assert_eq!(config.wordlist, wordlist());
     assert_eq!(config.proxy, String::new());
+    assert!(!config.proxy_all);
     assert_eq!(config.target_url, String::new());
     assert_eq!(config.time_limit, String::new());
+    assert!(!config.dry_run);
     assert_eq!(config.resume_from, String::new());
     assert_eq!(config.debug_log, String::new());
     assert_eq!(config.config, String::new());
@@ -71,32 +104,62 @@ fn default_configuration() {
     assert_eq!(config.threads, threads());
     assert_eq!(config.depth, depth());
     assert_eq!(config.timeout, timeout());
+    assert_eq!(config.connect_timeout, 0);
+    assert_eq!(config.read_timeout, 0);
     assert_eq!(config.verbosity, 0);
     assert_eq!(config.scan_limit, 0);
+    assert_eq!(config.max_requests_per_dir, 0);
+    assert_eq!(config.max_body_size, DEFAULT_MAX_BODY_SIZE);
     assert!(!config.silent);
     assert!(!config.quiet);
     assert_eq!(config.output_level, OutputLevel::Default);
     assert!(!config.dont_filter);
+    assert!(!config.ignore_wildcard_warning);
+    assert!(!config.auto_filter);
+    assert!(!config.redirects_only);
     assert!(!config.auto_tune);
     assert!(!config.auto_bail);
+    assert!(!config.auto_throttle);
+    assert!(!config.respect_crawl_delay);
     assert_eq!(config.requester_policy, RequesterPolicy::Default);
     assert!(!config.no_recursion);
     assert!(!config.json);
+    assert_eq!(config.similarity_threshold, SIMILARITY_THRESHOLD);
+    assert_eq!(config.output_format, "text");
     assert!(config.save_state);
     assert!(!config.stdin);
     assert!(!config.add_slash);
     assert!(!config.redirects);
     assert!(!config.extract_links);
+    assert!(!config.collect_intel);
+    assert!(!config.decode_slashes);
     assert!(!config.insecure);
+    assert!(!config.insecure_hostname_mismatch);
+    assert_eq!(config.tls_verification(), TlsVerification::Full);
+    assert_eq!(config.http_version, "auto");
+    assert!(config.basic_auth.is_empty());
+    assert!(config.bearer.is_empty());
+    assert!(config.auth().is_none());
     assert_eq!(config.queries, Vec::new());
     assert_eq!(config.filter_size, Vec::<u64>::new());
     assert_eq!(config.extensions, Vec::<String>::new());
+    assert_eq!(config.transform_case, Vec::<String>::new());
     assert_eq!(config.url_denylist, Vec::<String>::new());
+    assert!(config.dont_scan_globs.is_empty());
+    assert!(!config.dont_scan_case_sensitive);
+    assert_eq!(config.notify_url, String::new());
+    assert_eq!(config.notify_on, Vec::<String>::new());
+    assert_eq!(config.dont_recurse_path, Vec::<String>::new());
+    assert!(config.dont_recurse_globs.is_empty());
     assert_eq!(config.filter_regex, Vec::<String>::new());
     assert_eq!(config.filter_similar, Vec::<String>::new());
     assert_eq!(config.filter_word_count, Vec::<usize>::new());
     assert_eq!(config.filter_line_count, Vec::<usize>::new());
     assert_eq!(config.filter_status, Vec::<u16>::new());
+    assert_eq!(config.match_status, Vec::<u16>::new());
+    assert_eq!(config.match_size, Vec::<u64>::new());
+    assert_eq!(config.match_word_count, Vec::<usize>::new());
+    assert_eq!(config.match_line_count, Vec::<usize>::new());
     assert_eq!(config.headers, HashMap::new());
 }
 
@@ -104,7 +167,18 @@ fn default_configuration() {
 /// parse the test config and see that the value parsed is correct
 fn config_reads_wordlist() {
     let config = setup_config_test();
-    assert_eq!(config.wordlist, "/some/path");
+    assert_eq!(config.wordlist, vec!["/some/path"]);
+}
+
+#[test]
+/// wordlist can also be given as a list of paths in the config file
+fn config_reads_multiple_wordlists() {
+    let tmp_dir = TempDir::new().unwrap();
+    let file = tmp_dir.path().join(DEFAULT_CONFIG_NAME);
+    write(&file, r#"wordlist = ["/some/path", "/some/other/path"]"#).unwrap();
+
+    let config = Configuration::parse_config(file).unwrap();
+    assert_eq!(config.wordlist, vec!["/some/path", "/some/other/path"]);
 }
 
 #[test]
@@ -163,6 +237,20 @@ fn config_reads_rate_limit() {
     assert_eq!(config.rate_limit, 250);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_max_requests_per_dir() {
+    let config = setup_config_test();
+    assert_eq!(config.max_requests_per_dir, 100);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_max_body_size() {
+    let config = setup_config_test();
+    assert_eq!(config.max_body_size, 1024);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_timeout() {
@@ -170,6 +258,38 @@ fn config_reads_timeout() {
     assert_eq!(config.timeout, 5);
 }
 
+#[test]
+/// parse the test config and see that the values parsed are correct
+fn config_reads_connect_and_read_timeouts() {
+    let config = setup_config_test();
+    assert_eq!(config.connect_timeout, 2);
+    assert_eq!(config.read_timeout, 30);
+    assert_eq!(
+        config.timeouts(),
+        Timeouts {
+            connect: 2,
+            read: 30
+        }
+    );
+}
+
+#[test]
+/// --connect-timeout and --read-timeout should each fall back to --timeout when not given
+fn config_timeouts_fall_back_to_timeout() {
+    let mut config = Configuration::new().unwrap();
+    config.timeout = 12;
+    assert_eq!(config.timeouts(), Timeouts::from(12));
+
+    config.connect_timeout = 3;
+    assert_eq!(
+        config.timeouts(),
+        Timeouts {
+            connect: 3,
+            read: 12
+        }
+    );
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_proxy() {
@@ -177,6 +297,28 @@ fn config_reads_proxy() {
     assert_eq!(config.proxy, "http://127.0.0.1:8080");
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_proxy_all() {
+    let config = setup_config_test();
+    assert!(config.proxy_all);
+}
+
+#[test]
+/// the client built for robots.txt should use the same proxy as the scan's client
+fn build_client_uses_configured_proxy() {
+    let config = Configuration {
+        proxy: String::from("http://127.0.0.1:8080"),
+        ..Default::default()
+    };
+
+    let client = format!("{:?}", config.build_client(true).unwrap());
+    assert!(client.contains("127.0.0.1:8080"));
+
+    let client = format!("{:?}", Configuration::default().build_client(true).unwrap());
+    assert!(!client.contains("127.0.0.1:8080"));
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_replay_proxy() {
@@ -205,6 +347,13 @@ fn config_reads_json() {
     assert!(config.json);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_output_format() {
+    let config = setup_config_test();
+    assert_eq!(config.output_format, "csv");
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_auto_bail() {
@@ -219,6 +368,20 @@ fn config_reads_auto_tune() {
     assert!(config.auto_tune);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_auto_throttle() {
+    let config = setup_config_test();
+    assert!(config.auto_throttle);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_respect_crawl_delay() {
+    let config = setup_config_test();
+    assert!(config.respect_crawl_delay);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_verbosity() {
@@ -247,6 +410,41 @@ fn config_reads_insecure() {
     assert!(config.insecure);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct; --insecure wins when both
+/// are set, as it's a superset of --insecure-hostname-mismatch
+fn config_reads_insecure_hostname_mismatch() {
+    let mut config = setup_config_test();
+    assert!(config.insecure_hostname_mismatch);
+    assert_eq!(
+        config.tls_verification(),
+        TlsVerification::AcceptInvalidCerts
+    );
+
+    config.insecure = false;
+    assert_eq!(
+        config.tls_verification(),
+        TlsVerification::AcceptInvalidHostnames
+    );
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_http_version() {
+    let config = setup_config_test();
+    assert_eq!(config.http_version, "2");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct, and never shown when logged
+fn config_reads_basic_auth() {
+    let config = setup_config_test();
+    assert_eq!(config.basic_auth.as_str(), "admin:hunter2");
+    assert!(matches!(config.auth(), Some(Auth::Basic("admin:hunter2"))));
+    assert!(!format!("{:?}", config).contains("hunter2"));
+    assert!(!config.as_json().unwrap().contains("hunter2"));
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_no_recursion() {
@@ -254,6 +452,298 @@ fn config_reads_no_recursion() {
     assert!(config.no_recursion);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_dont_recurse_path() {
+    let config = setup_config_test();
+    assert_eq!(
+        config.dont_recurse_path,
+        vec!["**/static/", "/node_modules/"]
+    );
+}
+
+#[test]
+/// compile valid globs and ensure they match as expected; `*` shouldn't cross a `/`
+fn build_glob_set_matches_expected_paths() {
+    let patterns = vec!["**/static/".to_string(), "/node_*/".to_string()];
+    let globs = build_glob_set(&patterns).unwrap();
+
+    assert!(globs.is_match("/static/"));
+    assert!(globs.is_match("/assets/static/"));
+    assert!(globs.is_match("/node_modules/"));
+    assert!(!globs.is_match("/lib/node_modules/"));
+    assert!(!globs.is_match("/statics/"));
+}
+
+#[test]
+/// a --dont-recurse-path glob that uses alternation should reach the glob set in one piece,
+/// rather than being split on its comma
+fn build_glob_set_from_cli_keeps_alternation_intact() {
+    let args = crate::parser::initialize().get_matches_from(vec![
+        "feroxbuster",
+        "--url",
+        "http://localhost",
+        "--dont-recurse-path",
+        "**/{static,assets}/",
+    ]);
+
+    let patterns: Vec<String> = args
+        .values_of("dont_recurse_path")
+        .unwrap()
+        .map(String::from)
+        .collect();
+
+    assert_eq!(patterns, vec!["**/{static,assets}/"]);
+
+    let globs = build_glob_set(&patterns).unwrap();
+
+    assert!(globs.is_match("/static/"));
+    assert!(globs.is_match("/app/assets/"));
+    assert!(!globs.is_match("/images/"));
+}
+
+#[test]
+/// an invalid glob should result in an error
+fn build_glob_set_rejects_invalid_pattern() {
+    let patterns = vec!["/static/[a-".to_string()];
+    assert!(build_glob_set(&patterns).is_err());
+}
+
+#[test]
+#[should_panic]
+/// an invalid glob in the configuration should fail early via report_and_exit
+fn config_try_compile_globs_panics_on_invalid_pattern() {
+    let mut config = Configuration::new().unwrap();
+    config.dont_recurse_path = vec!["/static/[a-".to_string()];
+    Configuration::try_compile_globs(&mut config);
+}
+
+#[test]
+/// --dont-scan globs skip urls, match at any depth unless anchored w/ a leading slash, and
+/// ignore case unless told otherwise
+fn build_dont_scan_glob_set_matches_expected_paths() {
+    let denylist = vec![
+        "http://dont-scan.me".to_string(),
+        "*.png".to_string(),
+        "/cdn/**".to_string(),
+    ];
+
+    let globs = build_dont_scan_glob_set(&denylist, false).unwrap();
+
+    assert_eq!(globs.len(), 2);
+    assert!(globs.is_match("/logo.png"));
+    assert!(globs.is_match("/img/icons/LOGO.PNG"));
+    assert!(globs.is_match("/cdn/fonts/font.woff"));
+    assert!(globs.is_match("/CDN/jquery.js"));
+    assert!(!globs.is_match("/static/cdn/jquery.js"));
+    assert!(!globs.is_match("/logo.png.bak"));
+
+    let strict = build_dont_scan_glob_set(&denylist, true).unwrap();
+
+    assert!(strict.is_match("/img/icons/logo.png"));
+    assert!(!strict.is_match("/img/icons/LOGO.PNG"));
+    assert!(!strict.is_match("/CDN/jquery.js"));
+}
+
+#[test]
+/// a --dont-scan glob that uses alternation should reach the glob set in one piece, rather than
+/// being split on its comma
+fn build_dont_scan_glob_set_from_cli_keeps_alternation_intact() {
+    let args = crate::parser::initialize().get_matches_from(vec![
+        "feroxbuster",
+        "--url",
+        "http://localhost",
+        "--dont-scan",
+        "*.{png,woff}",
+        "--dont-scan",
+        "http://dont-scan.me",
+    ]);
+
+    let denylist: Vec<String> = args
+        .values_of("url_denylist")
+        .unwrap()
+        .map(String::from)
+        .collect();
+
+    assert_eq!(denylist, vec!["*.{png,woff}", "http://dont-scan.me"]);
+
+    let globs = build_dont_scan_glob_set(&denylist, false).unwrap();
+
+    assert_eq!(globs.len(), 1);
+    assert!(globs.is_match("/img/logo.png"));
+    assert!(globs.is_match("/fonts/font.woff"));
+    assert!(!globs.is_match("/js/app.js"));
+}
+
+#[test]
+#[should_panic]
+/// an invalid --dont-scan glob in the configuration should fail early via report_and_exit
+fn config_try_compile_globs_panics_on_invalid_dont_scan_pattern() {
+    let mut config = Configuration::new().unwrap();
+    config.url_denylist = vec!["*.[a-".to_string()];
+    Configuration::try_compile_globs(&mut config);
+}
+
+#[test]
+#[should_panic]
+/// unlimited recursion w/o a cap on requests per directory should fail early via report_and_exit
+fn config_validate_recursion_limits_panics_on_uncapped_infinite_depth() {
+    let mut config = Configuration::new().unwrap();
+    config.depth = 0;
+    Configuration::validate_recursion_limits(&config);
+}
+
+#[test]
+/// unlimited recursion is fine when capped, or when recursion is disabled entirely
+fn config_validate_recursion_limits_allows_capped_infinite_depth() {
+    let mut config = Configuration::new().unwrap();
+    Configuration::validate_recursion_limits(&config);
+
+    config.depth = 0;
+    config.max_requests_per_dir = 100;
+    Configuration::validate_recursion_limits(&config);
+
+    config.max_requests_per_dir = 0;
+    config.no_recursion = true;
+    Configuration::validate_recursion_limits(&config);
+}
+
+#[test]
+#[should_panic]
+/// an invalid --notify-on value should fail early via report_and_exit, rather than being skipped
+fn config_validate_notify_on_panics_on_invalid_value() {
+    let mut config = Configuration::new().unwrap();
+    config.notify_on = vec![String::from("200"), String::from("403:admin[")];
+    Configuration::validate_notify_on(&config);
+}
+
+#[test]
+/// status codes, url regexes, and a mix of the two are all valid --notify-on values
+fn config_validate_notify_on_allows_valid_values() {
+    let mut config = Configuration::new().unwrap();
+    Configuration::validate_notify_on(&config);
+
+    config.notify_on = vec![
+        String::from("200"),
+        String::from("admin"),
+        String::from("403:^/api"),
+    ];
+    Configuration::validate_notify_on(&config);
+}
+
+#[test]
+#[should_panic]
+/// output_format = "csv" w/o an output file (i.e. from a config file) should fail via
+/// report_and_exit
+fn config_validate_output_format_panics_on_csv_without_output() {
+    let mut config = Configuration::new().unwrap();
+    config.output_format = String::from("csv");
+    config.output = String::new();
+    Configuration::validate_output_format(&config);
+}
+
+#[test]
+#[should_panic]
+/// an unknown output_format value should fail via report_and_exit
+fn config_validate_output_format_panics_on_unknown_value() {
+    let mut config = Configuration::new().unwrap();
+    config.output_format = String::from("xml");
+    config.output = String::from("/some/path");
+    Configuration::validate_output_format(&config);
+}
+
+#[test]
+/// text and json don't need an output file, csv is fine once it has one
+fn config_validate_output_format_allows_known_values() {
+    let mut config = Configuration::new().unwrap();
+    Configuration::validate_output_format(&config);
+
+    config.output_format = String::from("json");
+    Configuration::validate_output_format(&config);
+
+    config.output_format = String::from("csv");
+    config.output = String::from("/some/path");
+    Configuration::validate_output_format(&config);
+}
+
+#[test]
+#[should_panic]
+/// an unknown transform_case value (i.e. from a config file) should fail via report_and_exit
+fn config_validate_transform_case_panics_on_unknown_value() {
+    let mut config = Configuration::new().unwrap();
+    config.transform_case = vec![String::from("upper"), String::from("Upper")];
+    Configuration::validate_transform_case(&config);
+}
+
+#[test]
+/// every value the cli accepts for --transform-case is also valid from a config file
+fn config_validate_transform_case_allows_known_values() {
+    let mut config = Configuration::new().unwrap();
+    Configuration::validate_transform_case(&config);
+
+    config.transform_case = vec![
+        String::from("lower"),
+        String::from("upper"),
+        String::from("capitalize"),
+        String::from("all"),
+    ];
+    Configuration::validate_transform_case(&config);
+}
+
+#[test]
+#[should_panic]
+/// collect_intel w/o extract_links (i.e. from a config file) should fail via report_and_exit
+fn config_validate_collect_intel_panics_without_extract_links() {
+    let mut config = Configuration::new().unwrap();
+    config.collect_intel = true;
+    config.extract_links = false;
+    Configuration::validate_collect_intel(&config);
+}
+
+#[test]
+/// collect_intel is fine alongside extract_links, and extract_links is fine on its own
+fn config_validate_collect_intel_allows_extract_links() {
+    let mut config = Configuration::new().unwrap();
+    Configuration::validate_collect_intel(&config);
+
+    config.extract_links = true;
+    Configuration::validate_collect_intel(&config);
+
+    config.collect_intel = true;
+    Configuration::validate_collect_intel(&config);
+}
+
+#[test]
+#[should_panic]
+/// a similarity_threshold above 100 (i.e. from a config file) should fail via report_and_exit
+fn config_validate_similarity_threshold_panics_above_100() {
+    let mut config = Configuration::new().unwrap();
+    config.similarity_threshold = 101;
+    Configuration::validate_similarity_threshold(&config);
+}
+
+#[test]
+#[should_panic]
+/// a similarity_threshold of 0 should fail via report_and_exit
+fn config_validate_similarity_threshold_panics_on_zero() {
+    let mut config = Configuration::new().unwrap();
+    config.similarity_threshold = 0;
+    Configuration::validate_similarity_threshold(&config);
+}
+
+#[test]
+/// the bounds of the 1-100 range are both valid thresholds
+fn config_validate_similarity_threshold_allows_bounds() {
+    let mut config = Configuration::new().unwrap();
+    Configuration::validate_similarity_threshold(&config);
+
+    config.similarity_threshold = 1;
+    Configuration::validate_similarity_threshold(&config);
+
+    config.similarity_threshold = 100;
+    Configuration::validate_similarity_threshold(&config);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_stdin() {
@@ -268,6 +758,27 @@ fn config_reads_dont_filter() {
     assert!(config.dont_filter);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_ignore_wildcard_warning() {
+    let config = setup_config_test();
+    assert!(config.ignore_wildcard_warning);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_auto_filter() {
+    let config = setup_config_test();
+    assert!(config.auto_filter);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_redirects_only() {
+    let config = setup_config_test();
+    assert!(config.redirects_only);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_add_slash() {
@@ -282,6 +793,20 @@ fn config_reads_extract_links() {
     assert!(config.extract_links);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_collect_intel() {
+    let config = setup_config_test();
+    assert!(config.collect_intel);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_decode_slashes() {
+    let config = setup_config_test();
+    assert!(config.decode_slashes);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_extensions() {
@@ -289,6 +814,13 @@ fn config_reads_extensions() {
     assert_eq!(config.extensions, vec!["html", "php", "js"]);
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_transform_case() {
+    let config = setup_config_test();
+    assert_eq!(config.transform_case, vec!["upper", "capitalize"]);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_url_denylist() {
@@ -299,6 +831,27 @@ fn config_reads_url_denylist() {
     );
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_dont_scan_case_sensitive() {
+    let config = setup_config_test();
+    assert!(config.dont_scan_case_sensitive);
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_notify_url() {
+    let config = setup_config_test();
+    assert_eq!(config.notify_url, "http://127.0.0.1:9999/webhook");
+}
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_notify_on() {
+    let config = setup_config_test();
+    assert_eq!(config.notify_on, vec!["200:admin", "403"]);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_filter_regex() {
@@ -341,6 +894,16 @@ fn config_reads_filter_status() {
     assert_eq!(config.filter_status, vec![201]);
 }
 
+#[test]
+/// parse the test config and see that the values parsed are correct
+fn config_reads_matchers() {
+    let config = setup_config_test();
+    assert_eq!(config.match_status, vec![200, 401]);
+    assert_eq!(config.match_size, vec![4096]);
+    assert_eq!(config.match_word_count, vec![12]);
+    assert_eq!(config.match_line_count, vec![3, 4]);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_save_state() {
@@ -355,6 +918,13 @@ fn config_reads_time_limit() {
     assert_eq!(config.time_limit, "10m");
 }
 
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_dry_run() {
+    let config = setup_config_test();
+    assert!(config.dry_run);
+}
+
 #[test]
 /// parse the test config and see that the value parsed is correct
 fn config_reads_resume_from() {
@@ -417,3 +987,10 @@ fn as_json_returns_json_representation_of_configuration_with_newline() {
     assert_eq!(json.timeout, config.timeout);
     assert_eq!(json.depth, config.depth);
 }
+
+#[test]
+/// parse the test config and see that the value parsed is correct
+fn config_reads_similarity_threshold() {
+    let config = setup_config_test();
+    assert_eq!(config.similarity_threshold, 80);
+}
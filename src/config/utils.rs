@@ -1,7 +1,12 @@
 use crate::{
     utils::{module_colorizer, status_colorizer},
-    DEFAULT_STATUS_CODES, DEFAULT_WORDLIST, VERSION,
+    DEFAULT_MAX_BODY_SIZE, DEFAULT_STATUS_CODES, DEFAULT_WORDLIST, SIMILARITY_THRESHOLD, VERSION,
 };
+use anyhow::Result;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use reqwest::Url;
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
 #[cfg(not(test))]
 use std::process::exit;
 
@@ -20,7 +25,7 @@ pub(super) fn report_and_exit(err: &str) -> ! {
     exit(1);
 }
 
-// functions timeout, threads, status_codes, user_agent, wordlist, save_state, and depth are used to provide
+// functions timeout, threads, status_codes, user_agent, http_version, wordlist, save_state, and depth are used to provide
 // defaults in the event that a ferox-config.toml is found but one or more of the values below
 // aren't listed in the config.  This way, we get the correct defaults upon Deserialization
 
@@ -53,8 +58,27 @@ pub(super) fn status_codes() -> Vec<u16> {
 }
 
 /// default wordlist
-pub(super) fn wordlist() -> String {
-    String::from(DEFAULT_WORDLIST)
+pub(super) fn wordlist() -> Vec<String> {
+    vec![String::from(DEFAULT_WORDLIST)]
+}
+
+/// deserialize `wordlist` from either a single path or a list of paths; config files and state
+/// files written before multiple wordlists were supported use a single path
+pub(super) fn deserialize_wordlist<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(path) => vec![path],
+        OneOrMany::Many(paths) => paths,
+    })
 }
 
 /// default user-agent
@@ -62,11 +86,128 @@ pub(super) fn user_agent() -> String {
     format!("feroxbuster/{}", VERSION)
 }
 
+/// default http version preference
+pub(super) fn http_version() -> String {
+    String::from("auto")
+}
+
+/// default output format
+pub(super) fn output_format() -> String {
+    String::from("text")
+}
+
 /// default recursion depth
 pub(super) fn depth() -> usize {
     4
 }
 
+/// default maximum response body size
+pub(super) fn max_body_size() -> usize {
+    DEFAULT_MAX_BODY_SIZE
+}
+
+/// default similarity threshold for --filter-similar-to
+pub(super) fn similarity_threshold() -> u32 {
+    SIMILARITY_THRESHOLD
+}
+
+/// compile the given glob patterns into a single `GlobSet`
+///
+/// `*` won't match across a `/`, use `**` to match any number of path segments
+pub(super) fn build_glob_set(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern).literal_separator(true).build()?;
+        builder.add(glob);
+    }
+
+    Ok(builder.build()?)
+}
+
+/// compile the --dont-scan values that aren't urls into a single `GlobSet`, matched against a
+/// url's path
+///
+/// patterns that start with a `/` are anchored to the start of the path (ex: `/cdn/**`), all
+/// others can match at any depth (ex: `*.png`); matching ignores case unless `case_sensitive`
+pub(super) fn build_dont_scan_glob_set(
+    denylist: &[String],
+    case_sensitive: bool,
+) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+
+    for entry in denylist {
+        if Url::parse(entry).is_ok() {
+            // full urls are handled by utils::should_deny_url
+            continue;
+        }
+
+        let pattern = if entry.starts_with('/') {
+            entry.to_string()
+        } else {
+            format!("**/{}", entry)
+        };
+
+        let glob = GlobBuilder::new(&pattern)
+            .literal_separator(true)
+            .case_insensitive(!case_sensitive)
+            .build()?;
+
+        builder.add(glob);
+    }
+
+    Ok(builder.build()?)
+}
+
+/// A secret value (--basic-auth, --bearer) that's redacted whenever the Configuration is logged
+///
+/// The secret is never serialized either, which keeps it out of --debug-log and --resume-from
+/// state files; a resumed scan needs its credentials passed in again
+#[derive(Clone, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct Credential(String);
+
+/// implementation of Credential
+impl Credential {
+    /// the secret itself
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// whether or not a secret was provided
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// create a Credential from a string slice
+impl From<&str> for Credential {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+/// Serialize implementation for Credential; always an empty string
+impl Serialize for Credential {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("")
+    }
+}
+
+/// Debug implementation for Credential; never shows the secret
+impl fmt::Debug for Credential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            write!(f, "\"\"")
+        } else {
+            write!(f, "\"[REDACTED]\"")
+        }
+    }
+}
+
 /// enum representing the three possible states for informational output (not logging verbosity)
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum OutputLevel {
@@ -1,10 +1,12 @@
 use std::sync::Arc;
 
 use reqwest::StatusCode;
-use tokio::sync::oneshot::Sender;
+use tokio::sync::{mpsc::UnboundedSender, oneshot::Sender};
 
+use super::FeroxEvent;
 use crate::response::FeroxResponse;
 use crate::{
+    extractor::Intel,
     statistics::{StatError, StatField},
     traits::FeroxFilter,
 };
@@ -21,6 +23,9 @@ pub enum Command {
     /// Add one to the proper field(s) based on the given `StatusCode`
     AddStatus(StatusCode),
 
+    /// Record the number of seconds the given url took to respond
+    AddResponseTime(String, f64),
+
     /// Create the progress bar (`BarType::Total`) that is updated from the stats thread
     CreateBar,
 
@@ -42,9 +47,24 @@ pub enum Command {
     /// Add a `FeroxFilter` implementor to `FilterHandler`'s instance of `FeroxFilters`
     AddFilter(Box<dyn FeroxFilter>),
 
+    /// Add to the extensions appended to each word by `ScopeHandler`'s instance of `Scope`
+    AddExtensions(Vec<String>),
+
+    /// Replace the requests per second limit held by `ScopeHandler`'s instance of `Scope`
+    SetRateLimit(usize),
+
     /// Send a `FeroxResponse` to the output handler for reporting
     Report(Box<FeroxResponse>),
 
+    /// Send an email address or comment (`Intel`) to the output handler for reporting
+    ReportIntel(Box<Intel>),
+
+    /// Send a `FeroxEvent` to each of the events handler's subscribers
+    Publish(Box<FeroxEvent>),
+
+    /// Add a transmitter to the events handler's subscribers
+    Subscribe(UnboundedSender<FeroxEvent>),
+
     /// Send a group of urls to be scanned (only used for the urls passed in explicitly by the user)
     ScanInitialUrls(Vec<String>),
 
@@ -1,6 +1,7 @@
 use super::*;
 use crate::config::Configuration;
 use crate::event_handlers::scans::ScanHandle;
+use crate::extractor::{CrawlDelays, RobotsCache};
 use crate::scan_manager::FeroxScans;
 use crate::Joiner;
 #[cfg(test)]
@@ -24,17 +25,33 @@ pub struct Tasks {
 
     /// JoinHandle for scans handler
     pub scans: Joiner,
+
+    /// JoinHandle for scope handler
+    pub scope: Joiner,
+
+    /// JoinHandle for events handler
+    pub events: Joiner,
 }
 
 /// Tasks implementation
 impl Tasks {
-    /// Given JoinHandles for terminal, statistics, and filters create a new Tasks object
-    pub fn new(terminal: Joiner, stats: Joiner, filters: Joiner, scans: Joiner) -> Self {
+    /// Given JoinHandles for terminal, statistics, filters, scans, scope, and events create a new
+    /// Tasks object
+    pub fn new(
+        terminal: Joiner,
+        stats: Joiner,
+        filters: Joiner,
+        scans: Joiner,
+        scope: Joiner,
+        events: Joiner,
+    ) -> Self {
         Self {
             terminal,
             stats,
             filters,
             scans,
+            scope,
+            events,
         }
     }
 }
@@ -54,25 +71,44 @@ pub struct Handles {
     /// Handle for Configuration
     pub config: Arc<Configuration>,
 
+    /// Handle for the parts of the configuration that can change mid-scan
+    pub scope: ScopeHandle,
+
+    /// Handle for the stream of `FeroxEvent`s; use `events.subscribe()` to receive them
+    pub events: EventsHandle,
+
     /// Handle for recursion
     pub scans: RwLock<Option<ScanHandle>>,
+
+    /// per-host cache of robots.txt paths
+    pub robots: RobotsCache,
+
+    /// per-host Crawl-delays from robots.txt (--respect-crawl-delay)
+    pub crawl_delays: CrawlDelays,
 }
 
 /// implementation of Handles
 impl Handles {
-    /// Given a StatsHandle, FiltersHandle, and OutputHandle, create a Handles object
+    /// Given a StatsHandle, FiltersHandle, OutputHandle, ScopeHandle, and EventsHandle, create a
+    /// Handles object
     pub fn new(
         stats: StatsHandle,
         filters: FiltersHandle,
         output: TermOutHandle,
         config: Arc<Configuration>,
+        scope: ScopeHandle,
+        events: EventsHandle,
     ) -> Self {
         Self {
             stats,
             filters,
             output,
             config,
+            scope,
+            events,
             scans: RwLock::new(None),
+            robots: RobotsCache::default(),
+            crawl_delays: CrawlDelays::default(),
         }
     }
 
@@ -93,7 +129,16 @@ impl Handles {
             tx.clone(),
         );
         let filters_handle = FiltersHandle::new(Arc::new(FeroxFilters::default()), tx.clone());
-        let handles = Self::new(stats_handle, filters_handle, terminal_handle, configuration);
+        let scope_handle = ScopeHandle::new(Arc::new(Scope::new(&configuration)), tx.clone());
+        let events_handle = EventsHandle::new(tx.clone());
+        let handles = Self::new(
+            stats_handle,
+            filters_handle,
+            terminal_handle,
+            configuration,
+            scope_handle,
+            events_handle,
+        );
         if let Some(sh) = scanned_urls {
             let scan_handle = ScanHandle::new(sh, tx);
             handles.set_scan_handle(scan_handle);
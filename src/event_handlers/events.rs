@@ -0,0 +1,268 @@
+use super::*;
+use crate::{response::FeroxResponse, CommandSender, FeroxChannel, Joiner};
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::{
+    mpsc::{self, UnboundedReceiver, UnboundedSender},
+    oneshot,
+};
+
+/// Typed progress/result events published over the course of a scan
+///
+/// These are meant for applications that embed feroxbuster and want to render their own UI
+/// instead of parsing terminal output; see `EventsHandle::subscribe`. The CLI's own terminal
+/// output is rendered from this same stream
+#[derive(Debug, Clone)]
+pub enum FeroxEvent {
+    /// the scan of the given url started sending requests
+    ScanStarted {
+        /// url being scanned
+        url: String,
+    },
+
+    /// a response made it past all filters and was reported
+    UrlFound {
+        /// url of the response
+        url: String,
+
+        /// status code of the response
+        status: u16,
+
+        /// content-length of the response
+        content_length: u64,
+
+        /// number of lines in the response's body
+        line_count: usize,
+
+        /// number of words in the response's body
+        word_count: usize,
+
+        /// the response itself, as reported (its body has already been dropped)
+        response: Arc<FeroxResponse>,
+    },
+
+    /// a directory was found and a recursive scan of it was queued
+    DirectoryRecursed {
+        /// url of the directory
+        url: String,
+
+        /// number of directories deeper than the initial target the directory is
+        depth: usize,
+    },
+
+    /// the scan of the given url ran to completion
+    ScanFinished {
+        /// url that was scanned
+        url: String,
+    },
+}
+
+/// implementation of FeroxEvent
+impl FeroxEvent {
+    /// create a `FeroxEvent::UrlFound` from the given response
+    pub(crate) fn url_found(response: FeroxResponse) -> Self {
+        FeroxEvent::UrlFound {
+            url: response.url().to_string(),
+            status: response.status().as_u16(),
+            content_length: response.content_length(),
+            line_count: response.line_count(),
+            word_count: response.word_count(),
+            response: Arc::new(response),
+        }
+    }
+
+    /// url the event is about
+    pub fn url(&self) -> &str {
+        match self {
+            FeroxEvent::ScanStarted { url }
+            | FeroxEvent::UrlFound { url, .. }
+            | FeroxEvent::DirectoryRecursed { url, .. }
+            | FeroxEvent::ScanFinished { url } => url,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Container for the events transmitter
+pub struct EventsHandle {
+    /// transmitter used to publish events and register subscribers
+    pub tx: CommandSender,
+}
+
+/// implementation of EventsHandle
+impl EventsHandle {
+    /// Given a CommandSender, create a new EventsHandle
+    pub fn new(tx: CommandSender) -> Self {
+        Self { tx }
+    }
+
+    /// Send the given Command over `tx`
+    pub fn send(&self, command: Command) -> Result<()> {
+        self.tx.send(command)?;
+        Ok(())
+    }
+
+    /// Sync the handle with the handler
+    pub async fn sync(&self) -> Result<()> {
+        let (tx, rx) = oneshot::channel::<bool>();
+        self.send(Command::Sync(tx))?;
+        rx.await?;
+        Ok(())
+    }
+
+    /// Register a new receiver for `FeroxEvent`s
+    ///
+    /// every receiver gets its own copy of each event published after it subscribed; dropping
+    /// the receiver unsubscribes it
+    pub fn subscribe(&self) -> UnboundedReceiver<FeroxEvent> {
+        let (tx, rx) = mpsc::unbounded_channel::<FeroxEvent>();
+
+        self.send(Command::Subscribe(tx))
+            .unwrap_or_else(|e| log::warn!("Could not subscribe to events: {}", e));
+
+        rx
+    }
+
+    /// Send the given event to every subscriber
+    pub fn publish(&self, event: FeroxEvent) {
+        self.send(Command::Publish(Box::new(event)))
+            .unwrap_or_else(|e| log::warn!("Could not publish event: {}", e));
+    }
+}
+
+/// event handler that sends each published `FeroxEvent` to every subscriber
+#[derive(Debug)]
+pub struct EventsHandler {
+    /// transmitters for each receiver handed out by `EventsHandle::subscribe`
+    subscribers: Vec<UnboundedSender<FeroxEvent>>,
+
+    /// Receiver half of mpsc from which `Command`s are processed
+    receiver: UnboundedReceiver<Command>,
+}
+
+/// implementation of event handler for events
+impl EventsHandler {
+    /// create new event handler
+    pub fn new(receiver: UnboundedReceiver<Command>) -> Self {
+        Self {
+            subscribers: Vec::new(),
+            receiver,
+        }
+    }
+
+    /// Initialize the sc side of an mpsc channel that is responsible for publishing events to
+    /// subscribers
+    pub fn initialize() -> (Joiner, EventsHandle) {
+        log::trace!("enter: initialize");
+
+        let (tx, rx): FeroxChannel<Command> = mpsc::unbounded_channel();
+
+        let mut handler = Self::new(rx);
+
+        let task = tokio::spawn(async move { handler.start().await });
+
+        let event_handle = EventsHandle::new(tx);
+
+        log::trace!("exit: initialize -> ({:?}, {:?})", task, event_handle);
+
+        (task, event_handle)
+    }
+
+    /// Start a single consumer task (sc side of mpsc)
+    ///
+    /// The consumer simply receives `Command` and acts accordingly
+    pub async fn start(&mut self) -> Result<()> {
+        log::trace!("enter: start({:?})", self);
+
+        while let Some(command) = self.receiver.recv().await {
+            match command {
+                Command::Subscribe(subscriber) => {
+                    self.subscribers.push(subscriber);
+                }
+                Command::Publish(event) => {
+                    log::trace!("publishing {:?}", event);
+
+                    // forget any subscribers whose receiver has been dropped
+                    self.subscribers
+                        .retain(|subscriber| subscriber.send(*event.clone()).is_ok());
+                }
+                Command::Sync(sender) => {
+                    sender.send(true).unwrap_or_default();
+                }
+                Command::Exit => break,
+                _ => {} // no other commands needed for EventsHandler
+            }
+        }
+
+        log::trace!("exit: start");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// each subscriber should receive every event published after subscribing, and dropped
+    /// subscribers should be forgotten
+    async fn publish_sends_events_to_each_subscriber() {
+        let (task, handle) = EventsHandler::initialize();
+
+        let early = FeroxEvent::ScanStarted {
+            url: String::from("http://localhost/early"),
+        };
+        handle.publish(early);
+
+        let mut first = handle.subscribe();
+        let mut second = handle.subscribe();
+        let dropped = handle.subscribe();
+        drop(dropped);
+
+        handle.publish(FeroxEvent::ScanFinished {
+            url: String::from("http://localhost/"),
+        });
+        handle.sync().await.unwrap();
+
+        for rx in [&mut first, &mut second].iter_mut() {
+            let event = rx.recv().now_or_never().flatten().unwrap();
+            assert!(matches!(event, FeroxEvent::ScanFinished { .. }));
+            assert_eq!(event.url(), "http://localhost/");
+
+            // events published before subscribing aren't received
+            assert!(rx.recv().now_or_never().is_none());
+        }
+
+        handle.send(Command::Exit).unwrap();
+        task.await.unwrap().unwrap();
+
+        // the handler is gone, so are the subscribers' transmitters
+        assert!(first.recv().await.is_none());
+    }
+
+    #[test]
+    /// UrlFound should be populated from the response
+    fn url_found_uses_response_values() {
+        let event = FeroxEvent::url_found(FeroxResponse::default());
+
+        match event {
+            FeroxEvent::UrlFound {
+                url,
+                status,
+                content_length,
+                line_count,
+                word_count,
+                response,
+            } => {
+                assert_eq!(url, "http://localhost/");
+                assert_eq!(status, 200);
+                assert_eq!(content_length, 0);
+                assert_eq!(line_count, 0);
+                assert_eq!(word_count, 0);
+                assert_eq!(response.url().as_str(), url);
+            }
+            _ => panic!("expected UrlFound"),
+        }
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     SLEEP_DURATION,
 };
 use anyhow::Result;
-use console::style;
+use console::{style, user_attended};
 use crossterm::event::{self, Event, KeyCode};
 use std::{
     sync::{
@@ -33,7 +33,8 @@ pub struct TermInputHandler {
 ///
 /// kicks off the following handlers related to terminal input:
 ///     ctrl+c handler that saves scan state to disk
-///     enter handler that listens for enter during scans to drop into interactive scan cancel menu
+///     enter handler that listens for enter during scans to drop into the interactive scan
+///     management menu (only when a user is around to answer it)
 impl TermInputHandler {
     /// Create new event handler
     pub fn new(handles: Arc<Handles>) -> Self {
@@ -53,7 +54,10 @@ impl TermInputHandler {
 
     /// wrapper around sigint_handler and enter_handler
     fn start(&self) {
-        tokio::task::spawn_blocking(Self::enter_handler);
+        if user_attended() {
+            // no sense in pausing for a menu nobody will answer (output piped elsewhere etc)
+            tokio::task::spawn_blocking(Self::enter_handler);
+        }
 
         if self.handles.config.save_state {
             // start the ctrl+c handler
@@ -4,13 +4,19 @@ mod filters;
 mod container;
 mod command;
 mod outputs;
+mod notify;
 mod scans;
 mod inputs;
+mod events;
+mod scope;
 
 pub use self::command::Command;
 pub use self::container::{Handles, Tasks};
+pub use self::events::{EventsHandle, EventsHandler, FeroxEvent};
 pub use self::filters::{FiltersHandle, FiltersHandler};
 pub use self::inputs::{TermInputHandler, SCAN_COMPLETE};
+pub(crate) use self::notify::NotifyCriterion;
 pub use self::outputs::{TermOutHandle, TermOutHandler};
 pub use self::scans::{ScanHandle, ScanHandler};
+pub use self::scope::{Scope, ScopeHandle, ScopeHandler};
 pub use self::statistics::{StatsHandle, StatsHandler};
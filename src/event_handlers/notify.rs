@@ -0,0 +1,318 @@
+use super::*;
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use anyhow::Result;
+use regex::Regex;
+use reqwest::{header::CONTENT_TYPE, Client};
+use serde::Serialize;
+
+use crate::{client, config::Configuration, response::FeroxResponse, CommandReceiver};
+
+/// minimum amount of time between two notifications sent to the webhook; findings that arrive
+/// in between are batched into the next notification
+pub(super) const NOTIFY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A single rule parsed from `--notify-on`
+#[derive(Debug)]
+pub(crate) enum NotifyCriterion {
+    /// notify when the response has the given status code (ex: `200`)
+    Status(u16),
+
+    /// notify when the response's url matches the given regex (ex: `admin`)
+    Url(Regex),
+
+    /// notify when both the status code and the url match (ex: `200:admin`)
+    StatusAndUrl(u16, Regex),
+}
+
+/// implementation of NotifyCriterion
+impl NotifyCriterion {
+    /// parse a single `--notify-on` value
+    ///
+    /// a value that is entirely a status code is treated as such, a value of the form
+    /// `STATUS:REGEX` requires both to match, and anything else is treated as a regex that
+    /// is matched against the response's url
+    pub(crate) fn parse(raw: &str) -> Result<Self> {
+        if let Ok(status) = raw.parse::<u16>() {
+            return Ok(Self::Status(status));
+        }
+
+        if let Some((status, pattern)) = raw.split_once(':') {
+            if let Ok(status) = status.parse::<u16>() {
+                return Ok(Self::StatusAndUrl(status, Regex::new(pattern)?));
+            }
+        }
+
+        Ok(Self::Url(Regex::new(raw)?))
+    }
+
+    /// determine whether or not the given response satisfies this criterion
+    fn is_match(&self, response: &FeroxResponse) -> bool {
+        let status = response.status().as_u16();
+
+        match self {
+            Self::Status(code) => *code == status,
+            Self::Url(regex) => regex.is_match(response.url().as_str()),
+            Self::StatusAndUrl(code, regex) => {
+                *code == status && regex.is_match(response.url().as_str())
+            }
+        }
+    }
+}
+
+/// Summary of a single response, as sent to the webhook
+#[derive(Debug, Serialize, PartialEq)]
+struct Finding {
+    /// url of the response
+    url: String,
+
+    /// status code of the response
+    status: u16,
+
+    /// content length of the response
+    size: u64,
+}
+
+/// Body of the POST request sent to the webhook
+///
+/// `text` is included so that chat services (Slack, Mattermost, etc) can display the notification
+/// without any additional configuration
+#[derive(Debug, Serialize)]
+struct Notification<'a> {
+    /// human readable summary of the findings
+    text: String,
+
+    /// findings collected since the last notification was sent
+    findings: &'a [Finding],
+}
+
+#[derive(Debug)]
+/// Event handler for webhook notifications (--notify-url)
+pub struct NotifyHandler {
+    /// notification handler's receiver
+    receiver: CommandReceiver,
+
+    /// pointer to "global" configuration struct
+    config: Arc<Configuration>,
+
+    /// client used to talk to the webhook; separate from the scan's client, as the user's
+    /// proxy and headers are meant for the target, not the webhook (unless --proxy-all is used,
+    /// in which case the proxy is used here too)
+    client: Client,
+
+    /// parsed `--notify-on` values; an empty list means every reported response is sent
+    criteria: Vec<NotifyCriterion>,
+
+    /// findings waiting to be sent
+    pending: Vec<Finding>,
+}
+
+/// implementation of NotifyHandler
+impl NotifyHandler {
+    /// Given a receiver and the global configuration, create a NotifyHandler
+    pub(super) fn new(receiver: CommandReceiver, config: Arc<Configuration>) -> Result<Self> {
+        // --proxy-all means nothing is allowed to go out w/o the proxy, webhooks included
+        let proxy = if config.proxy_all {
+            Some(config.proxy.as_str())
+        } else {
+            None
+        };
+
+        let client = client::initialize(
+            config.timeouts(),
+            &config.user_agent,
+            false,
+            config.tls_verification(),
+            "auto",
+            &HashMap::new(),
+            None,
+            proxy,
+        )?;
+
+        // values were already validated when the configuration was built
+        let criteria = config
+            .notify_on
+            .iter()
+            .map(|raw| NotifyCriterion::parse(raw))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            receiver,
+            config,
+            client,
+            criteria,
+            pending: Vec::new(),
+        })
+    }
+
+    /// determine whether or not the given response should be sent to the webhook
+    fn should_notify(&self, response: &FeroxResponse) -> bool {
+        self.criteria.is_empty() || self.criteria.iter().any(|c| c.is_match(response))
+    }
+
+    /// Spawn a single consumer task (sc side of mpsc)
+    ///
+    /// The consumer receives responses from the terminal handler and queues the ones that match
+    /// `--notify-on`; queued findings are sent at most once per `NOTIFY_INTERVAL`
+    pub(super) async fn start(&mut self) -> Result<()> {
+        log::trace!("enter: start_notify_handler");
+
+        let mut interval = tokio::time::interval(NOTIFY_INTERVAL);
+
+        loop {
+            tokio::select! {
+                command = self.receiver.recv() => {
+                    match command {
+                        Some(Command::Report(response)) => {
+                            if self.should_notify(&response) {
+                                self.pending.push(Finding {
+                                    url: response.url().to_string(),
+                                    status: response.status().as_u16(),
+                                    size: response.content_length(),
+                                });
+                            }
+                        }
+                        Some(Command::Sync(sender)) => {
+                            sender.send(true).unwrap_or_default();
+                        }
+                        Some(Command::Exit) | None => {
+                            break;
+                        }
+                        _ => {} // no more needed
+                    }
+                }
+                _ = interval.tick() => {
+                    self.flush().await;
+                }
+            }
+        }
+
+        // send anything that came in since the last tick
+        self.flush().await;
+
+        log::trace!("exit: start_notify_handler");
+        Ok(())
+    }
+
+    /// send all pending findings to the webhook
+    ///
+    /// failure to notify is never fatal to the scan, it's only logged
+    async fn flush(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        let findings = std::mem::take(&mut self.pending);
+
+        let mut text = format!("feroxbuster found {} matching response(s)", findings.len());
+
+        for finding in &findings {
+            text.push_str(&format!("\n{} {}", finding.status, finding.url));
+        }
+
+        let notification = Notification {
+            text,
+            findings: &findings,
+        };
+
+        let body = match serde_json::to_string(&notification) {
+            Ok(body) => body,
+            Err(e) => {
+                log::warn!("Could not serialize notification: {}", e);
+                return;
+            }
+        };
+
+        let result = self
+            .client
+            .post(&self.config.notify_url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if !response.status().is_success() => {
+                log::warn!(
+                    "Webhook at {} responded with {} when sending {} finding(s)",
+                    self.config.notify_url,
+                    response.status(),
+                    findings.len()
+                );
+            }
+            Ok(_) => {
+                log::debug!("sent {} finding(s) to webhook", findings.len());
+            }
+            Err(e) => {
+                log::warn!(
+                    "Could not send {} finding(s) to webhook at {}: {}",
+                    findings.len(),
+                    self.config.notify_url,
+                    e
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+
+    /// helper to create a response with the given url and status code
+    fn response(url: &str, status: StatusCode) -> FeroxResponse {
+        let mut response = FeroxResponse::default();
+        response.set_url(url);
+        response.set_status(status);
+        response
+    }
+
+    #[test]
+    /// a bare status code, a bare regex, and STATUS:REGEX should parse to their respective variants
+    fn notify_criterion_parses_each_variant() {
+        assert!(matches!(
+            NotifyCriterion::parse("200").unwrap(),
+            NotifyCriterion::Status(200)
+        ));
+        assert!(matches!(
+            NotifyCriterion::parse("admin").unwrap(),
+            NotifyCriterion::Url(_)
+        ));
+        assert!(matches!(
+            NotifyCriterion::parse("200:admin").unwrap(),
+            NotifyCriterion::StatusAndUrl(200, _)
+        ));
+        assert!(NotifyCriterion::parse("[invalid").is_err());
+    }
+
+    #[test]
+    /// STATUS:REGEX should only match when both the status and the url match
+    fn notify_criterion_matches_status_and_url() {
+        let criterion = NotifyCriterion::parse("200:admin").unwrap();
+
+        assert!(criterion.is_match(&response("http://localhost/admin", StatusCode::OK)));
+        assert!(!criterion.is_match(&response("http://localhost/admin", StatusCode::FORBIDDEN)));
+        assert!(!criterion.is_match(&response("http://localhost/stuff", StatusCode::OK)));
+    }
+
+    #[test]
+    /// a handler without criteria notifies on everything, otherwise any criterion may match
+    fn notify_handler_should_notify_respects_criteria() {
+        let (_, rx) = tokio::sync::mpsc::unbounded_channel::<Command>();
+        let config = Arc::new(Configuration::new().unwrap());
+        let mut handler = NotifyHandler::new(rx, config).unwrap();
+
+        assert!(handler.should_notify(&response("http://localhost/stuff", StatusCode::OK)));
+
+        handler.criteria = vec![
+            NotifyCriterion::parse("403").unwrap(),
+            NotifyCriterion::parse("admin").unwrap(),
+        ];
+
+        assert!(handler.should_notify(&response("http://localhost/stuff", StatusCode::FORBIDDEN)));
+        assert!(handler.should_notify(&response("http://localhost/admin", StatusCode::OK)));
+        assert!(!handler.should_notify(&response("http://localhost/stuff", StatusCode::OK)));
+    }
+}
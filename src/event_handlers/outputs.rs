@@ -1,17 +1,25 @@
+use super::notify::NotifyHandler;
 use super::Command::AddToUsizeField;
 use super::*;
+use std::collections::HashSet;
 
 use anyhow::{Context, Result};
-use tokio::sync::{mpsc, oneshot};
+use futures::FutureExt;
+use tokio::sync::{
+    mpsc::{self, UnboundedReceiver},
+    oneshot,
+};
 
 use crate::{
-    config::Configuration,
+    config::{Configuration, OutputLevel},
+    extractor::IntelKind,
     progress::PROGRESS_PRINTER,
+    response::{FeroxResponse, CSV_HEADER},
     scanner::RESPONSES,
     send_command, skip_fail,
-    statistics::StatField::ResourcesDiscovered,
+    statistics::StatField::{IntelFound, ResourcesDiscovered},
     traits::FeroxSerialize,
-    utils::{ferox_print, fmt_err, make_request, open_file, write_to},
+    utils::{ferox_print, fmt_err, open_file, write_csv_row, write_to},
     CommandReceiver, CommandSender, Joiner,
 };
 use std::sync::Arc;
@@ -81,15 +89,31 @@ impl FileOutHandler {
     async fn start(&mut self, tx_stats: CommandSender) -> Result<()> {
         log::trace!("enter: start_file_handler({:?})", tx_stats);
 
+        let csv = self.config.output_format == "csv";
+
         let mut file = open_file(&self.config.output)?;
 
+        if csv && file.get_ref().metadata()?.len() == 0 {
+            // only new files get a header, appending to an existing csv shouldn't add another
+            write_csv_row(CSV_HEADER, &mut file)?;
+        }
+
         log::info!("Writing scan results to {}", self.config.output);
 
+        // this is the only task writing to the file, so rows are written one at a time, in the
+        // order they're received
         while let Some(command) = self.receiver.recv().await {
             match command {
+                Command::Report(response) if csv => {
+                    skip_fail!(write_csv_row(&response.as_csv(), &mut file));
+                }
                 Command::Report(response) => {
                     skip_fail!(write_to(&*response, &mut file, self.config.json));
                 }
+                Command::ReportIntel(_) if csv => {} // intel doesn't fit the csv columns
+                Command::ReportIntel(intel) => {
+                    skip_fail!(write_to(&*intel, &mut file, self.config.json));
+                }
                 Command::Exit => {
                     break;
                 }
@@ -103,7 +127,10 @@ impl FileOutHandler {
         // close the file before we tell statistics to save current data to the same file
         drop(file);
 
-        send_command!(tx_stats, Command::Save);
+        if !csv {
+            // statistics would show up as a malformed row at the bottom of a csv
+            send_command!(tx_stats, Command::Save);
+        }
 
         log::trace!("exit: start_file_handler");
         Ok(())
@@ -122,8 +149,23 @@ pub struct TermOutHandler {
     /// optional file handler task
     file_task: Option<Joiner>,
 
+    /// notification handler
+    tx_notify: CommandSender,
+
+    /// optional notification handler task
+    notify_task: Option<Joiner>,
+
+    /// handle used to publish reported responses to the event stream
+    events: EventsHandle,
+
+    /// this handler's subscription to the event stream, which is what's rendered to the terminal
+    rx_events: UnboundedReceiver<FeroxEvent>,
+
     /// pointer to "global" configuration struct
     config: Arc<Configuration>,
+
+    /// intel (--collect-intel) that's already been reported, used to only report it once
+    seen_intel: HashSet<(IntelKind, String)>,
 }
 
 /// implementation of TermOutHandler
@@ -134,25 +176,42 @@ impl TermOutHandler {
         receiver: CommandReceiver,
         tx_file: CommandSender,
         file_task: Option<Joiner>,
+        tx_notify: CommandSender,
+        notify_task: Option<Joiner>,
+        events: EventsHandle,
         config: Arc<Configuration>,
     ) -> Self {
+        // subscribed before anything can be published, so nothing is missed
+        let rx_events = events.subscribe();
+
         Self {
             receiver,
             tx_file,
             file_task,
+            tx_notify,
+            notify_task,
+            events,
+            rx_events,
             config,
+            seen_intel: HashSet::new(),
         }
     }
 
-    /// Creates all required output handlers (terminal, file) and updates the given Handles/Tasks
+    /// Creates all required output handlers (terminal, file, notify) and updates the given
+    /// Handles/Tasks
+    ///
+    /// the terminal handler is one of the event stream's subscribers; reported responses are
+    /// published to the stream and printed once they come back around
     pub fn initialize(
         config: Arc<Configuration>,
         tx_stats: CommandSender,
+        events: EventsHandle,
     ) -> (Joiner, TermOutHandle) {
         log::trace!("enter: initialize({:?}, {:?})", config, tx_stats);
 
         let (tx_term, rx_term) = mpsc::unbounded_channel::<Command>();
         let (tx_file, rx_file) = mpsc::unbounded_channel::<Command>();
+        let (tx_notify, rx_notify) = mpsc::unbounded_channel::<Command>();
 
         let mut file_handler = FileOutHandler::new(rx_file, config.clone());
 
@@ -167,7 +226,30 @@ impl TermOutHandler {
             None
         };
 
-        let mut term_handler = Self::new(rx_term, tx_file.clone(), file_task, config);
+        let notify_task = if !config.notify_url.is_empty() {
+            // --notify-url used, need to spawn the thread for sending findings to the webhook
+            match NotifyHandler::new(rx_notify, config.clone()) {
+                Ok(mut notify_handler) => {
+                    Some(tokio::spawn(async move { notify_handler.start().await }))
+                }
+                Err(e) => {
+                    log::warn!("Could not create the --notify-url handler: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let mut term_handler = Self::new(
+            rx_term,
+            tx_file.clone(),
+            file_task,
+            tx_notify,
+            notify_task,
+            events,
+            config,
+        );
         let term_task = tokio::spawn(async move { term_handler.start(tx_stats).await });
 
         let event_handle = TermOutHandle::new(tx_term, tx_file);
@@ -179,75 +261,199 @@ impl TermOutHandler {
 
     /// Start a single consumer task (sc side of mpsc)
     ///
-    /// The consumer simply receives `Command` and acts accordingly
+    /// The consumer receives `Command`s and acts accordingly, while rendering the events that
+    /// come back from the event stream
     async fn start(&mut self, tx_stats: CommandSender) -> Result<()> {
         log::trace!("enter: start({:?})", tx_stats);
 
-        while let Some(command) = self.receiver.recv().await {
-            match command {
-                Command::Report(mut resp) => {
-                    let contains_sentry =
-                        self.config.status_codes.contains(&resp.status().as_u16());
-                    let unknown_sentry = !RESPONSES.contains(&resp); // !contains == unknown
-                    let should_process_response = contains_sentry && unknown_sentry;
-
-                    if should_process_response {
-                        // print to stdout
-                        ferox_print(&resp.as_str(), &PROGRESS_PRINTER);
-
-                        send_command!(tx_stats, AddToUsizeField(ResourcesDiscovered, 1));
-
-                        if self.file_task.is_some() {
-                            // -o used, need to send the report to be written out to disk
-                            self.tx_file
-                                .send(Command::Report(resp.clone()))
-                                .with_context(|| {
-                                    fmt_err(&format!("Could not send {} to file handler", resp))
-                                })?;
+        loop {
+            tokio::select! {
+                command = self.receiver.recv() => {
+                    match command {
+                        Some(Command::Exit) | None => {
+                            break;
                         }
+                        Some(command) => self.handle_command(command, &tx_stats).await?,
                     }
-                    log::trace!("report complete: {}", resp.url());
-
-                    if self.config.replay_client.is_some() && should_process_response {
-                        // replay proxy specified/client created and this response's status code is one that
-                        // should be replayed; not using logged_request due to replay proxy client
-                        make_request(
-                            self.config.replay_client.as_ref().unwrap(),
-                            &resp.url(),
-                            self.config.output_level,
-                            tx_stats.clone(),
-                        )
-                        .await
-                        .with_context(|| "Could not replay request through replay proxy")?;
-                    }
+                }
+                Some(event) = self.rx_events.recv() => {
+                    self.render(event);
+                }
+            }
+        }
 
-                    if should_process_response {
-                        // add response to RESPONSES for serialization in case of ctrl+c
-                        // placed all by its lonesome like this so that RESPONSES can take ownership
-                        // of the FeroxResponse
+        // anything reported before Exit still needs to make it to the terminal
+        self.flush_events().await;
 
-                        // before ownership is transferred, there's no real reason to keep the body anymore
-                        // so we can free that piece of data, reducing memory usage
-                        resp.drop_text();
+        if self.file_task.is_some() && self.tx_file.send(Command::Exit).is_ok() {
+            self.file_task.as_mut().unwrap().await??; // wait for death
+        }
+        if self.notify_task.is_some() && self.tx_notify.send(Command::Exit).is_ok() {
+            // pending findings are sent before the notify handler exits
+            self.notify_task.as_mut().unwrap().await??;
+        }
+
+        log::trace!("exit: start");
+        Ok(())
+    }
+
+    /// act on a single `Command` received by the terminal handler
+    async fn handle_command(&mut self, command: Command, tx_stats: &CommandSender) -> Result<()> {
+        match command {
+            Command::Report(mut resp) => {
+                // --match-status codes are reported even when they're not in --status-codes;
+                // the MatchFilter already made sure nothing else got this far
+                let status = resp.status().as_u16();
+                let contains_sentry = self.config.status_codes.contains(&status)
+                    || self.config.match_status.contains(&status);
+                let unknown_sentry = !RESPONSES.contains(&resp); // !contains == unknown
+                let should_process_response = contains_sentry && unknown_sentry;
+
+                if should_process_response {
+                    send_command!(tx_stats, AddToUsizeField(ResourcesDiscovered, 1));
+
+                    if self.file_task.is_some() {
+                        // -o used, need to send the report to be written out to disk
+                        self.tx_file
+                            .send(Command::Report(resp.clone()))
+                            .with_context(|| {
+                                fmt_err(&format!("Could not send {} to file handler", resp))
+                            })?;
+                    }
 
-                        RESPONSES.insert(*resp);
+                    if self.notify_task.is_some() {
+                        // --notify-url used, the notify handler decides whether or not this
+                        // response matches --notify-on
+                        send_command!(self.tx_notify, Command::Report(resp.clone()));
                     }
                 }
-                Command::Sync(sender) => {
-                    sender.send(true).unwrap_or_default();
+                log::trace!("report complete: {}", resp.url());
+
+                if should_process_response {
+                    self.replay(&resp).await;
                 }
-                Command::Exit => {
-                    if self.file_task.is_some() && self.tx_file.send(Command::Exit).is_ok() {
-                        self.file_task.as_mut().unwrap().await??; // wait for death
-                    }
-                    break;
+
+                if should_process_response {
+                    // add response to RESPONSES for serialization in case of ctrl+c
+                    // placed all by its lonesome like this so that RESPONSES can take ownership
+                    // of the FeroxResponse
+
+                    // before ownership is transferred, there's no real reason to keep the body anymore
+                    // so we can free that piece of data, reducing memory usage
+                    resp.drop_text();
+
+                    // printed to stdout once it comes back from the event stream, see render
+                    self.events
+                        .publish(FeroxEvent::url_found(resp.as_ref().clone()));
+
+                    RESPONSES.insert(*resp);
+                }
+            }
+            Command::ReportIntel(intel) => {
+                if !self
+                    .seen_intel
+                    .insert((intel.kind(), intel.value().to_string()))
+                {
+                    // already reported, possibly from a different response
+                    return Ok(());
+                }
+
+                ferox_print(&self.terminal_repr(&*intel), &PROGRESS_PRINTER);
+
+                send_command!(tx_stats, AddToUsizeField(IntelFound, 1));
+
+                if self.file_task.is_some() {
+                    send_command!(self.tx_file, Command::ReportIntel(intel));
                 }
-                _ => {} // no more commands needed
             }
+            Command::Sync(sender) => {
+                // syncing means everything reported so far is on the terminal
+                self.flush_events().await;
+                sender.send(true).unwrap_or_default();
+            }
+            _ => {} // no more commands needed
         }
-        log::trace!("exit: start");
+
         Ok(())
     }
+
+    /// show the given event on the terminal; only found urls are printed, everything else is
+    /// already reflected by the progress bars
+    fn render(&self, event: FeroxEvent) {
+        if let FeroxEvent::UrlFound { response, .. } = event {
+            ferox_print(&self.terminal_repr(&*response), &PROGRESS_PRINTER);
+        }
+    }
+
+    /// render every event that was published before this call, but hasn't made it back from
+    /// the event stream yet
+    async fn flush_events(&mut self) {
+        if let Err(e) = self.events.sync().await {
+            log::warn!("Could not sync with the events handler: {}", e);
+            return;
+        }
+
+        // after the sync, every event published so far is already waiting in rx_events;
+        // unconstrained keeps tokio's cooperative budget from cutting the loop short
+        while let Some(Some(event)) =
+            tokio::task::unconstrained(self.rx_events.recv()).now_or_never()
+        {
+            self.render(event);
+        }
+    }
+
+    /// Create the representation of the given item that's shown on the terminal
+    ///
+    /// when --silent and --json are used together, each item is shown as a single line of
+    /// json, otherwise the normal text representation is used
+    fn terminal_repr<T: FeroxSerialize>(&self, item: &T) -> String {
+        if self.config.json && matches!(self.config.output_level, OutputLevel::Silent) {
+            match item.as_json() {
+                Ok(json) => return json,
+                Err(e) => log::warn!("{}", e),
+            }
+        }
+
+        item.as_str()
+    }
+
+    /// Re-send the request that produced the given response through the replay proxy, as long
+    /// as --replay-proxy was used and the response's status code is one of --replay-codes
+    ///
+    /// The replayed response is thrown away; it's never reported, extracted from, recursed
+    /// into, or counted in the scan's statistics. Failing to replay is logged, and doesn't stop
+    /// any other responses from being reported
+    async fn replay(&self, resp: &FeroxResponse) {
+        let client = match &self.config.replay_client {
+            Some(client) => client,
+            None => return,
+        };
+
+        if !self.config.replay_codes.contains(&resp.status().as_u16()) {
+            return;
+        }
+
+        // not using make_request/logged_request, as both would add the replayed response to
+        // the scan's statistics
+        match client.get(resp.url().to_owned()).send().await {
+            Ok(replayed) => {
+                log::debug!(
+                    "replayed {} through {} -> {}",
+                    resp.url(),
+                    self.config.replay_proxy,
+                    replayed.status()
+                );
+            }
+            Err(e) => {
+                log::warn!(
+                    "Could not replay {} through replay proxy {}: {}",
+                    resp.url(),
+                    self.config.replay_proxy,
+                    e
+                );
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -271,6 +477,9 @@ mod tests {
     async fn struct_fields_of_term_out_handler() {
         let (tx, rx) = mpsc::unbounded_channel::<Command>();
         let (tx_file, _) = mpsc::unbounded_channel::<Command>();
+        let (tx_notify, _) = mpsc::unbounded_channel::<Command>();
+        let (tx_events, _) = mpsc::unbounded_channel::<Command>();
+        let (_, rx_events) = mpsc::unbounded_channel::<FeroxEvent>();
         let config = Arc::new(Configuration::new().unwrap());
 
         let toh = TermOutHandler {
@@ -278,6 +487,11 @@ mod tests {
             file_task: None,
             receiver: rx,
             tx_file,
+            tx_notify,
+            notify_task: None,
+            events: EventsHandle::new(tx_events),
+            rx_events,
+            seen_intel: HashSet::new(),
         };
 
         println!("{:?}", toh);
@@ -63,7 +63,8 @@ pub struct ScanHandler {
     /// Maximum recursion depth, a depth of 0 is infinite recursion
     max_depth: usize,
 
-    /// depths associated with the initial targets provided by the user
+    /// depths associated with the initial targets provided by the user, along with any
+    /// directories found via multi-segment wordlist entries
     depths: Vec<(String, usize)>,
 
     /// Bounded semaphore used as a barrier to limit concurrent scans
@@ -250,6 +251,22 @@ impl ScanHandler {
         Ok(())
     }
 
+    /// determine whether or not the given directory's path matches any of the globs passed to
+    /// --dont-recurse-path
+    ///
+    /// paths are always compared with a trailing slash, i.e. `/static/`
+    fn should_skip_recursion(&self, url: &Url) -> bool {
+        let globs = &self.handles.config.dont_recurse_globs;
+
+        if globs.is_empty() {
+            return false;
+        }
+
+        let path = format!("{}/", url.path().trim_end_matches('/'));
+
+        globs.is_match(&path)
+    }
+
     async fn try_recursion(&mut self, response: Box<FeroxResponse>) -> Result<()> {
         log::trace!("enter: try_recursion({:?})", response,);
 
@@ -259,22 +276,59 @@ impl ScanHandler {
         }
 
         let mut base_depth = 1_usize;
+        let mut longest_match = 0;
 
         for (base_url, base_url_depth) in &self.depths {
-            if response.url().as_str().starts_with(base_url) {
+            // the most specific base url wins, as it may account for directories added by a
+            // multi-segment wordlist entry
+            if response.url().as_str().starts_with(base_url) && base_url.len() >= longest_match {
+                longest_match = base_url.len();
                 base_depth = *base_url_depth;
             }
         }
 
+        let depth = response.current_depth(base_depth, self.handles.clone());
+
         if response.reached_max_depth(base_depth, self.max_depth, self.handles.clone()) {
             // at or past recursion depth
             return Ok(());
         }
 
+        if self.should_skip_recursion(response.url()) {
+            // matched --dont-recurse-path; the response has already been reported, so all that's
+            // left is to let FeroxScans know about the directory so it's never requested again
+            self.data
+                .add_skipped_directory_scan(response.url().as_str(), ScanOrder::Latest);
+
+            log::info!(
+                "Not recursing into {} (--dont-recurse-path)",
+                response.url()
+            );
+            log::trace!("exit: try_recursion");
+            return Ok(());
+        }
+
+        if response.extra_depth() > 0 {
+            // i.e. a directory found via api/v1/users; anything found beneath it needs to use the
+            // same offset, otherwise api/ and v1/ would count towards its recursion depth
+            let url = FeroxUrl::from_url(response.url(), self.handles.clone());
+            self.depths
+                .push((url.normalize(), base_depth + response.extra_depth()));
+        }
+
         let targets = vec![response.url().to_string()];
         self.ordered_scan_url(targets, ScanOrder::Latest).await?;
 
-        log::info!("Added new directory to recursive scan: {}", response.url());
+        self.handles.events.publish(FeroxEvent::DirectoryRecursed {
+            url: response.url().to_string(),
+            depth,
+        });
+
+        log::info!(
+            "Added new directory to recursive scan: {} (depth {})",
+            response.url(),
+            depth
+        );
 
         log::trace!("exit: try_recursion");
         Ok(())
@@ -0,0 +1,206 @@
+use super::*;
+use crate::{config::Configuration, CommandSender, FeroxChannel, Joiner};
+use anyhow::Result;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, RwLock,
+};
+use tokio::sync::{
+    mpsc::{self, UnboundedReceiver},
+    oneshot,
+};
+
+/// The parts of a scan's `Configuration` that can be changed while the scan is running (from the
+/// interactive menu); the values here are read before each request is made
+#[derive(Debug, Default)]
+pub struct Scope {
+    /// extensions appended to each word (-x|--extensions)
+    extensions: RwLock<Vec<String>>,
+
+    /// requests per second allowed per directory, 0 meaning no limit (--rate-limit)
+    rate_limit: AtomicUsize,
+}
+
+/// implementation of Scope
+impl Scope {
+    /// create a new Scope, starting from the values given on the command line/config file
+    pub fn new(config: &Configuration) -> Self {
+        Self {
+            extensions: RwLock::new(config.extensions.clone()),
+            rate_limit: AtomicUsize::new(config.rate_limit),
+        }
+    }
+
+    /// current list of extensions
+    pub fn extensions(&self) -> Vec<String> {
+        self.extensions
+            .read()
+            .map(|extensions| extensions.clone())
+            .unwrap_or_default()
+    }
+
+    /// current number of extensions
+    pub fn num_extensions(&self) -> usize {
+        self.extensions
+            .read()
+            .map(|extensions| extensions.len())
+            .unwrap_or_default()
+    }
+
+    /// current requests per second limit, 0 meaning no limit
+    pub fn rate_limit(&self) -> usize {
+        self.rate_limit.load(Ordering::Relaxed)
+    }
+
+    /// add any of the given extensions that aren't already known
+    fn add_extensions(&self, new: Vec<String>) {
+        if let Ok(mut extensions) = self.extensions.write() {
+            for extension in new {
+                if !extensions.contains(&extension) {
+                    extensions.push(extension);
+                }
+            }
+        }
+    }
+
+    /// replace the requests per second limit
+    fn set_rate_limit(&self, limit: usize) {
+        self.rate_limit.store(limit, Ordering::Relaxed);
+    }
+}
+
+#[derive(Debug)]
+/// Container for scope transmitter and Scope object
+pub struct ScopeHandle {
+    /// Scope object used across modules to read the current scope
+    pub data: Arc<Scope>,
+
+    /// transmitter used to update `data`
+    pub tx: CommandSender,
+}
+
+/// implementation of ScopeHandle
+impl ScopeHandle {
+    /// Given an Arc-wrapped Scope and CommandSender, create a new ScopeHandle
+    pub fn new(data: Arc<Scope>, tx: CommandSender) -> Self {
+        Self { data, tx }
+    }
+
+    /// Send the given Command over `tx`
+    pub fn send(&self, command: Command) -> Result<()> {
+        self.tx.send(command)?;
+        Ok(())
+    }
+
+    /// Sync the handle with the handler
+    pub async fn sync(&self) -> Result<()> {
+        let (tx, rx) = oneshot::channel::<bool>();
+        self.send(Command::Sync(tx))?;
+        rx.await?;
+        Ok(())
+    }
+}
+
+/// event handler for updating the parts of the configuration that can change mid-scan
+#[derive(Debug)]
+pub struct ScopeHandler {
+    /// the current Scope
+    data: Arc<Scope>,
+
+    /// Receiver half of mpsc from which `Command`s are processed
+    receiver: UnboundedReceiver<Command>,
+}
+
+/// implementation of event handler for scope
+impl ScopeHandler {
+    /// create new event handler
+    pub fn new(data: Arc<Scope>, receiver: UnboundedReceiver<Command>) -> Self {
+        Self { data, receiver }
+    }
+
+    /// Initialize a new `Scope` and the sc side of an mpsc channel that is responsible for
+    /// updates to the aforementioned object.
+    pub fn initialize(config: Arc<Configuration>) -> (Joiner, ScopeHandle) {
+        log::trace!("enter: initialize");
+
+        let data = Arc::new(Scope::new(&config));
+        let (tx, rx): FeroxChannel<Command> = mpsc::unbounded_channel();
+
+        let mut handler = Self::new(data.clone(), rx);
+
+        let task = tokio::spawn(async move { handler.start().await });
+
+        let event_handle = ScopeHandle::new(data, tx);
+
+        log::trace!("exit: initialize -> ({:?}, {:?})", task, event_handle);
+
+        (task, event_handle)
+    }
+
+    /// Start a single consumer task (sc side of mpsc)
+    ///
+    /// The consumer simply receives `Command` and acts accordingly
+    pub async fn start(&mut self) -> Result<()> {
+        log::trace!("enter: start({:?})", self);
+
+        while let Some(command) = self.receiver.recv().await {
+            match command {
+                Command::AddExtensions(extensions) => {
+                    self.data.add_extensions(extensions);
+                }
+                Command::SetRateLimit(limit) => {
+                    self.data.set_rate_limit(limit);
+                }
+                Command::Sync(sender) => {
+                    log::debug!("scope: {:?}", self);
+                    sender.send(true).unwrap_or_default();
+                }
+                Command::Exit => break,
+                _ => {} // no other commands needed for ScopeHandler
+            }
+        }
+
+        log::trace!("exit: start");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// extensions and rate limits sent to the handler should be reflected in its Scope, without
+    /// duplicating already known extensions
+    async fn scope_handler_applies_commands() {
+        let config = Configuration {
+            extensions: vec![String::from("php")],
+            rate_limit: 100,
+            ..Default::default()
+        };
+
+        let (task, handle) = ScopeHandler::initialize(Arc::new(config));
+
+        assert_eq!(handle.data.extensions(), vec![String::from("php")]);
+        assert_eq!(handle.data.rate_limit(), 100);
+
+        handle
+            .send(Command::AddExtensions(vec![
+                String::from("php"),
+                String::from("bak"),
+            ]))
+            .unwrap();
+        handle.send(Command::SetRateLimit(0)).unwrap();
+        handle.sync().await.unwrap();
+
+        assert_eq!(
+            handle.data.extensions(),
+            vec![String::from("php"), String::from("bak")]
+        );
+        assert_eq!(handle.data.num_extensions(), 2);
+        assert_eq!(handle.data.rate_limit(), 0);
+
+        handle.send(Command::Exit).unwrap();
+        task.await.unwrap().unwrap();
+    }
+}
@@ -96,6 +96,9 @@ impl StatsHandler {
                     self.stats.add_request();
                     self.increment_bar();
                 }
+                Command::AddResponseTime(url, seconds) => {
+                    self.stats.add_response_time(&url, seconds);
+                }
                 Command::Save => {
                     self.stats
                         .save(start.elapsed().as_secs_f64(), output_file)?;
@@ -138,7 +141,7 @@ impl StatsHandler {
 
     /// Wrapper around incrementing the overall scan's progress bar
     fn increment_bar(&self) {
-        let msg = format!(
+        let mut msg = format!(
             "{}:{:<7} {}:{:<7}",
             style("found").green(),
             self.stats.resources_discovered(),
@@ -146,6 +149,24 @@ impl StatsHandler {
             self.stats.errors(),
         );
 
+        let intel_found = self.stats.intel_found();
+
+        if intel_found > 0 {
+            // only shown once there's something to show, as it requires --collect-intel
+            msg.push_str(&format!(" {}:{:<7}", style("intel").magenta(), intel_found));
+        }
+
+        let throttled = self.stats.throttled();
+
+        if throttled > 0 {
+            // only shown once there's something to show, as it requires --auto-throttle
+            msg.push_str(&format!(
+                " {}:{:<7}",
+                style("throttled").yellow(),
+                throttled
+            ));
+        }
+
         self.bar.set_message(&msg);
         self.bar.inc(1);
     }
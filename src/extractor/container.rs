@@ -1,3 +1,6 @@
+use super::intel::{is_probably_email, normalize_comment};
+use super::links::LinkExtractor;
+use super::robots::{RobotsCache, RobotsEntry, RobotsTxt};
 use super::*;
 use crate::utils::should_deny_url;
 use crate::{
@@ -15,9 +18,9 @@ use crate::{
     url::FeroxUrl,
     utils::{logged_request, make_request},
 };
-use anyhow::{bail, Context, Result};
-use reqwest::{StatusCode, Url};
-use std::collections::HashSet;
+use anyhow::{bail, Result};
+use reqwest::{Client, StatusCode, Url};
+use std::{collections::HashSet, time::Duration};
 use tokio::sync::oneshot;
 
 /// Whether an active scan is recursive or not
@@ -39,6 +42,12 @@ pub struct Extractor<'a> {
     /// `ROBOTS_TXT_REGEX` as a regex::Regex type
     pub(super) robots_regex: Regex,
 
+    /// `EMAIL_REGEX` as a regex::Regex type
+    pub(super) email_regex: Regex,
+
+    /// `HTML_COMMENT_REGEX` as a regex::Regex type
+    pub(super) comment_regex: Regex,
+
     /// Response from which to extract links
     pub(super) response: Option<&'a FeroxResponse>,
 
@@ -133,6 +142,7 @@ impl<'a> Extractor<'a> {
     }
 
     /// Given a `reqwest::Response`, perform the following actions
+    ///   - parse the `Path` attribute of any `Set-Cookie` headers
     ///   - parse the response's text for links using the linkfinder regex
     ///   - for every link found take its url path and parse each sub-path
     ///     - example: Response contains a link fragment `homepage/assets/img/icons/handshake.svg`
@@ -145,44 +155,32 @@ impl<'a> Extractor<'a> {
     pub(super) async fn extract_from_body(&self) -> Result<HashSet<String>> {
         log::trace!("enter: get_links");
 
-        let mut links = HashSet::<String>::new();
+        let response = self.response.unwrap();
+        let link_extractor = self.link_extractor()?;
 
-        let body = self.response.unwrap().text();
+        // cookies scoped to a path point at a directory, no matter what the body looks like
+        let mut links = link_extractor.extract_from_set_cookie(response.headers());
 
-        for capture in self.links_regex.captures_iter(&body) {
-            // remove single & double quotes from both ends of the capture
-            // capture[0] is the entire match, additional capture groups start at [1]
-            let link = capture[0].trim_matches(|c| c == '\'' || c == '"');
+        if response.is_text() {
+            let body = response.text();
 
-            match Url::parse(link) {
-                Ok(absolute) => {
-                    if absolute.domain() != self.response.unwrap().url().domain()
-                        || absolute.host() != self.response.unwrap().url().host()
-                    {
-                        // domains/ips are not the same, don't scan things that aren't part of the original
-                        // target url
-                        continue;
-                    }
+            let (body_links, errors) = link_extractor.extract_counting_errors(&body);
 
-                    if self.add_all_sub_paths(absolute.path(), &mut links).is_err() {
-                        log::warn!("could not add sub-paths from {} to {:?}", absolute, links);
-                    }
-                }
-                Err(e) => {
-                    // this is the expected error that happens when we try to parse a url fragment
-                    //     ex: Url::parse("/login") -> Err("relative URL without a base")
-                    // while this is technically an error, these are good results for us
-                    if e.to_string().contains("relative URL without a base") {
-                        if self.add_all_sub_paths(link, &mut links).is_err() {
-                            log::warn!("could not add sub-paths from {} to {:?}", link, links);
-                        }
-                    } else {
-                        // unexpected error has occurred
-                        log::warn!("Could not parse given url: {}", e);
-                        self.handles.stats.send(AddError(Other)).unwrap_or_default();
-                    }
-                }
+            for _ in 0..errors {
+                self.handles.stats.send(AddError(Other)).unwrap_or_default();
             }
+
+            links.extend(body_links);
+
+            if self.handles.config.collect_intel {
+                self.collect_intel(&body);
+            }
+        } else {
+            // images, fonts, archives, etc don't have any links worth finding
+            log::debug!(
+                "skipping extraction from non-text response: {}",
+                response.url()
+            );
         }
 
         self.update_stats(links.len())?;
@@ -192,97 +190,119 @@ impl<'a> Extractor<'a> {
         Ok(links)
     }
 
+    /// search the given body for email addresses and html comments (--collect-intel) and send
+    /// anything found to the output handler
+    ///
+    /// intel is only ever reported, it's never requested or recursed into; deduplication
+    /// happens in the output handler, as it sees the intel from every response
+    pub(super) fn collect_intel(&self, body: &str) {
+        log::trace!("enter: collect_intel");
+
+        let url = self.response.unwrap().url().as_str();
+        let output_level = self.handles.config.output_level;
+
+        let mut found = Vec::new();
+
+        for email in self.email_regex.find_iter(body) {
+            if is_probably_email(email.as_str()) {
+                found.push(Intel::new(
+                    IntelKind::Email,
+                    email.as_str(),
+                    url,
+                    output_level,
+                ));
+            }
+        }
+
+        for capture in self.comment_regex.captures_iter(body) {
+            // capture[0] is the entire match, the comment's text is in [1]
+            if let Some(comment) = normalize_comment(&capture[1]) {
+                found.push(Intel::new(IntelKind::Comment, &comment, url, output_level));
+            }
+        }
+
+        for intel in found {
+            if let Err(e) = self
+                .handles
+                .output
+                .send(Command::ReportIntel(Box::new(intel)))
+            {
+                log::warn!("Could not send intel to output handler: {}", e);
+            }
+        }
+
+        log::trace!("exit: collect_intel");
+    }
+
     /// take a url fragment like homepage/assets/img/icons/handshake.svg and
     /// incrementally add
     ///     - homepage/assets/img/icons/
     ///     - homepage/assets/img/
     ///     - homepage/assets/
     ///     - homepage/
-    fn add_all_sub_paths(&self, url_path: &str, mut links: &mut HashSet<String>) -> Result<()> {
-        log::trace!("enter: add_all_sub_paths({}, {:?})", url_path, links);
-
-        for sub_path in self.get_sub_paths_from_path(url_path) {
-            self.add_link_to_set_of_links(&sub_path, &mut links)?;
-        }
-
-        log::trace!("exit: add_all_sub_paths");
-        Ok(())
+    pub(super) fn add_all_sub_paths(
+        &self,
+        url_path: &str,
+        links: &mut HashSet<String>,
+    ) -> Result<()> {
+        self.link_extractor()?.add_all_sub_paths(url_path, links)
     }
 
     /// Iterate over a given path, return a list of every sub-path found
     ///
-    /// example: `path` contains a link fragment `homepage/assets/img/icons/handshake.svg`
-    /// the following fragments would be returned:
-    ///   - homepage/assets/img/icons/handshake.svg
-    ///   - homepage/assets/img/icons/
-    ///   - homepage/assets/img/
-    ///   - homepage/assets/
-    ///   - homepage/
+    /// see `LinkExtractor::sub_paths`; nothing is returned when the base url can't be parsed
+    #[cfg(test)]
     pub(super) fn get_sub_paths_from_path(&self, path: &str) -> Vec<String> {
-        log::trace!("enter: get_sub_paths_from_path({})", path);
-        let mut paths = vec![];
-
-        // filter out any empty strings caused by .split
-        let mut parts: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
-
-        let length = parts.len();
-
-        for i in 0..length {
-            // iterate over all parts of the path
-            if parts.is_empty() {
-                // pop left us with an empty vector, we're done
-                break;
-            }
-
-            let mut possible_path = parts.join("/");
-
-            if possible_path.is_empty() {
-                // .join can result in an empty string, which we don't need, ignore
-                continue;
-            }
-
-            if i > 0 {
-                // this isn't the last index of the parts array
-                // ex: /buried/misc/stupidfile.php
-                // this block skips the file but sees all parent folders
-                possible_path = format!("{}/", possible_path);
+        match self.link_extractor() {
+            Ok(extractor) => extractor.sub_paths(path),
+            Err(e) => {
+                log::warn!("{}", e);
+                Vec::new()
             }
+        }
+    }
 
-            paths.push(possible_path); // good sub-path found
-            parts.pop(); // use .pop() to remove the last part of the path and continue iteration
+    /// url against which any links found during extraction are resolved
+    fn base_url(&self) -> Result<Url> {
+        match self.target {
+            ExtractionTarget::ResponseBody => Ok(self.response.unwrap().url().clone()),
+            ExtractionTarget::RobotsTxt => match Url::parse(&self.url) {
+                Ok(u) => Ok(u),
+                Err(e) => {
+                    bail!("Could not parse {}: {}", self.url, e);
+                }
+            },
         }
+    }
 
-        log::trace!("exit: get_sub_paths_from_path -> {:?}", paths);
-        paths
+    /// the scanner-independent part of extraction, configured w/ this Extractor's base url and
+    /// scoped to the target of the scan that the base url belongs to (if known)
+    fn link_extractor(&self) -> Result<LinkExtractor> {
+        let base_url = self.base_url()?;
+
+        let scope_url = self
+            .handles
+            .ferox_scans()
+            .ok()
+            .and_then(|scans| scans.get_initial_scan_by_url(base_url.as_str()))
+            .and_then(|scan| Url::parse(scan.url()).ok());
+
+        Ok(LinkExtractor {
+            links_regex: self.links_regex.clone(),
+            base_url,
+            scope_url,
+            decode_slashes: self.handles.config.decode_slashes,
+        })
     }
 
     /// simple helper to stay DRY, trys to join a url + fragment and add it to the `links` HashSet
+    #[cfg(test)]
     pub(super) fn add_link_to_set_of_links(
         &self,
         link: &str,
         links: &mut HashSet<String>,
     ) -> Result<()> {
-        log::trace!("enter: add_link_to_set_of_links({}, {:?})", link, links);
-
-        let old_url = match self.target {
-            ExtractionTarget::ResponseBody => self.response.unwrap().url().clone(),
-            ExtractionTarget::RobotsTxt => match Url::parse(&self.url) {
-                Ok(u) => u,
-                Err(e) => {
-                    bail!("Could not parse {}: {}", self.url, e);
-                }
-            },
-        };
-
-        let new_url = old_url
-            .join(&link)
-            .with_context(|| format!("Could not join {} with {}", old_url, link))?;
-
-        links.insert(new_url.to_string());
-
-        log::trace!("exit: add_link_to_set_of_links");
-
-        Ok(())
+        self.link_extractor()?.add_link_to_set_of_links(link, links)
     }
 
     /// Wrapper around link extraction logic
@@ -322,10 +342,18 @@ impl<'a> Extractor<'a> {
         }
 
         // make the request and store the response
-        let new_response = logged_request(&new_url, self.handles.clone()).await?;
-
-        let new_ferox_response =
-            FeroxResponse::from(new_response, true, self.handles.config.output_level).await;
+        let (new_response, hops) =
+            client::with_redirect_hops(logged_request(&new_url, self.handles.clone())).await;
+        let new_response = new_response?;
+
+        let mut new_ferox_response = FeroxResponse::from(
+            new_response,
+            true,
+            self.handles.config.max_body_size,
+            self.handles.config.output_level,
+        )
+        .await;
+        new_ferox_response.set_redirect_origin(&new_url, hops);
 
         log::trace!("exit: request_link -> {:?}", new_ferox_response);
 
@@ -340,20 +368,23 @@ impl<'a> Extractor<'a> {
     ///     http://localhost/stuff/things
     /// this function requests:
     ///     http://localhost/robots.txt
+    ///
+    /// robots.txt is only requested once per host; afterwards, its paths come from the
+    /// `RobotsCache` shared by the entire scan
     pub(super) async fn extract_from_robots(&self) -> Result<HashSet<String>> {
         log::trace!("enter: extract_robots_txt");
 
         let mut links: HashSet<String> = HashSet::new();
 
-        let response = self.request_robots_txt().await?;
+        let base_url = Url::parse(&self.url)?;
 
-        for capture in self.robots_regex.captures_iter(response.text()) {
-            if let Some(new_path) = capture.name("url_path") {
-                let mut new_url = Url::parse(&self.url)?;
-                new_url.set_path(new_path.as_str());
-                if self.add_all_sub_paths(&new_url.path(), &mut links).is_err() {
-                    log::warn!("could not add sub-paths from {} to {:?}", new_url, links);
-                }
+        let robots = self.cached_robots_txt(&base_url).await?;
+
+        for new_path in robots.iter().flat_map(|robots| robots.paths.iter()) {
+            let mut new_url = base_url.clone();
+            new_url.set_path(new_path);
+            if self.add_all_sub_paths(&new_url.path(), &mut links).is_err() {
+                log::warn!("could not add sub-paths from {} to {:?}", new_url, links);
             }
         }
 
@@ -363,6 +394,75 @@ impl<'a> Extractor<'a> {
         Ok(links)
     }
 
+    /// get the Crawl-delay from the robots.txt of the host being scanned, if it has one
+    ///
+    /// shares the `RobotsCache` w/ `extract_from_robots`, so robots.txt is still only requested
+    /// once per host
+    pub async fn crawl_delay(&self) -> Result<Option<Duration>> {
+        log::trace!("enter: crawl_delay");
+
+        let base_url = Url::parse(&self.url)?;
+
+        let delay = self
+            .cached_robots_txt(&base_url)
+            .await?
+            .and_then(|robots| robots.crawl_delay);
+
+        log::trace!("exit: crawl_delay -> {:?}", delay);
+        Ok(delay)
+    }
+
+    /// return the given url's host's entry in the `RobotsCache`, requesting robots.txt on a miss
+    async fn cached_robots_txt(&self, base_url: &Url) -> Result<RobotsEntry> {
+        self.handles
+            .robots
+            .get_or_fetch(&RobotsCache::key(base_url), || self.robots_txt())
+            .await
+    }
+
+    /// request /robots.txt and return the paths found in its Allow/Disallow rules, along w/ its
+    /// Crawl-delay
+    ///
+    /// returns None when the host doesn't serve a robots.txt (i.e. a non-2xx status code), so
+    /// that the absence can be cached as well
+    async fn robots_txt(&self) -> Result<RobotsEntry> {
+        log::trace!("enter: robots_txt");
+
+        let response = self.request_robots_txt().await?;
+
+        if !response.status().is_success() {
+            log::debug!("no robots.txt found at {}", response.url());
+            log::trace!("exit: robots_txt -> None");
+            return Ok(None);
+        }
+
+        let paths: Vec<String> = self
+            .robots_regex
+            .captures_iter(response.text())
+            .filter_map(|capture| capture.name("url_path"))
+            .map(|new_path| new_path.as_str().to_string())
+            .collect();
+
+        let robots = RobotsTxt {
+            paths,
+            crawl_delay: RobotsTxt::parse_crawl_delay(response.text()),
+        };
+
+        log::trace!("exit: robots_txt -> {:?}", robots);
+        Ok(Some(Arc::new(robots)))
+    }
+
+    /// client used to request robots.txt
+    ///
+    /// more often than not, domain/robots.txt will redirect to www.domain/robots.txt or something
+    /// similar; to account for that, the client follows redirects, regardless of what the user
+    /// specified for the scanning client. Other than redirects, it's built from the same settings
+    /// as the scanning client, its proxy included
+    pub(super) fn robots_client(&self) -> Result<Client> {
+        let follow_redirects = true;
+        self.handles.config.build_client(follow_redirects)
+    }
+
     /// helper function that simply requests /robots.txt on the given url's base url
     ///
     /// example:
@@ -373,26 +473,7 @@ impl<'a> Extractor<'a> {
     pub(super) async fn request_robots_txt(&self) -> Result<FeroxResponse> {
         log::trace!("enter: get_robots_file");
 
-        // more often than not, domain/robots.txt will redirect to www.domain/robots.txt or something
-        // similar; to account for that, create a client that will follow redirects, regardless of
-        // what the user specified for the scanning client. Other than redirects, it will respect
-        // all other user specified settings
-        let follow_redirects = true;
-
-        let proxy = if self.handles.config.proxy.is_empty() {
-            None
-        } else {
-            Some(self.handles.config.proxy.as_str())
-        };
-
-        let client = client::initialize(
-            self.handles.config.timeout,
-            &self.handles.config.user_agent,
-            follow_redirects,
-            self.handles.config.insecure,
-            &self.handles.config.headers,
-            proxy,
-        )?;
+        let client = self.robots_client()?;
 
         let mut url = Url::parse(&self.url)?;
         url.set_path("/robots.txt"); // overwrite existing path with /robots.txt
@@ -406,8 +487,13 @@ impl<'a> Extractor<'a> {
         )
         .await?;
 
-        let ferox_response =
-            FeroxResponse::from(response, true, self.handles.config.output_level).await;
+        let ferox_response = FeroxResponse::from(
+            response,
+            true,
+            self.handles.config.max_body_size,
+            self.handles.config.output_level,
+        )
+        .await;
 
         log::trace!("exit: get_robots_file -> {}", ferox_response);
         Ok(ferox_response)
@@ -0,0 +1,195 @@
+use anyhow::Context;
+use console::{style, Color};
+use serde::{Deserialize, Serialize};
+
+use crate::{config::OutputLevel, traits::FeroxSerialize, utils::fmt_err};
+
+/// Regular expression used to find email addresses in a response body
+pub(super) const EMAIL_REGEX: &str =
+    r"[a-zA-Z0-9._%+-]+@[a-zA-Z0-9-]+(?:\.[a-zA-Z0-9-]+)*\.[a-zA-Z]{2,}";
+
+/// Regular expression used to find html comments in a response body; the comment's text is
+/// captured in the first capture group
+pub(super) const HTML_COMMENT_REGEX: &str = r"(?s)<!--(.*?)-->";
+
+/// file extensions that look like a top-level domain when they follow an `@`, i.e. the
+/// `logo@2x.png` naming scheme used for high-resolution images
+const NOT_A_TLD: [&str; 8] = ["png", "jpg", "jpeg", "gif", "svg", "webp", "css", "js"];
+
+/// Which kind of intel was found
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IntelKind {
+    /// an email address
+    Email,
+
+    /// the text of an html comment
+    Comment,
+}
+
+/// Representation of a single piece of intel (--collect-intel), found in a response's body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Intel {
+    #[serde(rename = "type")]
+    /// Name of this type of struct, used for serialization, i.e. `{"type":"intel"}`
+    kind_type: String,
+
+    /// what kind of intel this is
+    kind: IntelKind,
+
+    /// the email address or comment text
+    value: String,
+
+    /// url of the response in which the intel was found
+    url: String,
+
+    /// whether or not only the value should be shown (--silent)
+    #[serde(skip)]
+    output_level: OutputLevel,
+}
+
+/// implementation of Intel
+impl Intel {
+    /// create a new Intel of the given kind, found in the response from `url`
+    pub fn new(kind: IntelKind, value: &str, url: &str, output_level: OutputLevel) -> Self {
+        Self {
+            kind_type: String::from("intel"),
+            kind,
+            value: value.to_string(),
+            url: url.to_string(),
+            output_level,
+        }
+    }
+
+    /// getter for kind
+    pub fn kind(&self) -> IntelKind {
+        self.kind
+    }
+
+    /// getter for value
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// getter for url
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+/// given the text of an email regex match, determine whether or not it's likely to be a real
+/// email address
+pub(super) fn is_probably_email(candidate: &str) -> bool {
+    let tld = candidate.rsplit('.').next().unwrap_or_default();
+    !NOT_A_TLD.contains(&tld.to_lowercase().as_str())
+}
+
+/// given the text of an html comment, return it w/ all whitespace collapsed, or None if the
+/// comment isn't worth reporting (empty or an IE conditional comment)
+pub(super) fn normalize_comment(comment: &str) -> Option<String> {
+    let collapsed = comment.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if collapsed.is_empty() || collapsed.starts_with("[if ") || collapsed.starts_with("<![endif]") {
+        return None;
+    }
+
+    Some(collapsed)
+}
+
+/// Implementation of FeroxSerialize for Intel
+impl FeroxSerialize for Intel {
+    /// Create a string representation of the intel; tagged w/ INT so that it stands apart from
+    /// the url findings
+    ///
+    /// ex: INT    email admin@localhost.com http://localhost/contact
+    fn as_str(&self) -> String {
+        if matches!(self.output_level, OutputLevel::Silent) {
+            return format!("{}\n", self.value);
+        }
+
+        let kind = match self.kind {
+            IntelKind::Email => "email",
+            IntelKind::Comment => "comment",
+        };
+
+        format!(
+            "{} {:>9} {} {}\n",
+            style("INT").bg(Color::Magenta).black(),
+            kind,
+            style(&self.value).magenta(),
+            self.url
+        )
+    }
+
+    /// Create an NDJSON representation of the intel
+    ///
+    /// (expanded for clarity)
+    /// ex:
+    /// {
+    ///   "type": "intel",
+    ///   "kind": "email",
+    ///   "value": "admin@localhost.com",
+    ///   "url": "http://localhost/contact"
+    /// }\n
+    fn as_json(&self) -> anyhow::Result<String> {
+        let mut json = serde_json::to_string(&self)
+            .with_context(|| fmt_err(&format!("Could not convert {} to JSON", self.value)))?;
+        json.push('\n');
+        Ok(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// image names using the @2x convention shouldn't be treated as email addresses
+    fn is_probably_email_ignores_image_names() {
+        assert!(is_probably_email("admin@localhost.com"));
+        assert!(is_probably_email("first.last@sub.example.co.uk"));
+        assert!(!is_probably_email("logo@2x.png"));
+        assert!(!is_probably_email("sprite@3x.JPG"));
+    }
+
+    #[test]
+    /// comments should have their whitespace collapsed, while empty and conditional comments
+    /// are ignored
+    fn normalize_comment_collapses_and_ignores() {
+        assert_eq!(
+            normalize_comment("  TODO: remove\n   admin backdoor  "),
+            Some("TODO: remove admin backdoor".to_string())
+        );
+        assert_eq!(normalize_comment("   \n "), None);
+        assert_eq!(
+            normalize_comment("[if lt IE 9]><script src=\"x.js\"></script><![endif]"),
+            None
+        );
+    }
+
+    #[test]
+    /// as_json should tag the entry as intel, as_str should only show the value when silent
+    fn intel_as_json_and_as_str() {
+        let intel = Intel::new(
+            IntelKind::Email,
+            "admin@localhost.com",
+            "http://localhost/contact",
+            OutputLevel::Default,
+        );
+
+        assert_eq!(
+            intel.as_json().unwrap(),
+            "{\"type\":\"intel\",\"kind\":\"email\",\"value\":\"admin@localhost.com\",\"url\":\"http://localhost/contact\"}\n"
+        );
+        assert!(intel.as_str().contains("INT"));
+        assert!(intel.as_str().contains("http://localhost/contact"));
+
+        let silent = Intel::new(
+            IntelKind::Comment,
+            "TODO",
+            "http://localhost/",
+            OutputLevel::Silent,
+        );
+        assert_eq!(silent.as_str(), "TODO\n");
+    }
+}
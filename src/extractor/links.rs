@@ -0,0 +1,585 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Context, Result};
+use regex::Regex;
+use reqwest::{
+    header::{HeaderMap, SET_COOKIE},
+    Url,
+};
+
+use super::builder::LINKFINDER_REGEX;
+use crate::url::FeroxUrl;
+
+/// Extracts links from arbitrary text, relative to a base url
+///
+/// This is the core of `--extract-links`, without any of the scanner's machinery attached: no
+/// requests are made and nothing is sent to the statistics, recursion, or output handlers.
+/// It's meant for use as a library, i.e.
+///
+/// ```
+/// use feroxbuster::extractor::LinkExtractorBuilder;
+///
+/// let extractor = LinkExtractorBuilder::default()
+///     .base_url("http://localhost/app/index.html")
+///     .build()
+///     .unwrap();
+///
+/// let links = extractor.extract(r#"<script src="/js/main.js"></script>"#);
+///
+/// assert!(links.contains("http://localhost/app/js/main.js"));
+/// assert!(links.contains("http://localhost/app/js/"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinkExtractor {
+    /// `LINKFINDER_REGEX` as a regex::Regex type
+    pub(super) links_regex: Regex,
+
+    /// url against which any links found are resolved
+    pub(super) base_url: Url,
+
+    /// directory that relative links aren't allowed to climb above w/ `..` segments; the root of
+    /// `base_url` when not set
+    pub(super) scope_url: Option<Url>,
+
+    /// whether or not `%2F` in extracted links is decoded to `/`
+    pub(super) decode_slashes: bool,
+}
+
+/// implementation of LinkExtractor
+impl LinkExtractor {
+    /// search the given text for links and return them, along w/ all of their sub-paths, as
+    /// absolute urls
+    ///
+    /// absolute links that point to a different host than the base url are ignored
+    pub fn extract(&self, text: &str) -> HashSet<String> {
+        self.extract_counting_errors(text).0
+    }
+
+    /// pull the `Path` attribute out of each `Set-Cookie` header in `headers` and return them,
+    /// along w/ all of their sub-paths, as absolute urls
+    ///
+    /// cookie paths are always relative to the root of the host that set them, regardless of the
+    /// base url's path; cookies without a `Path` attribute are ignored
+    pub fn extract_from_set_cookie(&self, headers: &HeaderMap) -> HashSet<String> {
+        log::trace!("enter: extract_from_set_cookie");
+
+        let mut links = HashSet::new();
+
+        let mut root = self.clone();
+        root.base_url.set_path("/");
+        root.base_url.set_query(None);
+        root.base_url.set_fragment(None);
+
+        for header in headers.get_all(SET_COOKIE) {
+            let path = match header.to_str().ok().and_then(cookie_path) {
+                Some(path) => path,
+                None => continue,
+            };
+
+            if root.add_all_sub_paths(path, &mut links).is_err() {
+                log::warn!("could not add sub-paths from {} to {:?}", path, links);
+            }
+        }
+
+        log::trace!("exit: extract_from_set_cookie -> {:?}", links);
+        links
+    }
+
+    /// same as `extract`, but also returns the number of links that couldn't be parsed
+    pub(super) fn extract_counting_errors(&self, text: &str) -> (HashSet<String>, usize) {
+        log::trace!("enter: extract_counting_errors");
+
+        let mut links = HashSet::new();
+        let mut errors = 0;
+
+        for capture in self.links_regex.captures_iter(text) {
+            // remove single & double quotes from both ends of the capture
+            // capture[0] is the entire match, additional capture groups start at [1]
+            let link = capture[0].trim_matches(|c| c == '\'' || c == '"');
+
+            match Url::parse(link) {
+                Ok(absolute) => {
+                    if absolute.host() != self.base_url.host() {
+                        // domains/ips are not the same, don't scan things that aren't part of the original
+                        // target url; comparing the parsed hosts (as opposed to `domain()`, which is
+                        // None for any ip) handles ipv4/ipv6 literals as well as domains
+                        continue;
+                    }
+
+                    if self.add_all_sub_paths(absolute.path(), &mut links).is_err() {
+                        log::warn!("could not add sub-paths from {} to {:?}", absolute, links);
+                    }
+                }
+                Err(e) => {
+                    // this is the expected error that happens when we try to parse a url fragment
+                    //     ex: Url::parse("/login") -> Err("relative URL without a base")
+                    // while this is technically an error, these are good results for us
+                    if e.to_string().contains("relative URL without a base") {
+                        if self.add_all_sub_paths(link, &mut links).is_err() {
+                            log::warn!("could not add sub-paths from {} to {:?}", link, links);
+                        }
+                    } else {
+                        // unexpected error has occurred
+                        log::warn!("Could not parse given url: {}", e);
+                        errors += 1;
+                    }
+                }
+            }
+        }
+
+        log::trace!("exit: extract_counting_errors -> {:?}, {}", links, errors);
+        (links, errors)
+    }
+
+    /// take a url fragment like homepage/assets/img/icons/handshake.svg and
+    /// incrementally add
+    ///     - homepage/assets/img/icons/
+    ///     - homepage/assets/img/
+    ///     - homepage/assets/
+    ///     - homepage/
+    pub(super) fn add_all_sub_paths(
+        &self,
+        url_path: &str,
+        mut links: &mut HashSet<String>,
+    ) -> Result<()> {
+        log::trace!("enter: add_all_sub_paths({}, {:?})", url_path, links);
+
+        for sub_path in self.sub_paths(url_path) {
+            self.add_link_to_set_of_links(&sub_path, &mut links)?;
+        }
+
+        log::trace!("exit: add_all_sub_paths");
+        Ok(())
+    }
+
+    /// Iterate over a given path, return a list of every sub-path found
+    ///
+    /// example: `path` contains a link fragment `homepage/assets/img/icons/handshake.svg`
+    /// the following fragments would be returned:
+    ///   - homepage/assets/img/icons/handshake.svg
+    ///   - homepage/assets/img/icons/
+    ///   - homepage/assets/img/
+    ///   - homepage/assets/
+    ///   - homepage/
+    ///
+    /// `.` and `..` segments are resolved before sub-paths are generated. Any `..` segments that
+    /// can't be resolved within the path itself are kept as a prefix of each sub-path, i.e.
+    /// `../css/style.css` results in `../css/style.css` and `../css/`. A path that would climb
+    /// above the scope url's directory (or the root of the base url, when there's no scope url)
+    /// is out of scope and no sub-paths are returned for it.
+    pub fn sub_paths(&self, path: &str) -> Vec<String> {
+        log::trace!("enter: sub_paths({})", path);
+        let mut paths = vec![];
+
+        let (num_parents, mut parts) = match self.normalize_path(path) {
+            Some(normalized) => normalized,
+            None => {
+                log::debug!("{} resolves above the base path, skipping...", path);
+                log::trace!("exit: sub_paths -> {:?}", paths);
+                return paths;
+            }
+        };
+
+        let prefix = "../".repeat(num_parents);
+
+        if parts.is_empty() && !prefix.is_empty() {
+            // path consisted solely of ../ segments, i.e. it's a parent directory of the base
+            paths.push(prefix);
+            log::trace!("exit: sub_paths -> {:?}", paths);
+            return paths;
+        }
+
+        let length = parts.len();
+
+        for i in 0..length {
+            // iterate over all parts of the path
+            if parts.is_empty() {
+                // pop left us with an empty vector, we're done
+                break;
+            }
+
+            let mut possible_path = format!("{}{}", prefix, parts.join("/"));
+
+            if possible_path.is_empty() {
+                // .join can result in an empty string, which we don't need, ignore
+                continue;
+            }
+
+            if i > 0 {
+                // this isn't the last index of the parts array
+                // ex: /buried/misc/stupidfile.php
+                // this block skips the file but sees all parent folders
+                possible_path = format!("{}/", possible_path);
+            }
+
+            paths.push(possible_path); // good sub-path found
+            parts.pop(); // use .pop() to remove the last part of the path and continue iteration
+        }
+
+        log::trace!("exit: sub_paths -> {:?}", paths);
+        paths
+    }
+
+    /// resolve any `.` and `..` segments found in the given path
+    ///
+    /// returns the number of `..` segments left over after resolution (i.e. how many directories
+    /// above the base url's directory the path begins) along with the remaining, non-empty path
+    /// segments. `None` is returned when the path climbs above the base path (see `max_parents`).
+    fn normalize_path<'b>(&self, path: &'b str) -> Option<(usize, Vec<&'b str>)> {
+        let is_absolute = path.starts_with('/');
+        let mut num_parents = 0;
+        let mut parts = Vec::new();
+
+        for segment in path.split('/') {
+            match segment {
+                // filter out any empty strings caused by .split, along with current-dir segments
+                "" | "." => continue,
+                ".." => {
+                    if parts.pop().is_none() {
+                        if is_absolute {
+                            // can't go any higher than / when starting from /
+                            return None;
+                        }
+                        num_parents += 1;
+                    }
+                }
+                _ => parts.push(segment),
+            }
+        }
+
+        if num_parents > self.max_parents() {
+            return None;
+        }
+
+        Some((num_parents, parts))
+    }
+
+    /// number of directories between the root of the base url and the directory in which the
+    /// base url lives, i.e. `http://localhost/app/js/main.js` has a depth of 2
+    fn base_depth(&self) -> usize {
+        let path = self.base_url.path();
+        let num_segments = path.split('/').filter(|s| !s.is_empty()).count();
+
+        if path.ends_with('/') {
+            num_segments
+        } else {
+            // last segment is a file, or at least not known to be a directory
+            num_segments.saturating_sub(1)
+        }
+    }
+
+    /// number of `..` segments a relative path may be left with after resolution, without
+    /// climbing above the base path
+    ///
+    /// the base path is the scope url (always treated as a directory) when one is set, i.e.
+    /// `../css/` from `http://localhost/app/js/main.js` is fine w/ a scope of
+    /// `http://localhost/app/`, but `../../secret/` isn't. Without a scope url, the root of the
+    /// base url is the limit. A base url that isn't beneath the scope url can't climb at all.
+    fn max_parents(&self) -> usize {
+        let scope_url = match &self.scope_url {
+            Some(scope_url) => scope_url,
+            None => return self.base_depth(),
+        };
+
+        let scope_path = format!("{}/", scope_url.path().trim_end_matches('/'));
+
+        if scope_url.origin() != self.base_url.origin()
+            || !self.base_url.path().starts_with(&scope_path)
+        {
+            return 0;
+        }
+
+        let scope_depth = scope_path.split('/').filter(|s| !s.is_empty()).count();
+
+        self.base_depth().saturating_sub(scope_depth)
+    }
+
+    /// simple helper to stay DRY, trys to join a url + fragment and add it to the `links` HashSet
+    ///
+    /// the joined url is canonicalized first, so that links that only differ in how they're
+    /// encoded (`/a//b` vs `/a/b`) result in a single request
+    pub(super) fn add_link_to_set_of_links(
+        &self,
+        link: &str,
+        links: &mut HashSet<String>,
+    ) -> Result<()> {
+        log::trace!("enter: add_link_to_set_of_links({}, {:?})", link, links);
+
+        let new_url = self
+            .base_url
+            .join(&link)
+            .with_context(|| format!("Could not join {} with {}", self.base_url, link))?;
+
+        let canonical = FeroxUrl::canonicalize_url(&new_url, self.decode_slashes);
+
+        links.insert(canonical.to_string());
+
+        log::trace!("exit: add_link_to_set_of_links");
+
+        Ok(())
+    }
+}
+
+/// responsible for building a `LinkExtractor`
+#[derive(Debug, Default)]
+pub struct LinkExtractorBuilder {
+    /// url against which any links found are resolved
+    base_url: String,
+
+    /// directory that relative links aren't allowed to climb above
+    scope_url: String,
+
+    /// whether or not `%2F` in extracted links is decoded to `/`
+    decode_slashes: bool,
+}
+
+/// LinkExtractorBuilder implementation
+impl LinkExtractorBuilder {
+    /// builder call to set `base_url`
+    pub fn base_url(&mut self, url: &str) -> &mut Self {
+        self.base_url = url.to_string();
+        self
+    }
+
+    /// builder call to set `scope_url` (default: the root of `base_url`)
+    ///
+    /// relative links whose `..` segments would climb above this url's directory are dropped,
+    /// i.e. the target url of a scan
+    pub fn scope_url(&mut self, url: &str) -> &mut Self {
+        self.scope_url = url.to_string();
+        self
+    }
+
+    /// builder call to set `decode_slashes` (default: false)
+    pub fn decode_slashes(&mut self, decode_slashes: bool) -> &mut Self {
+        self.decode_slashes = decode_slashes;
+        self
+    }
+
+    /// finalize configuration of LinkExtractorBuilder and return a LinkExtractor
+    ///
+    /// requires `base_url` (and `scope_url`, when set) to be an absolute url
+    pub fn build(&self) -> Result<LinkExtractor> {
+        let base_url = Url::parse(&self.base_url)
+            .map_err(|e| anyhow!("LinkExtractor requires a valid base url: {}", e))?;
+
+        let scope_url = if self.scope_url.is_empty() {
+            None
+        } else {
+            Some(
+                Url::parse(&self.scope_url)
+                    .map_err(|e| anyhow!("LinkExtractor requires a valid scope url: {}", e))?,
+            )
+        };
+
+        Ok(LinkExtractor {
+            links_regex: Regex::new(LINKFINDER_REGEX).unwrap(),
+            base_url,
+            scope_url,
+            decode_slashes: self.decode_slashes,
+        })
+    }
+}
+
+/// get the value of the `Path` attribute of a single `Set-Cookie` header's value, i.e. `/admin`
+/// from `sess=x; Path=/admin; HttpOnly`
+///
+/// attribute names are case-insensitive and the last `Path` wins, per RFC 6265; paths that
+/// aren't absolute are ignored, as browsers replace them w/ a default path
+fn cookie_path(cookie: &str) -> Option<&str> {
+    cookie
+        .split(';')
+        .skip(1) // name=value pair
+        .filter_map(|attribute| attribute.split_once('='))
+        .filter(|(name, _)| name.trim().eq_ignore_ascii_case("path"))
+        .map(|(_, value)| value.trim())
+        .last()
+        .filter(|path| path.starts_with('/'))
+}
+
+/// search `text` for links, resolving them against `base_url`, and return the resulting absolute
+/// urls along w/ all of their sub-paths
+///
+/// shorthand for building a `LinkExtractor` w/ default options and calling `extract`; errors
+/// only when `base_url` isn't a valid, absolute url
+///
+/// ```
+/// use feroxbuster::extractor::extract_links_from_text;
+///
+/// let links = extract_links_from_text("http://localhost", r#"<a href="/admin/login.php">"#).unwrap();
+///
+/// assert_eq!(links.len(), 2);
+/// assert!(links.contains("http://localhost/admin/login.php"));
+/// assert!(links.contains("http://localhost/admin/"));
+/// ```
+pub fn extract_links_from_text(base_url: &str, text: &str) -> Result<HashSet<String>> {
+    let extractor = LinkExtractorBuilder::default().base_url(base_url).build()?;
+    Ok(extractor.extract(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// links from other hosts should be ignored, relative links resolved against the base url
+    fn extract_links_from_text_resolves_and_scopes_links() {
+        let body = r#"
+            <a href="https://other-host.com/nope.php">
+            <script src="./js/app.js"></script>
+            <link href="https://example.com/css/main.css">
+        "#;
+
+        let links = extract_links_from_text("https://example.com/app/", body).unwrap();
+
+        let expected: HashSet<_> = vec![
+            "https://example.com/app/js/app.js",
+            "https://example.com/app/js/",
+            "https://example.com/app/css/main.css",
+            "https://example.com/app/css/",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        assert_eq!(links, expected);
+    }
+
+    #[test]
+    /// links from an ipv6 target should be scoped by comparing hosts, not domains
+    fn extract_links_from_text_with_ipv6_base_url() {
+        let body = r#"
+            <a href="http://[::2]:8080/nope.php">
+            <a href="http://[::1]:8080/admin/login.php">
+            <a href="http://10.0.0.1/nope.php">
+            <script src="/js/app.js"></script>
+        "#;
+
+        let links = extract_links_from_text("http://[::1]:8080/", body).unwrap();
+
+        let expected: HashSet<_> = vec![
+            "http://[::1]:8080/admin/login.php",
+            "http://[::1]:8080/admin/",
+            "http://[::1]:8080/js/app.js",
+            "http://[::1]:8080/js/",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        assert_eq!(links, expected);
+    }
+
+    #[test]
+    /// absolute links w/ an ipv4 host should be extracted when they match the target
+    fn extract_links_from_text_with_ipv4_base_url() {
+        let body = r#""http://10.0.0.1/admin/login.php" "http://10.0.0.2/nope.php""#;
+
+        let links = extract_links_from_text("http://10.0.0.1/", body).unwrap();
+
+        let expected: HashSet<_> =
+            vec!["http://10.0.0.1/admin/login.php", "http://10.0.0.1/admin/"]
+                .into_iter()
+                .map(String::from)
+                .collect();
+
+        assert_eq!(links, expected);
+    }
+
+    #[test]
+    /// paths from each Set-Cookie header should be resolved against the root of the base url
+    fn extract_from_set_cookie_resolves_cookie_paths() {
+        let mut headers = HeaderMap::new();
+        headers.append(SET_COOKIE, "sess=x; Path=/admin/panel".parse().unwrap());
+        headers.append(SET_COOKIE, "theme=dark; HttpOnly".parse().unwrap());
+        headers.append(SET_COOKIE, "lang=en; path=/".parse().unwrap());
+        headers.append(
+            SET_COOKIE,
+            "csrf=y; Path=relative; Secure; PATH = /api/v2/ ; SameSite=Lax"
+                .parse()
+                .unwrap(),
+        );
+
+        let extractor = LinkExtractorBuilder::default()
+            .base_url("http://localhost/app/index.html")
+            .build()
+            .unwrap();
+
+        let expected: HashSet<_> = vec![
+            "http://localhost/admin/panel",
+            "http://localhost/admin/",
+            "http://localhost/api/v2",
+            "http://localhost/api/",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        assert_eq!(extractor.extract_from_set_cookie(&headers), expected);
+    }
+
+    #[test]
+    /// only absolute Path attributes should be returned, the last one winning
+    fn cookie_path_parses_path_attribute() {
+        assert_eq!(cookie_path("sess=x; Path=/admin"), Some("/admin"));
+        assert_eq!(cookie_path("sess=x; path=/a; Path=/b"), Some("/b"));
+        assert_eq!(cookie_path("path=/not-an-attribute"), None);
+        assert_eq!(cookie_path("sess=x; Path=admin"), None);
+        assert_eq!(cookie_path("sess=x; Domain=localhost; Secure"), None);
+    }
+
+    #[test]
+    /// a relative base url can't be used to resolve links
+    fn link_extractor_builder_requires_absolute_base_url() {
+        assert!(LinkExtractorBuilder::default().build().is_err());
+        assert!(extract_links_from_text("/app/", "\"/js/app.js\"").is_err());
+    }
+
+    #[test]
+    /// decode_slashes should be honored when canonicalizing links
+    fn link_extractor_builder_decode_slashes() {
+        let body = "\"/static%2Fjs/app.js\"";
+
+        let encoded = LinkExtractorBuilder::default()
+            .base_url("http://localhost")
+            .build()
+            .unwrap()
+            .extract(body);
+        assert!(encoded.contains("http://localhost/static%2Fjs/app.js"));
+
+        let decoded = LinkExtractorBuilder::default()
+            .base_url("http://localhost")
+            .decode_slashes(true)
+            .build()
+            .unwrap()
+            .extract(body);
+        assert!(decoded.contains("http://localhost/static/js/app.js"));
+    }
+
+    #[test]
+    /// relative links should only be allowed to climb as far as the scope url's directory
+    fn link_extractor_scope_url_limits_parent_dir_segments() {
+        let extractor = LinkExtractorBuilder::default()
+            .base_url("http://localhost/app/js/main.js")
+            .scope_url("http://localhost/app")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            extractor.sub_paths("../css/style.css"),
+            vec!["../css/style.css", "../css/"]
+        );
+        assert!(extractor.sub_paths("../../secret/").is_empty());
+        assert_eq!(extractor.sub_paths("js/app.js"), vec!["js/app.js", "js/"]);
+
+        // base url outside of the scope url's directory can't climb at all
+        let outside = LinkExtractorBuilder::default()
+            .base_url("http://localhost/application/main.js")
+            .scope_url("http://localhost/app/")
+            .build()
+            .unwrap();
+
+        assert!(outside.sub_paths("../secret/").is_empty());
+        assert_eq!(outside.sub_paths("css/"), vec!["css"]);
+    }
+}
@@ -1,12 +1,18 @@
 //! extract links from html source and robots.txt
 mod builder;
 mod container;
+mod intel;
+mod links;
+mod robots;
 #[cfg(test)]
 mod tests;
 
 pub use self::builder::ExtractionTarget;
 pub use self::builder::ExtractorBuilder;
 pub use self::container::Extractor;
+pub use self::intel::{Intel, IntelKind};
+pub use self::links::{extract_links_from_text, LinkExtractor, LinkExtractorBuilder};
+pub use self::robots::{CrawlDelays, RobotsCache, RobotsTxt};
 
 use crate::response::FeroxResponse;
 use regex::Regex;
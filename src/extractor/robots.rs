@@ -0,0 +1,307 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use reqwest::Url;
+use tokio::{
+    sync::OnceCell,
+    time::{sleep_until, Instant},
+};
+
+/// longest Crawl-delay that's honored; larger values are clamped to it (same cap as the pause
+/// used by --auto-throttle)
+pub const MAX_CRAWL_DELAY: Duration = Duration::from_secs(300);
+
+/// what was found in a single host's robots.txt, or None when the host doesn't serve one
+pub type RobotsEntry = Option<Arc<RobotsTxt>>;
+
+/// The parts of a robots.txt that feroxbuster cares about
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RobotsTxt {
+    /// paths found in Allow/Disallow rules
+    pub paths: Vec<String>,
+
+    /// delay requested between each request to the host, if any
+    pub crawl_delay: Option<Duration>,
+}
+
+/// implementation of RobotsTxt
+impl RobotsTxt {
+    /// find the Crawl-delay directive(s) in the given robots.txt body
+    ///
+    /// feroxbuster doesn't track which user-agent a group of rules applies to, so when more than
+    /// one Crawl-delay is present, the largest one wins. Values that aren't a non-negative number
+    /// of seconds are ignored, as is a delay of 0. Delays longer than `MAX_CRAWL_DELAY` are
+    /// clamped to it.
+    pub fn parse_crawl_delay(text: &str) -> Option<Duration> {
+        let mut delay: Option<f64> = None;
+
+        for line in text.lines() {
+            // anything after a # is a comment
+            let line = line.split('#').next().unwrap_or_default();
+
+            let (directive, value) = match line.split_once(':') {
+                Some(pair) => pair,
+                None => continue,
+            };
+
+            if !directive.trim().eq_ignore_ascii_case("crawl-delay") {
+                continue;
+            }
+
+            match value.trim().parse::<f64>() {
+                Ok(seconds) if seconds.is_finite() && seconds > 0.0 => {
+                    delay = Some(delay.map_or(seconds, |current| current.max(seconds)));
+                }
+                _ => log::debug!("ignoring invalid Crawl-delay: {}", value.trim()),
+            }
+        }
+
+        // clamping before converting also keeps from_secs_f64 from panicking on huge values
+        delay.map(|seconds| Duration::from_secs_f64(seconds.min(MAX_CRAWL_DELAY.as_secs_f64())))
+    }
+}
+
+/// Per-host cache of what was found in robots.txt, shared across the entire scan
+///
+/// hosts are keyed by scheme, host, and port, so that recursing into `http://localhost/api/`
+/// reuses what was found when scanning `http://localhost/` instead of requesting /robots.txt
+/// again. Hosts without a robots.txt get a negative entry, so they're only asked once as well.
+#[derive(Debug, Default)]
+pub struct RobotsCache {
+    /// map of scheme+host+port to that host's (possibly not yet fetched) entry
+    entries: Mutex<HashMap<String, Arc<OnceCell<RobotsEntry>>>>,
+}
+
+/// implementation of RobotsCache
+impl RobotsCache {
+    /// given a url, return the key under which its host's robots.txt is cached
+    ///
+    /// ex: http://localhost:8080/api/users -> http://localhost:8080
+    pub fn key(url: &Url) -> String {
+        url.origin().ascii_serialization()
+    }
+
+    /// return the cached entry for `key`, calling `fetch` to populate it on a miss
+    ///
+    /// concurrent callers for the same key wait on a single `fetch` instead of each making
+    /// their own request. Errors returned by `fetch` aren't cached, so the next caller tries again.
+    pub async fn get_or_fetch<F, Fut>(&self, key: &str, fetch: F) -> Result<RobotsEntry>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<RobotsEntry>>,
+    {
+        let cell = {
+            let mut entries = self
+                .entries
+                .lock()
+                .map_err(|e| anyhow!("Could not lock the robots.txt cache: {}", e))?;
+
+            entries.entry(key.to_string()).or_default().clone()
+        };
+
+        let entry = cell.get_or_try_init(fetch).await?;
+
+        Ok(entry.clone())
+    }
+}
+
+/// schedule for a single host that asked for a Crawl-delay
+#[derive(Debug)]
+struct HostDelay {
+    /// time to wait between requests
+    delay: Duration,
+
+    /// earliest time at which the next request may be sent
+    next_request: Instant,
+}
+
+/// Per-host Crawl-delays (--respect-crawl-delay), shared across the entire scan
+///
+/// the delay applies to the host as a whole, so every scan of a host (i.e. each directory being
+/// recursed into) takes its turn from the same schedule. Hosts are keyed the same way as the
+/// `RobotsCache`; hosts without a delay aren't slowed down at all.
+#[derive(Debug, Default)]
+pub struct CrawlDelays {
+    /// map of scheme+host+port to that host's schedule
+    hosts: Mutex<HashMap<String, HostDelay>>,
+}
+
+/// implementation of CrawlDelays
+impl CrawlDelays {
+    /// set the delay to be observed between requests to the host with the given key
+    ///
+    /// a host that already has a delay keeps its current schedule; delays longer than
+    /// `MAX_CRAWL_DELAY` are clamped to it
+    pub fn set(&self, key: &str, delay: Duration) {
+        let delay = delay.min(MAX_CRAWL_DELAY);
+
+        if let Ok(mut hosts) = self.hosts.lock() {
+            hosts.entry(key.to_string()).or_insert_with(|| HostDelay {
+                delay,
+                next_request: Instant::now(),
+            });
+        }
+    }
+
+    /// get the delay for the host with the given key, if any
+    pub fn get(&self, key: &str) -> Option<Duration> {
+        let hosts = self.hosts.lock().ok()?;
+        hosts.get(key).map(|host| host.delay)
+    }
+
+    /// wait until the next request to the given url's host is allowed to be sent
+    ///
+    /// returns immediately when the host didn't ask for a delay
+    pub async fn wait(&self, url: &Url) {
+        let slot = match self.hosts.lock() {
+            Ok(mut hosts) => hosts.get_mut(&RobotsCache::key(url)).map(|host| {
+                // claim the next open slot, and push the one after it out by the delay
+                let slot = host.next_request.max(Instant::now());
+                host.next_request = slot.checked_add(host.delay).unwrap_or(slot);
+                slot
+            }),
+            Err(_) => None,
+        };
+
+        if let Some(slot) = slot {
+            sleep_until(slot).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// urls on the same scheme+host+port should share a key, anything else shouldn't
+    fn robots_cache_key_uses_scheme_host_and_port() {
+        let key = |url: &str| RobotsCache::key(&Url::parse(url).unwrap());
+
+        assert_eq!(key("http://localhost/api/users"), "http://localhost");
+        assert_eq!(key("http://localhost:80/"), key("http://localhost/stuff/"));
+        assert_ne!(key("http://localhost/"), key("https://localhost/"));
+        assert_ne!(key("http://localhost/"), key("http://localhost:8080/"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// once populated, whether positively or negatively, an entry shouldn't be fetched again;
+    /// failed fetches shouldn't be cached
+    async fn robots_cache_fetches_each_host_once() -> Result<()> {
+        let cache = RobotsCache::default();
+
+        let found = cache
+            .get_or_fetch("http://localhost", || async {
+                Ok(Some(Arc::new(RobotsTxt {
+                    paths: vec![String::from("/admin")],
+                    crawl_delay: None,
+                })))
+            })
+            .await?;
+        let cached = cache
+            .get_or_fetch("http://localhost", || async { panic!("fetched twice") })
+            .await?;
+        assert_eq!(found, cached);
+
+        let failed = cache
+            .get_or_fetch("http://127.0.0.1", || async { Err(anyhow!("timed out")) })
+            .await;
+        assert!(failed.is_err());
+
+        let missing = cache
+            .get_or_fetch("http://127.0.0.1", || async { Ok(None) })
+            .await?;
+        let still_missing = cache
+            .get_or_fetch("http://127.0.0.1", || async { panic!("fetched twice") })
+            .await?;
+        assert!(missing.is_none());
+        assert!(still_missing.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    /// crawl-delay should be parsed case-insensitively, ignoring comments and invalid values;
+    /// the largest valid delay wins
+    fn robots_txt_parse_crawl_delay() {
+        let parse = RobotsTxt::parse_crawl_delay;
+
+        assert_eq!(parse("User-agent: *\nDisallow: /admin\n"), None);
+        assert_eq!(
+            parse("User-agent: *\ncrawl-DELAY: 2 # be nice\n"),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            parse("Crawl-delay: 0.5\nCrawl-delay: 10\nCrawl-delay: 1"),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(parse("Crawl-delay: soon\nCrawl-delay: -3"), None);
+        assert_eq!(parse("Crawl-delay: 0\n# Crawl-delay: 5"), None);
+    }
+
+    #[test]
+    /// huge delays, including ones that don't fit in a Duration, should be clamped instead of
+    /// panicking
+    fn robots_txt_parse_crawl_delay_clamps_huge_values() {
+        let parse = RobotsTxt::parse_crawl_delay;
+
+        assert_eq!(parse("Crawl-delay: 301"), Some(MAX_CRAWL_DELAY));
+        assert_eq!(parse("Crawl-delay: 86400"), Some(MAX_CRAWL_DELAY));
+        assert_eq!(parse("Crawl-delay: 1e30"), Some(MAX_CRAWL_DELAY));
+        assert_eq!(
+            parse("Crawl-delay: 1e308\nCrawl-delay: 3"),
+            Some(MAX_CRAWL_DELAY)
+        );
+        assert_eq!(parse("Crawl-delay: inf"), None);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// delays set directly should be clamped too, so scheduling the next request can't overflow
+    async fn crawl_delays_clamp_huge_delays() {
+        let delays = CrawlDelays::default();
+        let url = Url::parse("http://localhost/admin").unwrap();
+
+        delays.set(&RobotsCache::key(&url), Duration::from_secs(u64::MAX));
+        assert_eq!(delays.get("http://localhost"), Some(MAX_CRAWL_DELAY));
+
+        // first request goes out right away, and scheduling the one after it doesn't panic
+        let start = Instant::now();
+        delays.wait(&url).await;
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// requests to a host w/ a delay should be spaced out by that delay, other hosts shouldn't
+    /// wait at all
+    async fn crawl_delays_space_out_requests_per_host() {
+        let delays = CrawlDelays::default();
+        let slow = Url::parse("http://localhost/admin").unwrap();
+        let fast = Url::parse("http://127.0.0.1/admin").unwrap();
+
+        delays.set(&RobotsCache::key(&slow), Duration::from_millis(200));
+        delays.set(&RobotsCache::key(&slow), Duration::from_secs(30)); // first one sticks
+
+        assert_eq!(
+            delays.get("http://localhost"),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(delays.get("http://127.0.0.1"), None);
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            delays.wait(&fast).await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(100));
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            delays.wait(&slow).await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(400));
+    }
+}
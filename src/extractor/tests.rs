@@ -1,15 +1,24 @@
 use super::builder::{LINKFINDER_REGEX, ROBOTS_TXT_REGEX};
+use super::intel::{EMAIL_REGEX, HTML_COMMENT_REGEX};
 use super::*;
 use crate::config::{Configuration, OutputLevel};
 use crate::scan_manager::ScanOrder;
+use crate::DEFAULT_MAX_BODY_SIZE;
 use crate::{
     event_handlers::Handles, scan_manager::FeroxScans, utils::make_request, Command, FeroxChannel,
 };
 use anyhow::Result;
+use flate2::{
+    write::{GzEncoder, ZlibEncoder},
+    Compression,
+};
 use httpmock::{Method::GET, MockServer};
 use lazy_static::lazy_static;
 use reqwest::{Client, StatusCode, Url};
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    io::Write,
+};
 use tokio::sync::mpsc;
 
 lazy_static! {
@@ -123,6 +132,127 @@ fn extractor_get_sub_paths_from_path_with_an_absolute_word() {
     }
 }
 
+/// creates an extractor whose base url lives two directories below the root
+fn setup_nested_extractor() -> Extractor<'static> {
+    let handles = Arc::new(Handles::for_testing(None, None).0);
+
+    ExtractorBuilder::default()
+        .url("http://localhost/app/js/main.js")
+        .target(ExtractionTarget::RobotsTxt)
+        .handles(handles)
+        .build()
+        .unwrap()
+}
+
+#[test]
+/// extract sub paths from a fragment that begins with ./; expect the ./ to be resolved
+fn extractor_get_sub_paths_from_path_with_current_dir_segment() {
+    let path = "./js/app.js";
+    let r_paths = ROBOTS_EXT.get_sub_paths_from_path(&path);
+    let b_paths = BODY_EXT.get_sub_paths_from_path(&path);
+    let expected = vec!["js/app.js", "js/"];
+
+    assert_eq!(r_paths, expected);
+    assert_eq!(b_paths, expected);
+}
+
+#[test]
+/// extract sub paths from a fragment that begins with ../; expect the ../ to be kept as a prefix
+/// of each sub path, since it can't be resolved within the fragment itself
+fn extractor_get_sub_paths_from_path_with_parent_dir_segments() {
+    let extractor = setup_nested_extractor();
+
+    let paths = extractor.get_sub_paths_from_path("../css/style.css");
+    assert_eq!(paths, vec!["../css/style.css", "../css/"]);
+
+    let paths = extractor.get_sub_paths_from_path("../../secret/");
+    assert_eq!(paths, vec!["../../secret"]);
+
+    let paths = extractor.get_sub_paths_from_path("../");
+    assert_eq!(paths, vec!["../"]);
+}
+
+#[test]
+/// extract sub paths from a fragment that climbs above the target of the scan the base url belongs
+/// to; expect nothing, even though it stays below the root of the base url
+fn extractor_get_sub_paths_from_path_above_scan_target() {
+    let scans = Arc::new(FeroxScans::default());
+    scans.add_directory_scan("http://localhost/app/", ScanOrder::Initial);
+
+    let handles = Arc::new(Handles::for_testing(Some(scans), None).0);
+
+    let extractor = ExtractorBuilder::default()
+        .url("http://localhost/app/js/main.js")
+        .target(ExtractionTarget::RobotsTxt)
+        .handles(handles)
+        .build()
+        .unwrap();
+
+    assert!(extractor
+        .get_sub_paths_from_path("../../secret/")
+        .is_empty());
+    assert_eq!(
+        extractor.get_sub_paths_from_path("../css/style.css"),
+        vec!["../css/style.css", "../css/"]
+    );
+}
+
+#[test]
+/// extract sub paths from a fragment that climbs above the root of the base url; expect nothing
+fn extractor_get_sub_paths_from_path_above_base_url_root() {
+    let extractor = setup_nested_extractor();
+
+    assert!(extractor
+        .get_sub_paths_from_path("../../../secret/")
+        .is_empty());
+    assert!(extractor
+        .get_sub_paths_from_path("/static/../../etc/passwd")
+        .is_empty());
+
+    // base url of ROBOTS_EXT and BODY_EXT is http://localhost, there's nothing above it
+    assert!(ROBOTS_EXT.get_sub_paths_from_path("../admin").is_empty());
+    assert!(BODY_EXT.get_sub_paths_from_path("../admin").is_empty());
+}
+
+#[test]
+/// extract sub paths from a fragment that mixes ./ and ../ with regular segments; expect all
+/// of them to be resolved before sub paths are generated
+fn extractor_get_sub_paths_from_path_with_mixed_dot_segments() {
+    let extractor = setup_nested_extractor();
+
+    let paths = extractor.get_sub_paths_from_path("static/./js/../css/main.css");
+    assert_eq!(paths, vec!["static/css/main.css", "static/css/", "static/"]);
+
+    let paths = extractor.get_sub_paths_from_path("/static/./js/../../admin/");
+    assert_eq!(paths, vec!["admin"]);
+
+    let paths = extractor.get_sub_paths_from_path("./../img/../../lib/./x.js");
+    assert_eq!(paths, vec!["../../lib/x.js", "../../lib/"]);
+}
+
+#[test]
+/// fragments beginning with ../ should be joined with the base url to produce in-scope urls
+fn extractor_add_all_sub_paths_resolves_parent_dir_segments() {
+    let extractor = setup_nested_extractor();
+    let mut links = HashSet::<String>::new();
+
+    extractor
+        .add_all_sub_paths("../css/style.css", &mut links)
+        .unwrap();
+    extractor
+        .add_all_sub_paths("../../../secret/", &mut links)
+        .unwrap();
+
+    let expected: HashSet<String> = vec![
+        "http://localhost/app/css/style.css".to_string(),
+        "http://localhost/app/css/".to_string(),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(links, expected);
+}
+
 #[test]
 /// test that an ExtractorBuilder without a FeroxResponse and without a URL bails
 fn extractor_builder_bails_when_neither_required_field_is_set() {
@@ -183,6 +313,45 @@ fn extractor_add_link_to_set_of_links_happy_path() {
     assert!(b_links.contains("http://localhost/shmadmin"));
 }
 
+#[test]
+/// links that only differ by duplicate slashes should result in a single entry, while an encoded
+/// slash is left alone unless --decode-slashes is used
+fn extractor_add_link_to_set_of_links_canonicalizes() -> Result<()> {
+    let mut links = HashSet::<String>::new();
+
+    for link in &["/a//b", "/a/b", "/a///b", "/a/%62"] {
+        ROBOTS_EXT.add_link_to_set_of_links(link, &mut links)?;
+    }
+
+    assert_eq!(links.len(), 1);
+    assert!(links.contains("http://localhost/a/b"));
+
+    ROBOTS_EXT.add_link_to_set_of_links("/a%2Fb", &mut links)?;
+
+    assert_eq!(links.len(), 2);
+    assert!(links.contains("http://localhost/a%2Fb"));
+
+    let mut config = Configuration::new()?;
+    config.decode_slashes = true;
+    let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+
+    let extractor = ExtractorBuilder::default()
+        .url("http://localhost")
+        .target(ExtractionTarget::RobotsTxt)
+        .handles(handles)
+        .build()?;
+
+    let mut decoded = HashSet::<String>::new();
+
+    for link in &["/a%2Fb", "/a/b", "/a%2F%2Fb"] {
+        extractor.add_link_to_set_of_links(link, &mut decoded)?;
+    }
+
+    assert_eq!(decoded.len(), 1);
+    assert!(decoded.contains("http://localhost/a/b"));
+    Ok(())
+}
+
 #[test]
 /// test that an invalid path fragment doesn't add anything to the set of links
 fn extractor_add_link_to_set_of_links_with_non_base_url() {
@@ -224,11 +393,14 @@ async fn extractor_get_links_with_absolute_url_that_differs_from_target_domain()
     let (handles, _rx) = Handles::for_testing(None, None);
 
     let handles = Arc::new(handles);
-    let ferox_response = FeroxResponse::from(response, true, OutputLevel::Default).await;
+    let ferox_response =
+        FeroxResponse::from(response, true, DEFAULT_MAX_BODY_SIZE, OutputLevel::Default).await;
 
     let extractor = Extractor {
         links_regex: Regex::new(LINKFINDER_REGEX).unwrap(),
         robots_regex: Regex::new(ROBOTS_TXT_REGEX).unwrap(),
+        email_regex: Regex::new(EMAIL_REGEX).unwrap(),
+        comment_regex: Regex::new(HTML_COMMENT_REGEX).unwrap(),
         response: Some(&ferox_response),
         url: String::new(),
         target: ExtractionTarget::ResponseBody,
@@ -242,6 +414,118 @@ async fn extractor_get_links_with_absolute_url_that_differs_from_target_domain()
     Ok(())
 }
 
+/// serve the given (compressed) body with the given Content-Encoding and return the links
+/// extracted from the response
+async fn extract_links_from_encoded_body(body: Vec<u8>, encoding: &str) -> Result<HashSet<String>> {
+    extract_links_with_header(body, "Content-Encoding", encoding).await
+}
+
+/// serve the given body with the given header and return the links extracted from the response
+async fn extract_links_with_header(
+    body: Vec<u8>,
+    header: &str,
+    value: &str,
+) -> Result<HashSet<String>> {
+    let (tx_stats, _): FeroxChannel<Command> = mpsc::unbounded_channel();
+
+    let srv = MockServer::start();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/some-path");
+        then.status(200).header(header, value).body(body);
+    });
+
+    let client = crate::client::initialize(
+        crate::client::Timeouts::from(5),
+        "stuff",
+        false,
+        crate::client::TlsVerification::Full,
+        "auto",
+        &HashMap::new(),
+        None,
+        None,
+    )?;
+    let url = Url::parse(&srv.url("/some-path"))?;
+
+    let response = make_request(&client, &url, OutputLevel::Default, tx_stats).await?;
+    let ferox_response =
+        FeroxResponse::from(response, true, DEFAULT_MAX_BODY_SIZE, OutputLevel::Default).await;
+
+    let (handles, _rx) = Handles::for_testing(None, None);
+
+    let extractor = Extractor {
+        links_regex: Regex::new(LINKFINDER_REGEX).unwrap(),
+        robots_regex: Regex::new(ROBOTS_TXT_REGEX).unwrap(),
+        email_regex: Regex::new(EMAIL_REGEX).unwrap(),
+        comment_regex: Regex::new(HTML_COMMENT_REGEX).unwrap(),
+        response: Some(&ferox_response),
+        url: String::new(),
+        target: ExtractionTarget::ResponseBody,
+        handles: Arc::new(handles),
+    };
+
+    let links = extractor.extract_from_body().await?;
+
+    assert_eq!(mock.hits(), 1);
+
+    if header == "Content-Encoding" {
+        // decompressed bodies are measured after decompression
+        assert_eq!(
+            ferox_response.content_length(),
+            ferox_response.text().len() as u64
+        );
+    }
+
+    Ok(links
+        .into_iter()
+        .map(|link| link.replace(&srv.url(""), ""))
+        .collect())
+}
+
+/// body used for testing compressed responses, along with the links expected from it
+fn compressible_body() -> (&'static str, HashSet<String>) {
+    let body = r#"<html><script src="/homepage/assets/js/app.js"></script></html>"#;
+    let expected = vec![
+        "/homepage/assets/js/app.js",
+        "/homepage/assets/js/",
+        "/homepage/assets/",
+        "/homepage/",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect();
+
+    (body, expected)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// a gzip encoded body should be decompressed before links are extracted from it
+async fn extractor_get_links_from_gzip_encoded_body() -> Result<()> {
+    let (body, expected) = compressible_body();
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body.as_bytes())?;
+
+    let links = extract_links_from_encoded_body(encoder.finish()?, "gzip").await?;
+
+    assert_eq!(links, expected);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// a deflate encoded body should be decompressed before links are extracted from it
+async fn extractor_get_links_from_deflate_encoded_body() -> Result<()> {
+    let (body, expected) = compressible_body();
+
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body.as_bytes())?;
+
+    let links = extract_links_from_encoded_body(encoder.finish()?, "deflate").await?;
+
+    assert_eq!(links, expected);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 /// test that /robots.txt is correctly requested given a base url (happy path)
 async fn request_robots_txt_without_proxy() -> Result<()> {
@@ -257,6 +541,8 @@ async fn request_robots_txt_without_proxy() -> Result<()> {
     let extractor = Extractor {
         links_regex: Regex::new(LINKFINDER_REGEX).unwrap(),
         robots_regex: Regex::new(ROBOTS_TXT_REGEX).unwrap(),
+        email_regex: Regex::new(EMAIL_REGEX).unwrap(),
+        comment_regex: Regex::new(HTML_COMMENT_REGEX).unwrap(),
         response: None,
         url: srv.url("/api/users/stuff/things"),
         target: ExtractionTarget::RobotsTxt,
@@ -304,6 +590,133 @@ async fn request_robots_txt_with_proxy() -> Result<()> {
     Ok(())
 }
 
+#[test]
+/// the robots.txt client should carry the same proxy as the scan's client
+fn robots_client_uses_same_proxy_as_scan_client() -> Result<()> {
+    let config = Configuration {
+        proxy: String::from("http://127.0.0.1:8080"),
+        proxy_all: true,
+        redirects: false,
+        ..Default::default()
+    };
+    let scan_client = config.build_client(config.redirects)?;
+
+    let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+
+    let extractor = ExtractorBuilder::default()
+        .url("http://localhost/api")
+        .target(ExtractionTarget::RobotsTxt)
+        .handles(handles)
+        .build()?;
+
+    /// pull the list of proxies out of a client's debug output
+    fn proxies(client: &Client) -> String {
+        let debug = format!("{:?}", client);
+        let start = debug.find("proxies: [").expect("client has no proxies");
+        let end = start + debug[start..].find(']').unwrap();
+        debug[start..=end].to_string()
+    }
+
+    let robots_client = extractor.robots_client()?;
+
+    assert!(proxies(&scan_client).contains("127.0.0.1:8080"));
+    assert_eq!(proxies(&robots_client), proxies(&scan_client));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// robots.txt should be requested through the proxy, even for a host that can't be resolved
+async fn request_robots_txt_goes_through_proxy() -> Result<()> {
+    let srv = MockServer::start();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/robots.txt");
+        then.status(200).body("proxied");
+    });
+
+    let config = Configuration {
+        proxy: srv.url(""),
+        proxy_all: true,
+        ..Default::default()
+    };
+    let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+
+    let extractor = ExtractorBuilder::default()
+        .url("http://robots.ferox-proxy-test.invalid/api/users")
+        .target(ExtractionTarget::RobotsTxt)
+        .handles(handles)
+        .build()?;
+
+    let resp = extractor.request_robots_txt().await?;
+
+    assert_eq!(resp.text(), "proxied");
+    assert_eq!(mock.hits(), 1);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// extracting from robots.txt more than once on the same host should only request it once and
+/// reuse the paths found the first time
+async fn extract_from_robots_caches_paths_per_host() -> Result<()> {
+    let (handles, _rx) = Handles::for_testing(None, None);
+    let handles = Arc::new(handles);
+
+    let srv = MockServer::start();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/robots.txt");
+        then.status(200)
+            .body("User-agent: *\nDisallow: /secret/stuff.php\n");
+    });
+
+    for path in &["/", "/api/users/", "/api/users/stuff/"] {
+        let expected: HashSet<_> = vec![
+            srv.url(format!("{}secret/stuff.php", path)),
+            srv.url(format!("{}secret/", path)),
+        ]
+        .into_iter()
+        .collect();
+
+        let extractor = ExtractorBuilder::default()
+            .url(&srv.url(*path))
+            .target(ExtractionTarget::RobotsTxt)
+            .handles(handles.clone())
+            .build()?;
+
+        assert_eq!(extractor.extract().await?, expected);
+    }
+
+    assert_eq!(mock.hits(), 1);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// a host without a robots.txt should be remembered, so that it isn't asked again
+async fn extract_from_robots_caches_missing_robots_txt() -> Result<()> {
+    let (handles, _rx) = Handles::for_testing(None, None);
+    let handles = Arc::new(handles);
+
+    let srv = MockServer::start();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/robots.txt");
+        then.status(404).body("Disallow: /not-a-robots-file/");
+    });
+
+    for path in &["/", "/api/"] {
+        let extractor = ExtractorBuilder::default()
+            .url(&srv.url(*path))
+            .target(ExtractionTarget::RobotsTxt)
+            .handles(handles.clone())
+            .build()?;
+
+        assert!(extractor.extract().await?.is_empty());
+    }
+
+    assert_eq!(mock.hits(), 1);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 /// request_link's happy path, expect back a FeroxResponse
 async fn request_link_happy_path() -> Result<()> {
@@ -325,6 +738,46 @@ async fn request_link_happy_path() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// request_link should add the user's query parameters to an extracted link w/o losing the
+/// parameters the link already had
+async fn request_link_merges_queries_into_existing_ones() -> Result<()> {
+    let srv = MockServer::start();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET)
+            .path("/search")
+            .query_param("page", "2")
+            .query_param("token", "stuff");
+        then.status(200).body("this is a test");
+    });
+
+    let config = Configuration {
+        queries: vec![(String::from("token"), String::from("stuff"))],
+        ..Default::default()
+    };
+    let handles = Arc::new(
+        Handles::for_testing(
+            Some(Arc::new(FeroxScans::default())),
+            Some(Arc::new(config)),
+        )
+        .0,
+    );
+
+    let extractor = ExtractorBuilder::default()
+        .url(&srv.url("/"))
+        .target(ExtractionTarget::RobotsTxt)
+        .handles(handles)
+        .build()?;
+
+    let resp = extractor.request_link(&srv.url("/search?page=2")).await?;
+
+    assert!(matches!(resp.status(), &StatusCode::OK));
+    assert_eq!(resp.url().query(), Some("page=2&token=stuff"));
+    assert_eq!(mock.hits(), 1);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 /// request_link should bail in the event that the url is already in scanned_urls
 async fn request_link_bails_on_seen_url() -> Result<()> {
@@ -352,3 +805,65 @@ async fn request_link_bails_on_seen_url() -> Result<()> {
     assert_eq!(mock.hits(), 0); // function exits before requests can happen
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// links shouldn't be searched for in a body whose Content-Type says it's binary, while the same
+/// body served as text is searched as usual
+async fn extractor_skips_binary_content_types() -> Result<()> {
+    let (body, expected) = compressible_body();
+
+    let links =
+        extract_links_with_header(body.as_bytes().to_vec(), "Content-Type", "image/png").await?;
+    assert!(links.is_empty());
+
+    let links =
+        extract_links_with_header(body.as_bytes().to_vec(), "Content-Type", "text/html").await?;
+    assert_eq!(links, expected);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// the Path of a Set-Cookie header should be extracted along w/ the links in the body, even when
+/// the body isn't searched
+async fn extractor_get_links_from_set_cookie_path() -> Result<()> {
+    let (body, mut expected) = compressible_body();
+    let cookie = "sess=x; Path=/admin/panel; HttpOnly";
+
+    let links = extract_links_with_header(body.as_bytes().to_vec(), "Set-Cookie", cookie).await?;
+
+    expected.insert(String::from("/admin/panel"));
+    expected.insert(String::from("/admin/"));
+    assert_eq!(links, expected);
+
+    let links =
+        extract_links_with_header(vec![0x89, 0x50, 0x4e, 0x47, 0x00], "Set-Cookie", cookie).await?;
+
+    let cookie_only: HashSet<_> = vec!["/admin/panel", "/admin/"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+    assert_eq!(links, cookie_only);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+/// without a useful Content-Type, a body that looks binary shouldn't be searched for links
+async fn extractor_skips_bodies_that_look_binary() -> Result<()> {
+    let (body, expected) = compressible_body();
+
+    let mut binary = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR".to_vec();
+    binary.extend_from_slice(body.as_bytes());
+
+    let links =
+        extract_links_with_header(binary, "Content-Type", "application/octet-stream").await?;
+    assert!(links.is_empty());
+
+    let links = extract_links_with_header(
+        body.as_bytes().to_vec(),
+        "Content-Type",
+        "application/octet-stream",
+    )
+    .await?;
+    assert_eq!(links, expected);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use super::*;
+use reqwest::Url;
+
+/// Simple implementor of FeroxFilter; used to filter out responses within a single directory
+/// that have the same status code and content length as a random-path probe (--auto-filter)
+#[derive(Default, Debug, PartialEq)]
+pub struct AutoFilter {
+    /// directory in which the probe was made, stored without a trailing slash
+    pub directory: String,
+
+    /// status code returned by the probe
+    pub status_code: u16,
+
+    /// content length returned by the probe
+    pub content_length: u64,
+}
+
+/// implementation of AutoFilter
+impl AutoFilter {
+    /// given the directory that was probed and the probe's response, create a new AutoFilter
+    pub fn new(directory: &str, response: &FeroxResponse) -> Self {
+        Self {
+            directory: directory.trim_end_matches('/').to_string(),
+            status_code: response.status().as_u16(),
+            content_length: response.content_length(),
+        }
+    }
+
+    /// determine whether or not the given url lives directly within this filter's directory
+    ///
+    /// sub-directories aren't considered to be within the directory, as they get probed on their
+    /// own when recursed into
+    fn in_directory(&self, url: &Url) -> bool {
+        let mut stripped = url.clone();
+        stripped.set_query(None);
+        stripped.set_fragment(None);
+
+        let as_str = stripped.as_str();
+
+        let prefix = format!("{}/", self.directory);
+
+        if let Some(remainder) = as_str.strip_prefix(&prefix) {
+            // directories found via --add-slash or links end with a slash, so that's trimmed
+            // before looking for any additional path segments
+            let remainder = remainder.trim_end_matches('/');
+            return !remainder.is_empty() && !remainder.contains('/');
+        }
+
+        false
+    }
+}
+
+/// implementation of FeroxFilter for AutoFilter
+impl FeroxFilter for AutoFilter {
+    /// Check the response's status code and content length against those seen when probing
+    /// the response's directory
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        if response.status().as_u16() == self.status_code
+            && response.content_length() == self.content_length
+            && self.in_directory(response.url())
+        {
+            log::debug!(
+                "filtered out {} based on --auto-filter probe of {}",
+                response.url(),
+                self.directory
+            );
+            log::trace!("exit: should_filter_response -> true");
+            return true;
+        }
+
+        log::trace!("exit: should_filter_response -> false");
+        false
+    }
+
+    /// Compare one AutoFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().map_or(false, |a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
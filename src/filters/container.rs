@@ -8,7 +8,7 @@ use crate::{
     CommandSender,
 };
 
-use super::{FeroxFilter, WildcardFilter};
+use super::{AutoFilter, FeroxFilter, WildcardFilter};
 
 /// Container around a collection of `FeroxFilters`s
 #[derive(Debug, Default)]
@@ -42,7 +42,9 @@ impl FeroxFilters {
             for filter in filters.iter() {
                 // wildcard.should_filter goes here
                 if filter.should_filter_response(&response) {
-                    if filter.as_any().downcast_ref::<WildcardFilter>().is_some() {
+                    if filter.as_any().downcast_ref::<WildcardFilter>().is_some()
+                        || filter.as_any().downcast_ref::<AutoFilter>().is_some()
+                    {
                         tx_stats
                             .send(AddToUsizeField(WildcardsFiltered, 1))
                             .unwrap_or_default();
@@ -1,5 +1,6 @@
 use super::{
-    LinesFilter, RegexFilter, SimilarityFilter, SizeFilter, StatusCodeFilter, WordsFilter,
+    LinesFilter, MatchFilter, RedirectsOnlyFilter, RegexFilter, SimilarityFilter, SizeFilter,
+    StatusCodeFilter, WordsFilter,
 };
 use crate::{
     event_handlers::Handles,
@@ -7,16 +8,27 @@ use crate::{
     skip_fail,
     utils::{fmt_err, logged_request},
     Command::AddFilter,
-    SIMILARITY_THRESHOLD,
 };
 use anyhow::Result;
-use fuzzyhash::FuzzyHash;
 use regex::Regex;
 use reqwest::Url;
 use std::sync::Arc;
 
 /// add all user-supplied filters to the (already started) filters handler
 pub async fn initialize(handles: Arc<Handles>) -> Result<()> {
+    // only keep responses that match what was asked for, the rest of the filters are applied to
+    // whatever's left  (--match-status|--match-size|--match-words|--match-lines)
+    let match_filter = MatchFilter {
+        status_codes: handles.config.match_status.clone(),
+        content_lengths: handles.config.match_size.clone(),
+        word_counts: handles.config.match_word_count.clone(),
+        line_counts: handles.config.match_line_count.clone(),
+    };
+
+    if !match_filter.is_empty() {
+        handles.filters.send(AddFilter(Box::new(match_filter)))?;
+    }
+
     // add any status code filters to filters handler's FeroxFilters  (-C|--filter-status)
     for code_filter in &handles.config.filter_status {
         let filter = StatusCodeFilter {
@@ -75,20 +87,27 @@ pub async fn initialize(handles: Arc<Handles>) -> Result<()> {
         let resp = skip_fail!(logged_request(&url, handles.clone()).await);
 
         // if successful, create a filter based on the response's body
-        let fr = FeroxResponse::from(resp, true, handles.config.output_level).await;
+        let fr = FeroxResponse::from(
+            resp,
+            true,
+            handles.config.max_body_size,
+            handles.config.output_level,
+        )
+        .await;
 
         // hash the response body and store the resulting hash in the filter object
-        let hash = FuzzyHash::new(&fr.text()).to_string();
-
-        let filter = SimilarityFilter {
-            text: hash,
-            threshold: SIMILARITY_THRESHOLD,
-        };
+        let filter = SimilarityFilter::from_response(&fr, handles.config.similarity_threshold);
 
         let boxed_filter = Box::new(filter);
         skip_fail!(handles.filters.send(AddFilter(boxed_filter)));
     }
 
+    // only report redirects (--redirects-only)
+    if handles.config.redirects_only {
+        let boxed_filter = Box::new(RedirectsOnlyFilter::default());
+        handles.filters.send(AddFilter(boxed_filter))?;
+    }
+
     handles.filters.sync().await?;
     Ok(())
 }
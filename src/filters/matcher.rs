@@ -0,0 +1,69 @@
+use super::*;
+
+/// Implementor of FeroxFilter that works the other way around from the rest of the filters;
+/// responses that *don't* match are filtered out; specified using --match-status, --match-size,
+/// --match-words, and --match-lines
+///
+/// A response matches when it matches one of the given values of each kind of matcher that was
+/// specified, i.e. `--match-status 200 --match-size 4096,512` keeps 200s that are either 4096 or
+/// 512 bytes long. Kinds of matchers that weren't specified match everything.
+#[derive(Default, Debug, PartialEq)]
+pub struct MatchFilter {
+    /// status codes that should be kept
+    pub status_codes: Vec<u16>,
+
+    /// response body lengths that should be kept
+    pub content_lengths: Vec<u64>,
+
+    /// response body word counts that should be kept
+    pub word_counts: Vec<usize>,
+
+    /// response body line counts that should be kept
+    pub line_counts: Vec<usize>,
+}
+
+/// implementation of MatchFilter
+impl MatchFilter {
+    /// whether or not any matchers were specified; an empty MatchFilter keeps everything
+    pub fn is_empty(&self) -> bool {
+        self.status_codes.is_empty()
+            && self.content_lengths.is_empty()
+            && self.word_counts.is_empty()
+            && self.line_counts.is_empty()
+    }
+
+    /// an unspecified matcher matches anything, otherwise `value` needs to be one of `allowed`
+    fn matches<T: PartialEq>(allowed: &[T], value: T) -> bool {
+        allowed.is_empty() || allowed.contains(&value)
+    }
+}
+
+/// implementation of FeroxFilter for MatchFilter
+impl FeroxFilter for MatchFilter {
+    /// Filter any response that doesn't match all of the specified matchers
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        let is_match = Self::matches(&self.status_codes, response.status().as_u16())
+            && Self::matches(&self.content_lengths, response.content_length())
+            && Self::matches(&self.word_counts, response.word_count())
+            && Self::matches(&self.line_counts, response.line_count());
+
+        if !is_match {
+            log::debug!("filtered out {} based on --match-*", response.url());
+        }
+
+        log::trace!("exit: should_filter_response -> {}", !is_match);
+        !is_match
+    }
+
+    /// Compare one MatchFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().map_or(false, |a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
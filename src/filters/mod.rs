@@ -5,11 +5,14 @@ use std::fmt::Debug;
 use crate::response::FeroxResponse;
 use crate::traits::{FeroxFilter, FeroxSerialize};
 
+pub use self::auto_filter::AutoFilter;
 pub use self::container::FeroxFilters;
 pub use self::init::initialize;
 pub use self::lines::LinesFilter;
+pub use self::matcher::MatchFilter;
+pub use self::redirects::RedirectsOnlyFilter;
 pub use self::regex::RegexFilter;
-pub use self::similarity::SimilarityFilter;
+pub use self::similarity::{fingerprint, SimilarityFilter};
 pub use self::size::SizeFilter;
 pub use self::status_code::StatusCodeFilter;
 pub use self::wildcard::WildcardFilter;
@@ -22,6 +25,9 @@ mod lines;
 mod size;
 mod regex;
 mod similarity;
+mod auto_filter;
+mod redirects;
+mod matcher;
 mod container;
 #[cfg(test)]
 mod tests;
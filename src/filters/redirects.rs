@@ -0,0 +1,33 @@
+use super::*;
+
+/// Simple implementor of FeroxFilter; used to filter out everything that isn't a redirect when
+/// --redirects-only is used
+#[derive(Default, Debug, PartialEq)]
+pub struct RedirectsOnlyFilter {}
+
+/// implementation of FeroxFilter for RedirectsOnlyFilter
+impl FeroxFilter for RedirectsOnlyFilter {
+    /// Filter any response that isn't a 3xx and wasn't reached by following redirects
+    fn should_filter_response(&self, response: &FeroxResponse) -> bool {
+        log::trace!("enter: should_filter_response({:?} {})", self, response);
+
+        if !response.is_redirect() {
+            log::debug!("filtered out {} based on --redirects-only", response.url());
+            log::trace!("exit: should_filter_response -> true");
+            return true;
+        }
+
+        log::trace!("exit: should_filter_response -> false");
+        false
+    }
+
+    /// Compare one RedirectsOnlyFilter to another
+    fn box_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().map_or(false, |a| self == a)
+    }
+
+    /// Return self as Any for dynamic dispatch purposes
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
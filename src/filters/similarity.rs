@@ -12,14 +12,32 @@ pub struct SimilarityFilter {
     pub threshold: u32,
 }
 
+/// implementation of SimilarityFilter
+impl SimilarityFilter {
+    /// given the response to the page passed via --filter-similar-to, create a filter that
+    /// removes responses that are at least `threshold` percent similar to it
+    pub fn from_response(response: &FeroxResponse, threshold: u32) -> Self {
+        Self {
+            text: fingerprint(response.text()),
+            threshold,
+        }
+    }
+}
+
+/// compute the fuzzy hash (ssdeep) of the given response body; any two bodies' fingerprints can
+/// be compared w/ `FuzzyHash::compare` to get their similarity as a percentage
+pub fn fingerprint(body: &str) -> String {
+    FuzzyHash::new(body).to_string()
+}
+
 /// implementation of FeroxFilter for SimilarityFilter
 impl FeroxFilter for SimilarityFilter {
     /// Check `FeroxResponse::text` against what was requested from the site passed in via
     /// --filter-similar-to
     fn should_filter_response(&self, response: &FeroxResponse) -> bool {
-        let other = FuzzyHash::new(&response.text());
+        let other = fingerprint(response.text());
 
-        if let Ok(result) = FuzzyHash::compare(&self.text, &other.to_string()) {
+        if let Ok(result) = FuzzyHash::compare(&self.text, &other) {
             return result >= self.threshold;
         }
 
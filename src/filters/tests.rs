@@ -1,6 +1,7 @@
 use super::*;
 use ::fuzzyhash::FuzzyHash;
 use ::regex::Regex;
+use reqwest::StatusCode;
 
 #[test]
 /// simply test the default values for wildcardfilter, expect 0, 0
@@ -192,6 +193,25 @@ fn similarity_filter_is_accurate() {
     assert!(filter.should_filter_response(&resp));
 }
 
+#[test]
+/// a filter created from a response should use the response's fingerprint and the given threshold
+fn similarity_filter_from_response() {
+    let mut unwanted = FeroxResponse::default();
+    unwanted.set_text("some data to hash for the purposes of running a test");
+
+    let filter = SimilarityFilter::from_response(&unwanted, 17);
+
+    assert_eq!(filter.text, fingerprint(unwanted.text()));
+    assert_eq!(filter.threshold, 17);
+
+    let mut resp = FeroxResponse::default();
+    resp.set_url("http://localhost/stuff");
+    resp.set_text("some data to hash for the purposes of running a te");
+
+    assert!(filter.should_filter_response(&resp));
+    assert!(!SimilarityFilter::from_response(&unwanted, 100).should_filter_response(&resp));
+}
+
 #[test]
 /// just a simple test to increase code coverage by hitting as_any and the inner value
 fn similarity_filter_as_any() {
@@ -213,3 +233,196 @@ fn similarity_filter_as_any() {
         filter
     );
 }
+
+#[test]
+/// just a simple test to increase code coverage by hitting as_any and the inner value
+fn auto_filter_as_any() {
+    let filter = AutoFilter {
+        directory: String::from("http://localhost/stuff"),
+        status_code: 200,
+        content_length: 42,
+    };
+
+    let filter2 = AutoFilter {
+        directory: String::from("http://localhost/stuff"),
+        status_code: 200,
+        content_length: 42,
+    };
+
+    assert!(filter.box_eq(filter2.as_any()));
+
+    assert_eq!(filter.directory, "http://localhost/stuff");
+    assert_eq!(
+        *filter.as_any().downcast_ref::<AutoFilter>().unwrap(),
+        filter
+    );
+}
+
+#[test]
+/// AutoFilter::new should strip the directory's trailing slash and record status/size
+fn auto_filter_new_records_probe_response() {
+    let mut resp = FeroxResponse::default();
+    resp.set_url("http://localhost/stuff/a1b2c3");
+    resp.set_text("probe body");
+
+    let filter = AutoFilter::new("http://localhost/stuff/", &resp);
+
+    assert_eq!(filter.directory, "http://localhost/stuff");
+    assert_eq!(filter.status_code, 200);
+    assert_eq!(filter.content_length, 10);
+}
+
+#[test]
+/// test should_filter on AutoFilter only filters matching responses within the probed directory
+fn auto_filter_should_filter_only_within_its_directory() {
+    let filter = AutoFilter {
+        directory: String::from("http://localhost/stuff"),
+        status_code: 200,
+        content_length: 10,
+    };
+
+    let mut resp = FeroxResponse::default();
+    resp.set_text("probe body");
+
+    resp.set_url("http://localhost/stuff/things");
+    assert!(filter.should_filter_response(&resp));
+
+    resp.set_url("http://localhost/stuff/things/");
+    assert!(filter.should_filter_response(&resp));
+
+    resp.set_url("http://localhost/stuff/things?id=1");
+    assert!(filter.should_filter_response(&resp));
+
+    // sub-directories get their own probe
+    resp.set_url("http://localhost/stuff/things/more");
+    assert!(!filter.should_filter_response(&resp));
+
+    // sibling/parent directories aren't affected
+    resp.set_url("http://localhost/stuffing");
+    assert!(!filter.should_filter_response(&resp));
+
+    resp.set_url("http://localhost/things");
+    assert!(!filter.should_filter_response(&resp));
+
+    resp.set_url("http://localhost/stuff/");
+    assert!(!filter.should_filter_response(&resp));
+}
+
+#[test]
+/// test should_filter on AutoFilter requires both status code and size to match
+fn auto_filter_should_not_filter_when_status_or_size_differ() {
+    let filter = AutoFilter {
+        directory: String::from("http://localhost"),
+        status_code: 200,
+        content_length: 10,
+    };
+
+    let mut resp = FeroxResponse::default();
+    resp.set_url("http://localhost/things");
+    resp.set_text("probe body");
+    assert!(filter.should_filter_response(&resp));
+
+    resp.set_text("not the probe body");
+    assert!(!filter.should_filter_response(&resp));
+
+    resp.set_text("probe body");
+    resp.set_status(StatusCode::FORBIDDEN);
+    assert!(!filter.should_filter_response(&resp));
+}
+
+#[test]
+/// RedirectsOnlyFilter should only keep 3xx responses and responses reached by following redirects
+fn redirects_only_filter_keeps_only_redirects() {
+    let filter = RedirectsOnlyFilter::default();
+
+    let mut resp = FeroxResponse::default();
+    resp.set_url("http://localhost/stuff");
+    resp.set_status(StatusCode::OK);
+    assert!(filter.should_filter_response(&resp));
+
+    resp.set_status(StatusCode::MOVED_PERMANENTLY);
+    assert!(!filter.should_filter_response(&resp));
+
+    resp.set_status(StatusCode::OK);
+    resp.set_redirect_origin(
+        &reqwest::Url::parse("http://localhost/old-stuff").unwrap(),
+        1,
+    );
+    assert!(!filter.should_filter_response(&resp));
+
+    assert!(filter.box_eq(RedirectsOnlyFilter::default().as_any()));
+}
+
+#[test]
+/// MatchFilter should keep responses matching any value of each given matcher, and filter the rest
+fn match_filter_keeps_only_matching_responses() {
+    let filter = MatchFilter {
+        status_codes: vec![200, 401],
+        content_lengths: vec![14, 4096],
+        ..Default::default()
+    };
+
+    let mut resp = FeroxResponse::default();
+    resp.set_url("http://localhost/stuff");
+    resp.set_text("this is a test");
+    assert!(!filter.should_filter_response(&resp));
+
+    resp.set_status(StatusCode::UNAUTHORIZED);
+    assert!(!filter.should_filter_response(&resp));
+
+    // right size, wrong status
+    resp.set_status(StatusCode::FORBIDDEN);
+    assert!(filter.should_filter_response(&resp));
+
+    // right status, wrong size
+    resp.set_status(StatusCode::OK);
+    resp.set_text("this is not a test");
+    assert!(filter.should_filter_response(&resp));
+
+    // unspecified matchers match anything
+    assert!(MatchFilter::default().is_empty());
+    assert!(!MatchFilter::default().should_filter_response(&resp));
+    assert!(!filter.is_empty());
+
+    assert!(filter.box_eq(
+        MatchFilter {
+            status_codes: vec![200, 401],
+            content_lengths: vec![14, 4096],
+            ..Default::default()
+        }
+        .as_any()
+    ));
+}
+
+#[test]
+/// an inclusion filter along w/ exclusion filters should only report responses that match the
+/// former and aren't excluded by the latter
+fn match_filter_and_exclusion_filters_intersect() {
+    let filters = FeroxFilters::default();
+    let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+    filters
+        .push(Box::new(MatchFilter {
+            status_codes: vec![200],
+            ..Default::default()
+        }))
+        .unwrap();
+    filters
+        .push(Box::new(WordsFilter { word_count: 4 }))
+        .unwrap();
+
+    let mut kept = FeroxResponse::default();
+    kept.set_text("not filtered");
+    assert!(!filters.should_filter_response(&kept, tx.clone()));
+
+    // matched, but excluded
+    let mut excluded = FeroxResponse::default();
+    excluded.set_text("this is a test");
+    assert!(filters.should_filter_response(&excluded, tx.clone()));
+
+    // not excluded, but not matched either
+    let mut unmatched = FeroxResponse::default();
+    unmatched.set_text("not filtered");
+    unmatched.set_status(StatusCode::NOT_FOUND);
+    assert!(filters.should_filter_response(&unmatched, tx));
+}
@@ -1,13 +1,19 @@
 use std::sync::Arc;
 
-use anyhow::{bail, Result};
-use console::style;
+use anyhow::{anyhow, bail, Result};
+use console::{style, user_attended, Term};
+use reqwest::Url;
+use tokio::{
+    net::TcpStream,
+    time::{timeout, Duration},
+};
 use uuid::Uuid;
 
 use crate::{
+    client,
     config::OutputLevel,
     event_handlers::{Command, Handles},
-    filters::WildcardFilter,
+    filters::{AutoFilter, SimilarityFilter, WildcardFilter},
     progress::PROGRESS_PRINTER,
     response::FeroxResponse,
     skip_fail,
@@ -15,6 +21,9 @@ use crate::{
     utils::{ferox_print, fmt_err, logged_request, status_colorizer},
 };
 
+/// port used for a socks proxy that doesn't specify one
+const SOCKS_DEFAULT_PORT: u16 = 1080;
+
 /// length of a standard UUID, used when determining wildcard responses
 const UUID_LENGTH: u64 = 32;
 
@@ -142,6 +151,75 @@ impl HeuristicTests {
         Ok(2)
     }
 
+    /// Requests a single random path from the given directory and records the response's status
+    /// code and content length.
+    ///
+    /// When the probe's status code is one that would be reported, an
+    /// [AutoFilter](struct.AutoFilter.html) is created and sent to the filters event handler, which
+    /// results in any response from the same directory with an identical status code and content
+    /// length being filtered out.
+    ///
+    /// Returns the number of times to increment the caller's progress bar
+    pub async fn auto_filter(&self, target_url: &str) -> Result<u64> {
+        log::trace!("enter: auto_filter({:?})", target_url);
+
+        if !self.handles.config.auto_filter {
+            // early return, only --auto-filter scans need probed
+            log::trace!("exit: auto_filter -> 0");
+            return Ok(0);
+        }
+
+        let ferox_url = FeroxUrl::from_string(target_url, self.handles.clone());
+
+        let unique_str = self.unique_string(1);
+        let nonexistent_url = ferox_url.format(&unique_str, None)?;
+
+        let response = logged_request(&nonexistent_url, self.handles.clone()).await?;
+        let ferox_response = FeroxResponse::from(
+            response,
+            true,
+            self.handles.config.max_body_size,
+            self.handles.config.output_level,
+        )
+        .await;
+
+        let status = ferox_response.status().as_u16();
+
+        if !self.handles.config.status_codes.contains(&status) {
+            // responses with this status code won't be reported anyway, no need for a filter
+            log::trace!("exit: auto_filter -> 1");
+            return Ok(1);
+        }
+
+        let filter = AutoFilter::new(target_url, &ferox_response);
+
+        if matches!(
+            self.handles.config.output_level,
+            OutputLevel::Default | OutputLevel::Quiet
+        ) {
+            let msg = format!(
+                "{} {:>9} {:>9} {:>9} Probe of {} returned {}; {} {} responses of {} bytes in this directory\n",
+                status_colorizer("WLD"),
+                "-",
+                "-",
+                "-",
+                target_url,
+                status_colorizer(&status.to_string()),
+                style("auto-filtering").yellow(),
+                status_colorizer(&status.to_string()),
+                style(filter.content_length).cyan(),
+            );
+            ferox_print(&msg, &PROGRESS_PRINTER);
+        }
+
+        self.handles
+            .filters
+            .send(Command::AddFilter(Box::new(filter)))?;
+
+        log::trace!("exit: auto_filter -> 1");
+        Ok(1)
+    }
+
     /// Generates a uuid and appends it to the given target url. The reasoning is that the randomly
     /// generated unique string should not exist on and be served by the target web server.
     ///
@@ -158,7 +236,10 @@ impl HeuristicTests {
         let unique_str = self.unique_string(length);
         let nonexistent_url = target.format(&unique_str, None)?;
 
-        let response = logged_request(&nonexistent_url.to_owned(), self.handles.clone()).await?;
+        let (response, hops) =
+            client::with_redirect_hops(logged_request(&nonexistent_url, self.handles.clone()))
+                .await;
+        let response = response?;
 
         if self
             .handles
@@ -167,9 +248,15 @@ impl HeuristicTests {
             .contains(&response.status().as_u16())
         {
             // found a wildcard response
-            let mut ferox_response =
-                FeroxResponse::from(response, true, self.handles.config.output_level).await;
+            let mut ferox_response = FeroxResponse::from(
+                response,
+                true,
+                self.handles.config.max_body_size,
+                self.handles.config.output_level,
+            )
+            .await;
             ferox_response.set_wildcard(true);
+            ferox_response.set_redirect_origin(&nonexistent_url, hops);
 
             if self
                 .handles
@@ -196,6 +283,50 @@ impl HeuristicTests {
         bail!("uninteresting status code")
     }
 
+    /// Makes sure the proxy is accepting connections before anything is sent through it
+    /// (--proxy-all)
+    ///
+    /// requests never fall back to a direct connection when a proxy is configured, but w/o this
+    /// check, a dead proxy would only show up as each target failing the connectivity test; with
+    /// --proxy-all, it's treated as the fatal error it is instead
+    pub async fn proxy_connectivity(&self) -> Result<()> {
+        log::trace!("enter: proxy_connectivity");
+
+        let proxy = &self.handles.config.proxy;
+
+        let url =
+            Url::parse(proxy).map_err(|e| anyhow!("Could not parse proxy {}: {}", proxy, e))?;
+
+        let host = match url.host_str() {
+            Some(host) => host,
+            None => bail!("Could not determine the host of proxy {}", proxy),
+        };
+
+        // socks proxies have no default port as far as Url is concerned
+        let port = url.port_or_known_default().unwrap_or(SOCKS_DEFAULT_PORT);
+        let address = format!("{}:{}", host, port);
+        let wait = Duration::from_secs(self.handles.config.timeouts().connect);
+
+        match timeout(wait, TcpStream::connect(&address)).await {
+            Ok(Ok(_)) => {
+                log::trace!("exit: proxy_connectivity");
+                Ok(())
+            }
+            Ok(Err(e)) => {
+                log::trace!("exit: proxy_connectivity -> {}", e);
+                bail!("Could not connect to proxy {} (--proxy-all): {}", proxy, e)
+            }
+            Err(_) => {
+                log::trace!("exit: proxy_connectivity -> timed out");
+                bail!(
+                    "Could not connect to proxy {} (--proxy-all): timed out after {}s",
+                    proxy,
+                    wait.as_secs()
+                )
+            }
+        }
+    }
+
     /// Simply tries to connect to all given sites before starting to scan
     ///
     /// In the event that no sites can be reached, the program will exit.
@@ -245,6 +376,167 @@ impl HeuristicTests {
         log::trace!("exit: connectivity_test -> {:?}", good_urls);
         Ok(good_urls)
     }
+
+    /// Requests a random path from each of the given targets, along w/ a random subdomain of
+    /// targets given by domain name, before any scanning starts
+    ///
+    /// A probe that comes back w/ a status code that would be reported, where a 404 (or a DNS
+    /// failure) was expected, means the target answers for anything and the scan is likely to be
+    /// full of false positives. Each such target results in a warning. When someone's around to
+    /// answer, they're asked whether to continue as-is, filter out responses similar to the probe,
+    /// or skip the target altogether.
+    ///
+    /// Returns the targets that should still be scanned
+    pub async fn catch_all(&self, target_urls: Vec<String>) -> Result<Vec<String>> {
+        log::trace!("enter: catch_all({:?})", target_urls);
+
+        let mut kept = vec![];
+
+        for target_url in target_urls {
+            let probe = match self.probe_catch_all(&target_url).await {
+                Some(probe) => probe,
+                None => {
+                    kept.push(target_url);
+                    continue;
+                }
+            };
+
+            let msg = format!(
+                "{} {:>9} {:>9} {:>9} {}: {} looks like a catch-all; {} returned {} ({} bytes) where a 404 was expected\n",
+                status_colorizer("WLD"),
+                "-",
+                "-",
+                "-",
+                style("WARNING").bold().yellow(),
+                target_url,
+                probe.url(),
+                status_colorizer(&probe.status().as_u16().to_string()),
+                style(probe.content_length()).cyan(),
+            );
+            ferox_print(&msg, &PROGRESS_PRINTER);
+
+            if !matches!(self.handles.config.output_level, OutputLevel::Default) || !user_attended()
+            {
+                // nobody to ask, point out how to deal with it and move on
+                let msg = format!(
+                    "{} {:>9} {:>9} {:>9} Expect false positives; use {} {} to filter them, or {} to hide this warning\n",
+                    status_colorizer("WLD"),
+                    "-",
+                    "-",
+                    "-",
+                    style("--filter-similar-to").yellow(),
+                    probe.url(),
+                    style("--ignore-wildcard-warning").yellow(),
+                );
+                ferox_print(&msg, &PROGRESS_PRINTER);
+                kept.push(target_url);
+                continue;
+            }
+
+            let term = Term::stderr();
+
+            let _ = term.write_line(&format!(
+                "Expect false positives; [{}]ontinue, [{}]ilter responses similar to the probe, or [{}]kip {}? [C/f/s]",
+                style("c").yellow(),
+                style("f").yellow(),
+                style("s").yellow(),
+                target_url
+            ));
+
+            match term.read_char().unwrap_or('c').to_ascii_lowercase() {
+                'f' => {
+                    let filter = SimilarityFilter::from_response(
+                        &probe,
+                        self.handles.config.similarity_threshold,
+                    );
+                    self.handles
+                        .filters
+                        .send(Command::AddFilter(Box::new(filter)))?;
+                    kept.push(target_url);
+                }
+                's' => log::info!("skipping catch-all target {}", target_url),
+                _ => kept.push(target_url),
+            }
+        }
+
+        self.handles.filters.sync().await?;
+
+        log::trace!("exit: catch_all -> {:?}", kept);
+        Ok(kept)
+    }
+
+    /// request a random path, and when the target is a domain name, a random subdomain of it;
+    /// the first of the two that looks like it was served by a catch-all is returned
+    async fn probe_catch_all(&self, target_url: &str) -> Option<FeroxResponse> {
+        log::trace!("enter: probe_catch_all({})", target_url);
+
+        let mut probes = vec![];
+
+        if let Ok(url) = Url::parse(&format!(
+            "{}/{}",
+            target_url.trim_end_matches('/'),
+            self.unique_string(1)
+        )) {
+            probes.push(url);
+        }
+
+        if let Ok(mut url) = Url::parse(target_url) {
+            // ip addresses and single-label names (localhost etc) can't have wildcard dns
+            let domain = url.domain().filter(|d| d.contains('.')).map(String::from);
+
+            if let Some(domain) = domain {
+                let random_host = format!("{}.{}", self.unique_string(1), domain);
+
+                if url.set_host(Some(&random_host)).is_ok() {
+                    probes.push(url);
+                }
+            }
+        }
+
+        for probe in probes {
+            let response = match logged_request(&probe, self.handles.clone()).await {
+                Ok(response) => response,
+                Err(e) => {
+                    // a random subdomain not resolving is what's supposed to happen
+                    log::debug!("catch-all probe of {} failed: {}", probe, e);
+                    continue;
+                }
+            };
+
+            if !self
+                .handles
+                .config
+                .status_codes
+                .contains(&response.status().as_u16())
+            {
+                continue;
+            }
+
+            let ferox_response = FeroxResponse::from(
+                response,
+                true,
+                self.handles.config.max_body_size,
+                self.handles.config.output_level,
+            )
+            .await;
+
+            if self
+                .handles
+                .filters
+                .data
+                .should_filter_response(&ferox_response, self.handles.stats.tx.clone())
+            {
+                // the user's filters already take care of it
+                continue;
+            }
+
+            log::trace!("exit: probe_catch_all -> {}", ferox_response);
+            return Some(ferox_response);
+        }
+
+        log::trace!("exit: probe_catch_all -> None");
+        None
+    }
 }
 
 #[cfg(test)]
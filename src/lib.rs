@@ -21,7 +21,7 @@ pub mod scanner;
 pub mod statistics;
 mod traits;
 pub mod utils;
-mod extractor;
+pub mod extractor;
 mod macros;
 mod url;
 mod response;
@@ -45,6 +45,9 @@ pub(crate) const VERSION: &str = env!("CARGO_PKG_VERSION");
 /// Maximum number of file descriptors that can be opened during a scan
 pub const DEFAULT_OPEN_FILE_LIMIT: usize = 8192;
 
+/// Default maximum number of bytes read from each response's body (5MB)
+pub const DEFAULT_MAX_BODY_SIZE: usize = 5 * 1024 * 1024;
+
 /// Default value used to determine near-duplicate web pages (equivalent to 95%)
 pub const SIMILARITY_THRESHOLD: u32 = 95;
 
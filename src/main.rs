@@ -1,7 +1,8 @@
 use std::{
+    collections::HashSet,
     env::args,
     fs::File,
-    io::{stderr, BufRead, BufReader},
+    io::{stderr, stdout, BufRead, BufReader, BufWriter},
     ops::Index,
     process::Command,
     sync::{atomic::Ordering, Arc},
@@ -20,14 +21,14 @@ use feroxbuster::{
     config::{Configuration, OutputLevel},
     event_handlers::{
         Command::{CreateBar, Exit, JoinTasks, LoadStats, ScanInitialUrls, UpdateWordlist},
-        FiltersHandler, Handles, ScanHandler, StatsHandler, Tasks, TermInputHandler,
-        TermOutHandler, SCAN_COMPLETE,
+        EventsHandler, FiltersHandler, Handles, ScanHandler, ScopeHandler, StatsHandler, Tasks,
+        TermInputHandler, TermOutHandler, SCAN_COMPLETE,
     },
     filters, heuristics, logger,
     progress::{PROGRESS_BAR, PROGRESS_PRINTER},
     scan_manager::{self},
     scanner,
-    utils::fmt_err,
+    utils::{fmt_err, transform_case},
 };
 #[cfg(not(target_os = "windows"))]
 use feroxbuster::{utils::set_open_file_limit, DEFAULT_OPEN_FILE_LIMIT};
@@ -39,37 +40,97 @@ lazy_static! {
     static ref PARALLEL_LIMITER: Semaphore = Semaphore::new(0);
 }
 
-/// Create a HashSet of Strings from the given wordlist then stores it inside an Arc
-fn get_unique_words_from_wordlist(path: &str) -> Result<Arc<Vec<String>>> {
-    log::trace!("enter: get_unique_words_from_wordlist({})", path);
+/// Read each of the given wordlists, merging their words into a single deduplicated list that's
+/// stored inside an Arc
+///
+/// words are deduplicated while they're read, so duplicates are never stored. Words keep the
+/// order in which they were first seen
+///
+/// when --transform-case is used, each word is expanded into its case variations here, before any
+/// requests are generated
+fn get_unique_words_from_wordlists(
+    paths: &[String],
+    transforms: &[String],
+) -> Result<Arc<Vec<String>>> {
+    log::trace!(
+        "enter: get_unique_words_from_wordlists({:?}, {:?})",
+        paths,
+        transforms
+    );
 
-    let file = File::open(&path).with_context(|| format!("Could not open {}", path))?;
+    let mut words = Vec::new();
+    let mut seen = HashSet::new();
+    let mut duplicates = 0;
 
-    let reader = BufReader::new(file);
+    for path in paths {
+        let file = File::open(&path).with_context(|| format!("Could not open {}", path))?;
 
-    let mut words = Vec::new();
+        let reader = BufReader::new(file);
 
-    for line in reader.lines() {
-        let result = match line {
-            Ok(read_line) => read_line,
-            Err(_) => continue,
-        };
+        for line in reader.lines() {
+            let result = match line {
+                Ok(read_line) => read_line,
+                Err(_) => continue,
+            };
+
+            if result.starts_with('#') || result.is_empty() {
+                continue;
+            }
 
-        if result.starts_with('#') || result.is_empty() {
-            continue;
+            if seen.contains(&result) {
+                duplicates += 1;
+                continue;
+            }
+
+            seen.insert(result.clone());
+            words.push(result);
         }
+    }
 
-        words.push(result);
+    log::debug!(
+        "read {} unique words from {} wordlist(s), skipped {} duplicates",
+        words.len(),
+        paths.len(),
+        duplicates
+    );
+
+    if !transforms.is_empty() {
+        words = transform_case(words, transforms);
     }
 
     log::trace!(
-        "exit: get_unique_words_from_wordlist -> Arc<wordlist[{} words...]>",
+        "exit: get_unique_words_from_wordlists -> Arc<wordlist[{} words...]>",
         words.len()
     );
 
     Ok(Arc::new(words))
 }
 
+/// Print every url that would be requested for the given targets, then return without having
+/// made a single request (--dry-run)
+fn dry_run(targets: &[String], handles: Arc<Handles>) -> Result<()> {
+    log::trace!("enter: dry_run({:?}, {:?})", targets, handles);
+
+    let words =
+        get_unique_words_from_wordlists(&handles.config.wordlist, &handles.config.transform_case)?;
+
+    if words.len() == 0 {
+        bail!(
+            "Did not find any words in {}",
+            handles.config.wordlist.join(", ")
+        );
+    }
+
+    let std_stdout = stdout();
+    let mut writer = BufWriter::new(std_stdout.lock());
+
+    let total = scanner::dry_run(targets, &words, handles, &mut writer)?;
+
+    log::debug!("dry run planned {} request(s)", total);
+    log::trace!("exit: dry_run");
+    Ok(())
+}
+
 /// Determine whether it's a single url scan or urls are coming from stdin, then scan as needed
 async fn scan(targets: Vec<String>, handles: Arc<Handles>) -> Result<()> {
     log::trace!("enter: scan({:?}, {:?})", targets, handles);
@@ -77,10 +138,14 @@ async fn scan(targets: Vec<String>, handles: Arc<Handles>) -> Result<()> {
     // so that will allow for cheap/safe sharing of a single wordlist across multi-target scans
     // as well as additional directories found as part of recursion
 
-    let words = get_unique_words_from_wordlist(&handles.config.wordlist)?;
+    let words =
+        get_unique_words_from_wordlists(&handles.config.wordlist, &handles.config.transform_case)?;
 
     if words.len() == 0 {
-        bail!("Did not find any words in {}", handles.config.wordlist);
+        bail!(
+            "Did not find any words in {}",
+            handles.config.wordlist.join(", ")
+        );
     }
 
     let scanned_urls = handles.ferox_scans()?;
@@ -177,8 +242,13 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
     // spawn all event handlers, expect back a JoinHandle and a *Handle to the specific event
     let (stats_task, stats_handle) = StatsHandler::initialize(config.clone());
     let (filters_task, filters_handle) = FiltersHandler::initialize();
-    let (out_task, out_handle) =
-        TermOutHandler::initialize(config.clone(), stats_handle.tx.clone());
+    let (scope_task, scope_handle) = ScopeHandler::initialize(config.clone());
+    let (events_task, events_handle) = EventsHandler::initialize();
+    let (out_task, out_handle) = TermOutHandler::initialize(
+        config.clone(),
+        stats_handle.tx.clone(),
+        events_handle.clone(),
+    );
 
     // bundle up all the disparate handles and JoinHandles (tasks)
     let handles = Arc::new(Handles::new(
@@ -186,6 +256,8 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         filters_handle,
         out_handle,
         config.clone(),
+        scope_handle,
+        events_handle,
     ));
 
     let (scan_task, scan_handle) = ScanHandler::initialize(handles.clone());
@@ -195,7 +267,14 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
     filters::initialize(handles.clone()).await?; // send user-supplied filters to the handler
 
     // create new Tasks object, each of these handles is one that will be joined on later
-    let tasks = Tasks::new(out_task, stats_task, filters_task, scan_task);
+    let tasks = Tasks::new(
+        out_task,
+        stats_task,
+        filters_task,
+        scan_task,
+        scope_task,
+        events_task,
+    );
 
     if !config.time_limit.is_empty() {
         // --time-limit value not an empty string, need to kick off the thread that enforces
@@ -234,6 +313,19 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         }
     };
 
+    if config.dry_run {
+        // nothing is sent over the wire, not even the banner's update check
+        let result = dry_run(&targets, handles.clone());
+        clean_up(handles, tasks).await?;
+
+        if let Err(e) = result {
+            bail!(fmt_err(&format!("Failed during dry run: {}", e)));
+        }
+
+        log::trace!("exit: dry run branch && wrapped main");
+        return Ok(());
+    }
+
     // --parallel branch
     if config.parallel > 0 {
         log::trace!("enter: parallel branch");
@@ -329,6 +421,18 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         }
     }
 
+    if config.proxy_all {
+        // nothing may go out w/o the proxy; a proxy that's down is fatal, not a reason to skip
+        // every target
+        let test = heuristics::HeuristicTests::new(handles.clone());
+        if let Err(e) = test.proxy_connectivity().await {
+            clean_up(handles, tasks).await?;
+            // unlike other errors, this one shouldn't be mistaken for a scan that finished
+            eprintln!("{}", fmt_err(&e.to_string()));
+            std::process::exit(1);
+        }
+    }
+
     // discard non-responsive targets
     let live_targets = {
         let test = heuristics::HeuristicTests::new(handles.clone());
@@ -340,6 +444,23 @@ async fn wrapped_main(config: Arc<Configuration>) -> Result<()> {
         result?
     };
 
+    // warn about targets that answer for anything; --dont-filter means wildcards are expected
+    let live_targets = if config.ignore_wildcard_warning
+        || config.dont_filter
+        || matches!(config.output_level, OutputLevel::Silent)
+    {
+        live_targets
+    } else {
+        let test = heuristics::HeuristicTests::new(handles.clone());
+        match test.catch_all(live_targets).await {
+            Ok(targets) => targets,
+            Err(e) => {
+                clean_up(handles, tasks).await?;
+                bail!(fmt_err(&e.to_string()));
+            }
+        }
+    };
+
     if live_targets.is_empty() {
         clean_up(handles, tasks).await?;
         bail!(fmt_err("Could not find any live targets to scan"));
@@ -376,14 +497,28 @@ async fn clean_up(handles: Arc<Handles>, tasks: Tasks) -> Result<()> {
     tasks.terminal.await??;
     log::trace!("terminal handler closed");
 
+    // the terminal handler subscribes to events, so it has to be closed first
+    handles.events.send(Exit)?;
+    tasks.events.await??;
+    log::trace!("events handler closed");
+
     handles.filters.send(Exit)?;
     tasks.filters.await??;
     log::trace!("filters handler closed");
 
+    handles.scope.send(Exit)?;
+    tasks.scope.await??;
+    log::trace!("scope handler closed");
+
     handles.stats.send(Exit)?;
     tasks.stats.await??;
     log::trace!("stats handler closed");
 
+    if matches!(handles.config.output_level, OutputLevel::Default) {
+        // stderr, same as the banner, so the summary doesn't end up in piped output
+        eprint!("{}", handles.stats.data.latency_summary());
+    }
+
     // mark all scans complete so the terminal input handler will exit cleanly
     SCAN_COMPLETE.store(true, Ordering::Relaxed);
 
@@ -407,6 +542,12 @@ fn main() -> Result<()> {
         logger::initialize(config.clone())?;
     }
 
+    if matches!(config.output_level, OutputLevel::Silent) {
+        // --silent output is meant for other programs, never for eyeballs
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+
     // this function uses rlimit, which is not supported on windows
     #[cfg(not(target_os = "windows"))]
     set_open_file_limit(DEFAULT_OPEN_FILE_LIMIT);
@@ -1,4 +1,4 @@
-use clap::{App, Arg, ArgGroup};
+use clap::{App, Arg};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::env;
@@ -27,8 +27,9 @@ pub fn initialize() -> App<'static, 'static> {
                 .short("w")
                 .long("wordlist")
                 .value_name("FILE")
-                .help("Path to the wordlist")
-                .takes_value(true),
+                .help("Path to the wordlist; may be used more than once, words are merged and deduplicated (ex: -w common.txt -w api.txt)")
+                .takes_value(true)
+                .multiple(true),
         )
         .arg(
             Arg::with_name("url")
@@ -54,7 +55,7 @@ pub fn initialize() -> App<'static, 'static> {
                 .long("depth")
                 .value_name("RECURSION_DEPTH")
                 .takes_value(true)
-                .help("Maximum recursion depth, a depth of 0 is infinite recursion (default: 4)"),
+                .help("Maximum recursion depth, a depth of 0 is infinite recursion and requires --max-requests-per-dir (default: 4)"),
         )
         .arg(
             Arg::with_name("timeout")
@@ -64,6 +65,20 @@ pub fn initialize() -> App<'static, 'static> {
                 .takes_value(true)
                 .help("Number of seconds before a request times out (default: 7)"),
         )
+        .arg(
+            Arg::with_name("connect_timeout")
+                .long("connect-timeout")
+                .value_name("SECONDS")
+                .takes_value(true)
+                .help("Number of seconds before a connection attempt times out (default: --timeout)"),
+        )
+        .arg(
+            Arg::with_name("read_timeout")
+                .long("read-timeout")
+                .value_name("SECONDS")
+                .takes_value(true)
+                .help("Number of seconds before a request, including reading its response, times out (default: --timeout)"),
+        )
         .arg(
             Arg::with_name("verbosity")
                 .short("v")
@@ -83,6 +98,15 @@ pub fn initialize() -> App<'static, 'static> {
                     "Proxy to use for requests (ex: http(s)://host:port, socks5(h)://host:port)",
                 ),
         )
+        .arg(
+            Arg::with_name("proxy_all")
+                .long("proxy-all")
+                .takes_value(false)
+                .requires("proxy")
+                .help(
+                    "Send every request through --proxy, robots.txt and --notify-url included; exit if the proxy is unreachable",
+                ),
+        )
         .arg(
             Arg::with_name("replay_proxy")
                 .short("P")
@@ -106,6 +130,27 @@ pub fn initialize() -> App<'static, 'static> {
                     "Status Codes to send through a Replay Proxy when found (default: --status-codes value)",
                 ),
         )
+        .arg(
+            Arg::with_name("notify_url")
+                .long("notify-url")
+                .takes_value(true)
+                .value_name("WEBHOOK")
+                .help(
+                    "POST a JSON summary of each finding to the given webhook (batched, at most once every 5 seconds)",
+                ),
+        )
+        .arg(
+            Arg::with_name("notify_on")
+                .long("notify-on")
+                .value_name("CRITERIA")
+                .takes_value(true)
+                .multiple(true)
+                .use_delimiter(true)
+                .requires("notify_url")
+                .help(
+                    "Only notify on findings matching a status code, url regex, or both (ex: --notify-on 200:admin)",
+                ),
+        )
         .arg(
             Arg::with_name("status_codes")
                 .short("s")
@@ -123,7 +168,7 @@ pub fn initialize() -> App<'static, 'static> {
                 .long("silent")
                 .takes_value(false)
                 .conflicts_with("quiet")
-                .help("Only print URLs + turn off logging (good for piping a list of urls to other commands)")
+                .help("Only print URLs (or JSON w/ --json) + turn off logging, colors, banner, and bars (good for piping a list of urls to other commands)")
         )
         .arg(
             Arg::with_name("quiet")
@@ -137,7 +182,7 @@ pub fn initialize() -> App<'static, 'static> {
                 .long("auto-tune")
                 .takes_value(false)
                 .conflicts_with("auto_bail")
-                .help("Automatically lower scan rate when an excessive amount of errors are encountered")
+                .help("Automatically lower scan rate and concurrency when an excessive amount of errors are encountered")
         )
         .arg(
             Arg::with_name("auto_bail")
@@ -145,12 +190,32 @@ pub fn initialize() -> App<'static, 'static> {
                 .takes_value(false)
                 .help("Automatically stop scanning when an excessive amount of errors are encountered")
         )
+        .arg(
+            Arg::with_name("auto_throttle")
+                .long("auto-throttle")
+                .takes_value(false)
+                .help("Pause a scan when the target responds w/ 429/503, honoring any Retry-After header")
+        )
+        .arg(
+            Arg::with_name("respect_crawl_delay")
+                .long("respect-crawl-delay")
+                .takes_value(false)
+                .help("Wait for the Crawl-delay found in a host's robots.txt between requests to that host")
+        )
         .arg(
             Arg::with_name("json")
                 .long("json")
                 .takes_value(false)
-                .requires("output_files")
-                .help("Emit JSON logs to --output and --debug-log instead of normal text")
+                .help("Emit JSON logs to --output and --debug-log instead of normal text (or to stdout w/ --silent)")
+        )
+        .arg(
+            Arg::with_name("output_format")
+                .long("output-format")
+                .value_name("FORMAT")
+                .takes_value(true)
+                .possible_values(&["text", "json", "csv"])
+                .conflicts_with("json")
+                .help("Format of the findings written to --output (default: text); json is the same as --json")
         )
         .arg(
             Arg::with_name("dont_filter")
@@ -159,6 +224,24 @@ pub fn initialize() -> App<'static, 'static> {
                 .takes_value(false)
                 .help("Don't auto-filter wildcard responses")
         )
+        .arg(
+            Arg::with_name("ignore_wildcard_warning")
+                .long("ignore-wildcard-warning")
+                .takes_value(false)
+                .help("Skip checking targets for catch-all responses/wildcard dns (and the prompt that comes with finding one)")
+        )
+        .arg(
+            Arg::with_name("auto_filter")
+                .long("auto-filter")
+                .takes_value(false)
+                .help("Probe each directory with a random path and filter responses matching its status and size")
+        )
+        .arg(
+            Arg::with_name("redirects_only")
+                .long("redirects-only")
+                .takes_value(false)
+                .help("Only report redirects (3xx responses, or responses reached by following redirects w/ --redirects)")
+        )
         .arg(
             Arg::with_name("output")
                 .short("o")
@@ -206,6 +289,47 @@ pub fn initialize() -> App<'static, 'static> {
                 .takes_value(false)
                 .help("Disables TLS certificate validation")
         )
+        .arg(
+            Arg::with_name("insecure_hostname_mismatch")
+                .long("insecure-hostname-mismatch")
+                .takes_value(false)
+                .help("Accept certificates issued for a different hostname, while still validating the rest of the certificate (-k skips all validation)")
+        )
+        .arg(
+            Arg::with_name("http_version")
+                .long("http-version")
+                .value_name("VERSION")
+                .takes_value(true)
+                .possible_values(&["auto", "1.1", "2", "2-prior-knowledge"])
+                .help("HTTP version to use (default: auto)")
+        )
+        .arg(
+            Arg::with_name("basic_auth")
+                .long("basic-auth")
+                .value_name("USER:PASS")
+                .takes_value(true)
+                .conflicts_with("bearer")
+                .help("Credentials for HTTP Basic authentication (ex: --basic-auth admin:hunter2)")
+        )
+        .arg(
+            Arg::with_name("bearer")
+                .long("bearer")
+                .value_name("TOKEN")
+                .takes_value(true)
+                .help("Token for HTTP Bearer authentication")
+        )
+        .arg(
+            Arg::with_name("transform_case")
+                .long("transform-case")
+                .value_name("CASE")
+                .takes_value(true)
+                .multiple(true)
+                .use_delimiter(true)
+                .possible_values(&["lower", "upper", "capitalize", "all"])
+                .help(
+                    "Add case variations of each word in the wordlist (ex: --transform-case upper capitalize)",
+                ),
+        )
         .arg(
             Arg::with_name("extensions")
                 .short("x")
@@ -224,11 +348,17 @@ pub fn initialize() -> App<'static, 'static> {
                 .value_name("URL")
                 .takes_value(true)
                 .multiple(true)
-                .use_delimiter(true)
                 .help(
-                    "URL(s) to exclude from recursion/scans",
+                    "URL(s) or path glob(s) to exclude from recursion/scans (ex: --dont-scan http://127.1/cdn '*.png')",
                 ),
         )
+        .arg(
+            Arg::with_name("dont_scan_case_sensitive")
+                .long("dont-scan-case-sensitive")
+                .takes_value(false)
+                .requires("url_denylist")
+                .help("Match --dont-scan glob(s) case-sensitively (default: ignore case)")
+        )
         .arg(
             Arg::with_name("headers")
                 .short("H")
@@ -260,6 +390,16 @@ pub fn initialize() -> App<'static, 'static> {
                 .takes_value(false)
                 .help("Do not scan recursively")
         )
+        .arg(
+            Arg::with_name("dont_recurse_path")
+                .long("dont-recurse-path")
+                .value_name("GLOB")
+                .takes_value(true)
+                .multiple(true)
+                .help(
+                    "Report, but never recurse into, directories whose path matches the given glob (ex: --dont-recurse-path '**/static/')",
+                ),
+        )
         .arg(
             Arg::with_name("add_slash")
                 .short("f")
@@ -284,7 +424,7 @@ pub fn initialize() -> App<'static, 'static> {
                 .multiple(true)
                 .use_delimiter(true)
                 .help(
-                    "Filter out messages of a particular size (ex: -S 5120 -S 4927,1970)",
+                    "Filter out messages of a particular (decompressed) size (ex: -S 5120 -S 4927,1970)",
                 ),
         )
         .arg(
@@ -335,6 +475,50 @@ pub fn initialize() -> App<'static, 'static> {
                     "Filter out status codes (deny list) (ex: -C 200 -C 401)",
                 ),
         )
+        .arg(
+            Arg::with_name("match_status")
+                .long("match-status")
+                .value_name("STATUS_CODE")
+                .takes_value(true)
+                .multiple(true)
+                .use_delimiter(true)
+                .help(
+                    "Only report these status codes (allow list, applied before any filters) (ex: --match-status 200,401)",
+                ),
+        )
+        .arg(
+            Arg::with_name("match_size")
+                .long("match-size")
+                .value_name("SIZE")
+                .takes_value(true)
+                .multiple(true)
+                .use_delimiter(true)
+                .help(
+                    "Only report messages of a particular (decompressed) size (ex: --match-size 4096,512)",
+                ),
+        )
+        .arg(
+            Arg::with_name("match_words")
+                .long("match-words")
+                .value_name("WORDS")
+                .takes_value(true)
+                .multiple(true)
+                .use_delimiter(true)
+                .help(
+                    "Only report messages of a particular word count (ex: --match-words 312)",
+                ),
+        )
+        .arg(
+            Arg::with_name("match_lines")
+                .long("match-lines")
+                .value_name("LINES")
+                .takes_value(true)
+                .multiple(true)
+                .use_delimiter(true)
+                .help(
+                    "Only report messages of a particular line count (ex: --match-lines 20)",
+                ),
+        )
         .arg(
             Arg::with_name("filter_similar")
                 .long("filter-similar-to")
@@ -346,6 +530,17 @@ pub fn initialize() -> App<'static, 'static> {
                     "Filter out pages that are similar to the given page (ex. --filter-similar-to http://site.xyz/soft404)",
                 ),
         )
+        .arg(
+            Arg::with_name("similarity_threshold")
+                .long("similarity-threshold")
+                .value_name("PERCENT")
+                .takes_value(true)
+                .requires("filter_similar")
+                .validator(valid_percentage)
+                .help(
+                    "Percentage of similarity at which a page is filtered by --filter-similar-to (default: 95)",
+                ),
+        )
         .arg(
             Arg::with_name("extract_links")
                 .short("e")
@@ -353,6 +548,20 @@ pub fn initialize() -> App<'static, 'static> {
                 .takes_value(false)
                 .help("Extract links from response body (html, javascript, etc...); make new requests based on findings (default: false)")
         )
+        .arg(
+            Arg::with_name("collect_intel")
+                .long("collect-intel")
+                .takes_value(false)
+                .requires("extract_links")
+                .help("Report email addresses and html comments found in response bodies (requires --extract-links)")
+        )
+        .arg(
+            Arg::with_name("decode_slashes")
+                .long("decode-slashes")
+                .takes_value(false)
+                .requires("extract_links")
+                .help("Treat encoded slashes (%2F) in extracted links as path separators when deduplicating them (requires --extract-links)")
+        )
         .arg(
             Arg::with_name("scan_limit")
                 .short("L")
@@ -377,6 +586,20 @@ pub fn initialize() -> App<'static, 'static> {
                 .conflicts_with("auto_tune")
                 .help("Limit number of requests per second (per directory) (default: 0, i.e. no limit)")
         )
+        .arg(
+            Arg::with_name("max_requests_per_dir")
+                .long("max-requests-per-dir")
+                .value_name("MAX_REQUESTS")
+                .takes_value(true)
+                .help("Limit number of requests sent to each directory (default: 0, i.e. no limit)")
+        )
+        .arg(
+            Arg::with_name("max_body_size")
+                .long("max-body-size")
+                .value_name("BYTES")
+                .takes_value(true)
+                .help("Maximum number of bytes read from each response body (default: 5242880, 0 means no limit)")
+        )
         .arg(
             Arg::with_name("time_limit")
                 .long("time-limit")
@@ -385,9 +608,11 @@ pub fn initialize() -> App<'static, 'static> {
                 .validator(valid_time_spec)
                 .help("Limit total run time of all scans (ex: --time-limit 10m)")
         )
-        .group(ArgGroup::with_name("output_files")
-            .args(&["debug_log", "output"])
-            .multiple(true)
+        .arg(
+            Arg::with_name("dry_run")
+                .long("dry-run")
+                .takes_value(false)
+                .help("Print each url that would be requested to stdout and exit without sending any requests")
         )
         .after_help(r#"NOTE:
     Options that take multiple values are very flexible.  Consider the following ways of specifying
@@ -453,10 +678,33 @@ fn valid_time_spec(time_spec: String) -> Result<(), String> {
     }
 }
 
+/// Validate that a string is a whole number from 1 to 100
+fn valid_percentage(percentage: String) -> Result<(), String> {
+    match percentage.parse::<u32>() {
+        Ok(1..=100) => Ok(()),
+        _ => Err(format!(
+            "Expected a whole number from 1 to 100; received {}",
+            percentage
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    /// valid_percentage should only accept whole numbers from 1 to 100
+    fn validate_valid_percentage_validation() {
+        for accepted in &["1", "50", "100"] {
+            assert!(valid_percentage(accepted.to_string()).is_ok());
+        }
+
+        for rejected in &["0", "101", "-5", "95.5", "95%", ""] {
+            assert!(valid_percentage(rejected.to_string()).is_err());
+        }
+    }
+
     #[test]
     /// initalize parser, expect a clap::App returned
     fn parser_initialize_gives_defaults() {
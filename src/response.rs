@@ -2,13 +2,15 @@ use std::{
     collections::HashMap,
     convert::{TryFrom, TryInto},
     fmt,
+    io::Write,
     str::FromStr,
     sync::Arc,
 };
 
 use anyhow::{Context, Result};
+use flate2::write::{DeflateDecoder, ZlibDecoder};
 use reqwest::{
-    header::{HeaderMap, HeaderName, HeaderValue},
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_ENCODING, CONTENT_TYPE},
     Response, StatusCode, Url,
 };
 use serde::ser::SerializeStruct;
@@ -51,10 +53,310 @@ pub struct FeroxResponse {
     /// Wildcard response status
     wildcard: bool,
 
+    /// whether the body was cut short due to --max-body-size
+    truncated: bool,
+
+    /// The `Url` that was requested, when redirects were followed (-r) to get to `url`
+    original_url: Option<Url>,
+
+    /// The number of redirects followed to get from `original_url` to `url`
+    redirect_hops: usize,
+
+    /// The number of path segments, beyond the first, added by the wordlist entry that was
+    /// requested to get this response, i.e. 2 for `api/v1/users`
+    extra_depth: usize,
+
+    /// The extension (-x) appended to the wordlist entry that was requested to get this response
+    extension: Option<String>,
+
     /// whether the user passed --quiet|--silent on the command line
     pub(crate) output_level: OutputLevel,
 }
 
+/// substrings of `Content-Type`s that always describe text, i.e. `application/json`,
+/// `image/svg+xml`, and `application/javascript`
+const TEXT_CONTENT_TYPE_HINTS: [&str; 5] = ["json", "xml", "javascript", "ecmascript", "html"];
+
+/// prefixes of `Content-Type`s that always describe binary data
+const BINARY_CONTENT_TYPE_PREFIXES: [&str; 18] = [
+    "image/",
+    "audio/",
+    "video/",
+    "font/",
+    "application/font",
+    "application/x-font",
+    "application/zip",
+    "application/gzip",
+    "application/x-gzip",
+    "application/x-tar",
+    "application/x-bzip",
+    "application/x-7z",
+    "application/x-rar",
+    "application/pdf",
+    "application/msword",
+    "application/vnd.",
+    "application/wasm",
+    "application/java-archive",
+];
+
+/// number of characters examined at the start of a body when guessing whether it's binary
+const SNIFF_LENGTH: usize = 1024;
+
+/// guess whether the given (lossily decoded) body is binary data by looking for NUL bytes, as
+/// well as an excess of control characters and invalid utf-8 sequences in its first
+/// `SNIFF_LENGTH` characters
+fn looks_binary(body: &str) -> bool {
+    let mut examined = 0;
+    let mut suspicious = 0;
+
+    for c in body.chars().take(SNIFF_LENGTH) {
+        examined += 1;
+
+        if c == '\0' {
+            return true;
+        }
+
+        if c == char::REPLACEMENT_CHARACTER || (c.is_control() && !c.is_whitespace()) {
+            suspicious += 1;
+        }
+    }
+
+    // more than 10% of the examined characters didn't belong in text
+    examined > 0 && suspicious * 10 > examined
+}
+
+/// keeps the first `limit` bytes written to it (0 means no limit), while counting all of them
+#[derive(Debug, Default)]
+struct LimitedBody {
+    /// the bytes that fit within the limit
+    kept: Vec<u8>,
+
+    /// maximum number of bytes kept, 0 means no limit
+    limit: usize,
+
+    /// total number of bytes written, kept or not
+    len: u64,
+}
+
+/// implementation of LimitedBody
+impl LimitedBody {
+    /// create an empty LimitedBody that keeps at most `limit` bytes (0 means no limit)
+    fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            ..Default::default()
+        }
+    }
+
+    /// keep as much of `bytes` as fits, counting all of it
+    fn extend(&mut self, bytes: &[u8]) {
+        let room = if self.limit == 0 {
+            bytes.len()
+        } else {
+            self.limit.saturating_sub(self.kept.len()).min(bytes.len())
+        };
+
+        self.kept.extend_from_slice(&bytes[..room]);
+        self.len += bytes.len() as u64;
+    }
+
+    /// whether or not more bytes were written than were kept
+    fn is_truncated(&self) -> bool {
+        self.len > self.kept.len() as u64
+    }
+}
+
+/// Write implementation for LimitedBody, needed in order to be the output of a flate2 decoder
+impl Write for LimitedBody {
+    /// keep as much of `buf` as fits, never fails
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.extend(buf);
+        Ok(buf.len())
+    }
+
+    /// nothing buffered, nothing to flush
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// the decoder picked by an Inflater
+enum Decoder {
+    /// zlib-wrapped deflate stream, what the spec says deflate means
+    Zlib(ZlibDecoder<LimitedBody>),
+
+    /// raw deflate stream, sent by some servers anyway
+    Raw(DeflateDecoder<LimitedBody>),
+}
+
+/// decompresses a `Content-Encoding: deflate` body a chunk at a time, keeping at most `max_size`
+/// bytes of the result (0 means no limit) while still counting its full length
+///
+/// the spec says deflate means zlib-wrapped data, but some servers send raw deflate streams, so
+/// the stream's first two bytes decide which decoder is used. A body that turns out not to be
+/// deflate data is used as-is, while a zlib stream that ends early (i.e. the body was cut short)
+/// still produces whatever could be decompressed. Raw deflate streams have no header to tell them
+/// apart from plain data, so they must be complete to be used.
+struct Inflater {
+    /// the body as it was received, used when it can't be decompressed
+    raw: LimitedBody,
+
+    /// start of the body, held until there's enough of it to pick a decoder
+    header: Vec<u8>,
+
+    /// decoder for the body, once picked
+    decoder: Option<Decoder>,
+
+    /// whether or not the body was found not to be deflate data
+    failed: bool,
+}
+
+/// implementation of Inflater
+impl Inflater {
+    /// create an Inflater that keeps at most `max_size` decompressed bytes (0 means no limit)
+    fn new(max_size: usize) -> Self {
+        Self {
+            raw: LimitedBody::new(max_size),
+            header: Vec::new(),
+            decoder: None,
+            failed: false,
+        }
+    }
+
+    /// whether or not the given bytes start with a valid zlib header (RFC 1950)
+    fn is_zlib(header: &[u8]) -> bool {
+        header.len() >= 2
+            && header[0] & 0x0f == 8
+            && (u16::from(header[0]) << 8 | u16::from(header[1])) % 31 == 0
+    }
+
+    /// decompress the next chunk of the body
+    fn write(&mut self, chunk: &[u8]) {
+        self.raw.extend(chunk);
+
+        if self.failed {
+            return;
+        }
+
+        if self.decoder.is_none() {
+            self.header.extend_from_slice(chunk);
+
+            if self.header.len() < 2 {
+                return;
+            }
+
+            let limit = self.raw.limit;
+
+            self.decoder = Some(if Self::is_zlib(&self.header) {
+                Decoder::Zlib(ZlibDecoder::new(LimitedBody::new(limit)))
+            } else {
+                Decoder::Raw(DeflateDecoder::new(LimitedBody::new(limit)))
+            });
+
+            let header = std::mem::take(&mut self.header);
+            return self.decode(&header);
+        }
+
+        self.decode(chunk);
+    }
+
+    /// hand the given bytes to the decoder, giving up on decompression if they're not valid
+    fn decode(&mut self, bytes: &[u8]) {
+        let result = match self.decoder.as_mut() {
+            Some(Decoder::Zlib(decoder)) => decoder.write_all(bytes),
+            Some(Decoder::Raw(decoder)) => decoder.write_all(bytes),
+            None => Ok(()),
+        };
+
+        if let Err(e) = result {
+            log::debug!("Could not decompress deflate encoded body: {}", e);
+            self.failed = true;
+        }
+    }
+
+    /// finish decompressing, returning the decompressed body or, when the body wasn't deflate
+    /// data, the body as it was received
+    fn finish(mut self) -> LimitedBody {
+        // try_finish only fails when the stream ended early; a zlib stream's partial output is
+        // kept, since its header already showed it to be deflate data
+        let decoded = match self.decoder.take() {
+            Some(Decoder::Zlib(mut decoder)) => {
+                decoder.try_finish().unwrap_or_default();
+                std::mem::take(decoder.get_mut())
+            }
+            Some(Decoder::Raw(mut decoder)) => {
+                if decoder.try_finish().is_err() {
+                    self.failed = true;
+                }
+                std::mem::take(decoder.get_mut())
+            }
+            None => LimitedBody::default(),
+        };
+
+        if self.failed || (decoded.len == 0 && self.raw.len > 0) {
+            log::warn!("Could not decompress deflate encoded body, using it as-is");
+            return self.raw;
+        }
+
+        decoded
+    }
+}
+
+/// decompress a complete (or cut short) `Content-Encoding: deflate` body, keeping at most
+/// `max_size` bytes of the result (0 means no limit)
+#[cfg(test)]
+fn inflate(bytes: &[u8], max_size: usize) -> LimitedBody {
+    let mut inflater = Inflater::new(max_size);
+    inflater.write(bytes);
+    inflater.finish()
+}
+
+/// read the given response's body, keeping at most `max_size` bytes of it (0 means no limit);
+/// deflate encoded bodies are decompressed as they're read
+///
+/// when `count_all` is false, reading stops at the limit, the rest of the body is never
+/// downloaded. Otherwise, the rest of the body is read (but not kept) in order to learn its full
+/// length, which is needed when there's no usable Content-Length header.
+async fn read_body_with_limit(
+    mut response: Response,
+    max_size: usize,
+    is_deflated: bool,
+    count_all: bool,
+) -> LimitedBody {
+    let mut body = LimitedBody::new(max_size);
+    let mut inflater = if is_deflated {
+        Some(Inflater::new(max_size))
+    } else {
+        None
+    };
+
+    loop {
+        match response.chunk().await {
+            Ok(Some(chunk)) => {
+                match inflater.as_mut() {
+                    Some(inflater) => inflater.write(&chunk),
+                    None => body.extend(&chunk),
+                }
+
+                if !count_all && body.is_truncated() {
+                    // the length is already known, the rest of the body is never downloaded
+                    break;
+                }
+            }
+            Ok(None) => break,
+            Err(e) => {
+                log::warn!("Could not read body from response: {}", e);
+                break;
+            }
+        }
+    }
+
+    match inflater {
+        Some(inflater) => inflater.finish(),
+        None => body,
+    }
+}
+
 /// implement Default trait for FeroxResponse
 impl Default for FeroxResponse {
     /// return a default reqwest::Url and then normal defaults after that
@@ -68,6 +370,11 @@ impl Default for FeroxResponse {
             word_count: 0,
             headers: Default::default(),
             wildcard: false,
+            truncated: false,
+            original_url: None,
+            redirect_hops: 0,
+            extra_depth: 0,
+            extension: None,
             output_level: Default::default(),
         }
     }
@@ -119,6 +426,80 @@ impl FeroxResponse {
         self.content_length
     }
 
+    /// Whether or not the body of this response was cut short due to --max-body-size
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Get the `Url` that was requested, if redirects were followed (-r) to get to `url`
+    pub fn original_url(&self) -> Option<&Url> {
+        self.original_url.as_ref()
+    }
+
+    /// Get the number of redirects followed to get from `original_url` to `url`
+    pub fn redirect_hops(&self) -> usize {
+        self.redirect_hops
+    }
+
+    /// Get the absolute `Url` pointed to by a 3xx response's `Location` header
+    pub fn location(&self) -> Option<Url> {
+        if !self.status().is_redirection() {
+            return None;
+        }
+
+        let location = self.headers().get("Location")?.to_str().ok()?;
+        self.url().join(location).ok()
+    }
+
+    /// Whether or not this response is part of a redirect; either a 3xx itself or the final
+    /// response of a chain that was followed (-r)
+    pub fn is_redirect(&self) -> bool {
+        self.status().is_redirection() || self.redirect_hops > 0
+    }
+
+    /// Given the `Url` that was requested and the number of redirects the client followed (see
+    /// `client::with_redirect_hops`), record where the response came from when the client
+    /// followed redirects to get here; has no affect when no redirects were followed
+    pub fn set_redirect_origin(&mut self, requested: &Url, hops: usize) {
+        if requested == &self.url {
+            return;
+        }
+
+        // differing urls still means there was at least one hop, even w/o a recorded count
+        self.redirect_hops = hops.max(1);
+        self.original_url = Some(requested.to_owned());
+    }
+
+    /// Given the wordlist entry that was requested to get this response, record how many
+    /// directories it added beyond the first, so that a multi-segment entry like `api/v1/users`
+    /// counts as a single level of recursion; has no affect when redirects were followed, as
+    /// the response's url no longer ends with the word
+    pub fn set_wordlist_entry(&mut self, word: &str) {
+        if self.original_url.is_some() {
+            return;
+        }
+
+        let segments = word.split('/').filter(|s| !s.is_empty()).count();
+        self.extra_depth = segments.saturating_sub(1);
+    }
+
+    /// Get the number of path segments, beyond the first, added by the wordlist entry that was
+    /// requested to get this response
+    pub fn extra_depth(&self) -> usize {
+        self.extra_depth
+    }
+
+    /// Get the extension (-x) that was appended to the wordlist entry to get this response, if any
+    pub fn extension(&self) -> Option<&str> {
+        self.extension.as_deref()
+    }
+
+    /// Record the extension (-x) that was appended to the wordlist entry to get this response;
+    /// unlike the url, it survives redirects being followed (-r)
+    pub fn set_extension(&mut self, extension: Option<&str>) {
+        self.extension = extension.map(String::from);
+    }
+
     /// Set `FeroxResponse`'s `url` attribute, has no affect if an error occurs
     pub fn set_url(&mut self, url: &str) {
         match Url::parse(&url) {
@@ -136,6 +517,12 @@ impl FeroxResponse {
         self.wildcard = is_wildcard;
     }
 
+    /// set `status` attribute
+    #[cfg(test)]
+    pub fn set_status(&mut self, status: StatusCode) {
+        self.status = status;
+    }
+
     /// set `text` attribute; update words/lines/content_length
     #[cfg(test)]
     pub fn set_text(&mut self, text: &str) {
@@ -175,6 +562,42 @@ impl FeroxResponse {
         self.url.query_pairs().count() > 0 || has_extension
     }
 
+    /// Make a reasonable guess at whether the response's body is text, i.e. whether it's worth
+    /// searching for links
+    ///
+    /// the `Content-Type` header is trusted when it names a well-known text or binary type;
+    /// otherwise (missing, `application/octet-stream`, or unknown types) the start of the body
+    /// is examined for NUL bytes and other characters that don't show up in text
+    pub fn is_text(&self) -> bool {
+        let content_type = self
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| {
+                // text/html; charset=UTF-8 -> text/html
+                let essence = value.split(';').next().unwrap_or_default();
+                essence.trim().to_lowercase()
+            })
+            .unwrap_or_default();
+
+        if content_type.starts_with("text/")
+            || TEXT_CONTENT_TYPE_HINTS
+                .iter()
+                .any(|hint| content_type.contains(hint))
+        {
+            return true;
+        }
+
+        if BINARY_CONTENT_TYPE_PREFIXES
+            .iter()
+            .any(|prefix| content_type.starts_with(prefix))
+        {
+            return false;
+        }
+
+        !looks_binary(&self.text)
+    }
+
     /// Returns line count of the response text.
     pub fn line_count(&self) -> usize {
         self.line_count
@@ -186,28 +609,61 @@ impl FeroxResponse {
     }
 
     /// Create a new `FeroxResponse` from the given `Response`
-    pub async fn from(response: Response, read_body: bool, output_level: OutputLevel) -> Self {
+    ///
+    /// at most `max_body_size` bytes of the body are kept in memory (0 means no limit)
+    pub async fn from(
+        response: Response,
+        read_body: bool,
+        max_body_size: usize,
+        output_level: OutputLevel,
+    ) -> Self {
         let url = response.url().clone();
         let status = response.status();
         let headers = response.headers().clone();
-        let content_length = response.content_length().unwrap_or(0);
-
-        let text = if read_body {
-            // .text() consumes the response, must be called last
-            // additionally, --extract-links is currently the only place we use the body of the
-            // response, so we forego the processing if not performing extraction
-            match response.text().await {
-                // await the response's body
-                Ok(text) => text,
-                Err(e) => {
-                    log::warn!("Could not parse body from response: {}", e);
-                    String::new()
-                }
+
+        // gzip and brotli bodies are decompressed by the client, deflate needs handled here
+        let is_deflated = headers
+            .get(CONTENT_ENCODING)
+            .map_or(false, |encoding| encoding == "deflate");
+
+        // Content-Length of a deflated body is its compressed size, ignore it
+        let content_length = response.content_length().filter(|_| !is_deflated);
+
+        let (text, truncated, body_length) = if read_body {
+            // reading the body consumes the response, must be called last; without a
+            // Content-Length, the whole body is read to learn its size, even past the limit
+            let body = read_body_with_limit(
+                response,
+                max_body_size,
+                is_deflated,
+                content_length.is_none(),
+            )
+            .await;
+
+            let truncated = body.is_truncated();
+
+            if truncated {
+                log::warn!(
+                    "Body of {} exceeded --max-body-size of {} bytes and was truncated",
+                    url,
+                    max_body_size
+                );
             }
+
+            (
+                String::from_utf8_lossy(&body.kept).to_string(),
+                truncated,
+                body.len,
+            )
         } else {
-            String::new()
+            (String::new(), false, 0)
         };
 
+        // the client strips the Content-Length header from bodies it decompresses, in which case
+        // the full size of the decompressed body is used (same goes for deflate, from above),
+        // even when only part of it was kept
+        let content_length = content_length.unwrap_or(body_length);
+
         let line_count = text.lines().count();
         let word_count = text.lines().map(|s| s.split_whitespace().count()).sum();
 
@@ -221,9 +677,28 @@ impl FeroxResponse {
             word_count,
             output_level,
             wildcard: false,
+            truncated,
+            original_url: None,
+            redirect_hops: 0,
+            extra_depth: 0,
+            extension: None,
         }
     }
 
+    /// Helper function that determines how many directories deeper than its base url (i.e. the
+    /// url of the scan that found it) the response's Url is
+    ///
+    /// always computed, even when recursion is unlimited (--depth 0), so the depth can be shown;
+    /// directories added by a multi-segment wordlist entry beyond its first aren't counted
+    pub(crate) fn current_depth(&self, base_depth: usize, handles: Arc<Handles>) -> usize {
+        let url = FeroxUrl::from_url(&self.url, handles);
+        let depth = url.depth().unwrap_or_default(); // 0 on error
+
+        depth
+            .saturating_sub(base_depth)
+            .saturating_sub(self.extra_depth)
+    }
+
     /// Helper function that determines if the configured maximum recursion depth has been reached
     ///
     /// Essentially looks at the Url path and determines how many directories are present in the
@@ -241,15 +716,19 @@ impl FeroxResponse {
             handles
         );
 
+        let depth = self.current_depth(base_depth, handles);
+
         if max_depth == 0 {
-            // early return, as 0 means recurse forever; no additional processing needed
-            log::trace!("exit: reached_max_depth -> false");
+            // 0 means recurse forever, the depth is only of interest for display purposes
+            log::trace!(
+                "exit: reached_max_depth -> false (depth {}, unlimited)",
+                depth
+            );
             return false;
         }
-        let url = FeroxUrl::from_url(&self.url, handles);
-        let depth = url.depth().unwrap_or_default(); // 0 on error
 
-        if depth - base_depth >= max_depth {
+        if depth >= max_depth {
+            log::trace!("exit: reached_max_depth -> true");
             return true;
         }
 
@@ -316,6 +795,35 @@ impl FeroxResponse {
         log::trace!("exit: send_report");
         Ok(())
     }
+
+    /// Create a single csv row representing the FeroxResponse (--output-format csv); the columns
+    /// match those in `CSV_HEADER`
+    ///
+    /// ex: https://localhost.com/images,301,179,16,10,GET\n
+    pub fn as_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}\n",
+            csv_field(self.url().as_str()),
+            self.status().as_str(),
+            self.content_length(),
+            self.word_count(),
+            self.line_count(),
+            reqwest::Method::GET, // the only method used when scanning
+        )
+    }
+}
+
+/// header row written at the top of a new --output-format csv file
+pub const CSV_HEADER: &str = "url,status,size,words,lines,method\n";
+
+/// quote the given csv field if it contains a comma, quote, or newline; quotes within the field
+/// are doubled, per RFC 4180
+fn csv_field(value: &str) -> String {
+    if value.contains(|c| matches!(c, ',' | '"' | '\n' | '\r')) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
 }
 
 /// Implement FeroxSerialize for FeroxResponse
@@ -366,13 +874,31 @@ impl FeroxSerialize for FeroxResponse {
             // base message + redirection message (if appropriate)
             message
         } else {
-            // not a wildcard, just create a normal entry
+            // not a wildcard, just create a normal entry; redirects show where they lead, unless
+            // --silent is in use, in which case only the url is shown
+            let url = match (&self.original_url, self.location()) {
+                _ if matches!(self.output_level, OutputLevel::Silent) => self.url().to_string(),
+                (Some(original), _) => format!(
+                    "{} => {} ({} {})",
+                    original,
+                    self.url(),
+                    self.redirect_hops,
+                    if self.redirect_hops == 1 {
+                        "hop"
+                    } else {
+                        "hops"
+                    }
+                ),
+                (None, Some(location)) => format!("{} => {}", self.url(), location),
+                (None, None) => self.url().to_string(),
+            };
+
             utils::create_report_string(
                 self.status.as_str(),
                 &lines,
                 &words,
                 &chars,
-                self.url().as_str(),
+                &url,
                 self.output_level,
             )
         }
@@ -386,10 +912,15 @@ impl FeroxSerialize for FeroxResponse {
     ///    "type":"response",
     ///    "url":"https://localhost.com/images",
     ///    "path":"/images",
+    ///    "extension":"",
     ///    "status":301,
     ///    "content_length":179,
     ///    "line_count":10,
     ///    "word_count":16,
+    ///    "truncated":false,
+    ///    "location":"https://localhost.com/images/",
+    ///    "original_url":"",
+    ///    "redirect_hops":0,
     ///    "headers":{
     ///       "x-content-type-options":"nosniff",
     ///       "strict-transport-security":"max-age=31536000; includeSubDomains",
@@ -423,7 +954,7 @@ impl Serialize for FeroxResponse {
         S: Serializer,
     {
         let mut headers = HashMap::new();
-        let mut state = serializer.serialize_struct("FeroxResponse", 7)?;
+        let mut state = serializer.serialize_struct("FeroxResponse", 14)?;
 
         // need to convert the HeaderMap to a HashMap in order to pass it to the serializer
         for (key, value) in &self.headers {
@@ -435,11 +966,22 @@ impl Serialize for FeroxResponse {
         state.serialize_field("type", "response")?;
         state.serialize_field("url", self.url.as_str())?;
         state.serialize_field("path", self.url.path())?;
+        state.serialize_field("extension", self.extension().unwrap_or(""))?;
         state.serialize_field("wildcard", &self.wildcard)?;
         state.serialize_field("status", &self.status.as_u16())?;
         state.serialize_field("content_length", &self.content_length)?;
         state.serialize_field("line_count", &self.line_count)?;
         state.serialize_field("word_count", &self.word_count)?;
+        state.serialize_field("truncated", &self.truncated)?;
+        state.serialize_field(
+            "location",
+            self.location().as_ref().map_or("", |url| url.as_str()),
+        )?;
+        state.serialize_field(
+            "original_url",
+            self.original_url.as_ref().map_or("", |url| url.as_str()),
+        )?;
+        state.serialize_field("redirect_hops", &self.redirect_hops)?;
         state.serialize_field("headers", &headers)?;
 
         state.end()
@@ -463,6 +1005,11 @@ impl<'de> Deserialize<'de> for FeroxResponse {
             output_level: Default::default(),
             line_count: 0,
             word_count: 0,
+            truncated: false,
+            original_url: None,
+            redirect_hops: 0,
+            extra_depth: 0,
+            extension: None,
         };
 
         let map: HashMap<String, Value> = HashMap::deserialize(deserializer)?;
@@ -521,6 +1068,28 @@ impl<'de> Deserialize<'de> for FeroxResponse {
                         response.wildcard = result;
                     }
                 }
+                "truncated" => {
+                    if let Some(result) = value.as_bool() {
+                        response.truncated = result;
+                    }
+                }
+                "original_url" => {
+                    if let Some(url) = value.as_str() {
+                        // an empty string means no redirects were followed
+                        response.original_url = Url::parse(url).ok();
+                    }
+                }
+                "redirect_hops" => {
+                    if let Some(num) = value.as_u64() {
+                        response.redirect_hops = num.try_into().unwrap_or_default();
+                    }
+                }
+                "extension" => {
+                    if let Some(ext) = value.as_str() {
+                        // an empty string means no extension was appended
+                        response.set_extension(Some(ext).filter(|ext| !ext.is_empty()));
+                    }
+                }
                 _ => {}
             }
         }
@@ -532,6 +1101,38 @@ impl<'de> Deserialize<'de> for FeroxResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client;
+
+    #[test]
+    /// fields w/ commas or quotes should be quoted, quotes within them doubled
+    fn csv_field_quotes_only_when_necessary() {
+        assert_eq!(
+            csv_field("http://localhost/stuff"),
+            "http://localhost/stuff"
+        );
+        assert_eq!(
+            csv_field("http://localhost/a,b?c=\"d\""),
+            "\"http://localhost/a,b?c=\"\"d\"\"\""
+        );
+    }
+
+    #[test]
+    /// as_csv should produce a row w/ the same columns as CSV_HEADER
+    fn ferox_response_as_csv() {
+        let mut response = FeroxResponse::default();
+        response.set_url("http://localhost/stuff,things");
+        response.set_status(StatusCode::OK);
+        response.set_text("one two\nthree");
+
+        assert_eq!(
+            response.as_csv(),
+            "\"http://localhost/stuff,things\",200,13,3,2,GET\n"
+        );
+        assert_eq!(
+            response.as_csv().split(',').count(),
+            CSV_HEADER.split(',').count() + 1 // +1 for the comma in the url
+        );
+    }
 
     #[test]
     /// call reached_max_depth with max depth of zero, which is infinite recursion, expect false
@@ -547,12 +1148,31 @@ mod tests {
             word_count: 0,
             headers: Default::default(),
             wildcard: false,
+            truncated: false,
+            original_url: None,
+            redirect_hops: 0,
+            extra_depth: 0,
+            extension: None,
             output_level: Default::default(),
         };
         let result = response.reached_max_depth(0, 0, handles);
         assert!(!result);
     }
 
+    #[test]
+    /// current_depth should be relative to the base depth, even when max depth is zero
+    fn current_depth_is_relative_to_base_depth() {
+        let handles = Arc::new(Handles::for_testing(None, None).0);
+        let mut response = FeroxResponse::default();
+        response.set_url("http://localhost/one/two/");
+
+        // http://localhost/ has a depth of 1
+        assert_eq!(response.current_depth(0, handles.clone()), 3);
+        assert_eq!(response.current_depth(1, handles.clone()), 2);
+        assert_eq!(response.current_depth(5, handles.clone()), 0);
+        assert!(!response.reached_max_depth(1, 0, handles));
+    }
+
     #[test]
     /// call reached_max_depth with url depth equal to max depth, expect true
     fn reached_max_depth_current_depth_equals_max() {
@@ -568,6 +1188,11 @@ mod tests {
             word_count: 0,
             headers: Default::default(),
             wildcard: false,
+            truncated: false,
+            original_url: None,
+            redirect_hops: 0,
+            extra_depth: 0,
+            extension: None,
             output_level: Default::default(),
         };
 
@@ -575,6 +1200,33 @@ mod tests {
         assert!(result);
     }
 
+    #[test]
+    /// a multi-segment wordlist entry only adds a single level of depth, unless redirects were
+    /// followed to get to the response
+    fn current_depth_counts_multi_segment_word_once() {
+        let handles = Arc::new(Handles::for_testing(None, None).0);
+        let mut response = FeroxResponse {
+            url: Url::parse("http://localhost/api/v1/users/").unwrap(),
+            ..Default::default()
+        };
+
+        assert_eq!(response.current_depth(1, handles.clone()), 3);
+
+        response.set_wordlist_entry("/api/v1/users/");
+        assert_eq!(response.extra_depth(), 2);
+        assert_eq!(response.current_depth(1, handles.clone()), 1);
+        assert!(!response.reached_max_depth(1, 2, handles.clone()));
+
+        let mut redirected = FeroxResponse {
+            url: Url::parse("http://localhost/login/").unwrap(),
+            ..Default::default()
+        };
+        redirected.set_redirect_origin(&Url::parse("http://localhost/api/v1/users").unwrap(), 1);
+        redirected.set_wordlist_entry("api/v1/users");
+        assert_eq!(redirected.extra_depth(), 0);
+        assert_eq!(redirected.current_depth(1, handles), 1);
+    }
+
     #[test]
     /// call reached_max_depth with url dpeth less than max depth, expect false
     fn reached_max_depth_current_depth_less_than_max() {
@@ -589,6 +1241,11 @@ mod tests {
             word_count: 0,
             headers: Default::default(),
             wildcard: false,
+            truncated: false,
+            original_url: None,
+            redirect_hops: 0,
+            extra_depth: 0,
+            extension: None,
             output_level: Default::default(),
         };
 
@@ -610,6 +1267,11 @@ mod tests {
             word_count: 0,
             headers: Default::default(),
             wildcard: false,
+            truncated: false,
+            original_url: None,
+            redirect_hops: 0,
+            extra_depth: 0,
+            extension: None,
             output_level: Default::default(),
         };
 
@@ -631,10 +1293,296 @@ mod tests {
             word_count: 0,
             headers: Default::default(),
             wildcard: false,
+            truncated: false,
+            original_url: None,
+            redirect_hops: 0,
+            extra_depth: 0,
+            extension: None,
             output_level: Default::default(),
         };
 
         let result = response.reached_max_depth(0, 2, handles);
         assert!(result);
     }
+
+    #[test]
+    /// inflate should handle zlib-wrapped and raw deflate streams, falling back to the raw bytes
+    fn inflate_handles_zlib_and_raw_deflate_bodies() {
+        use flate2::{
+            write::{DeflateEncoder, ZlibEncoder},
+            Compression,
+        };
+        use std::io::Write;
+
+        let body = "some text that was compressed";
+
+        let mut zlib = ZlibEncoder::new(Vec::new(), Compression::default());
+        zlib.write_all(body.as_bytes()).unwrap();
+        let zlib = zlib.finish().unwrap();
+        assert_eq!(inflate(&zlib, 0).kept, body.as_bytes());
+
+        let mut raw = DeflateEncoder::new(Vec::new(), Compression::default());
+        raw.write_all(body.as_bytes()).unwrap();
+        assert_eq!(inflate(&raw.finish().unwrap(), 0).kept, body.as_bytes());
+
+        assert_eq!(inflate(body.as_bytes(), 0).kept, body.as_bytes());
+
+        // decompressed output is capped, but its full length is still known
+        let capped = inflate(&zlib, 4);
+        assert_eq!(capped.kept, b"some");
+        assert_eq!(capped.len, body.len() as u64);
+        assert!(capped.is_truncated());
+    }
+
+    #[test]
+    /// a zlib stream that was cut short should still produce what could be decompressed, instead
+    /// of falling back to the compressed bytes
+    fn inflate_keeps_partial_output_of_truncated_zlib_stream() {
+        use flate2::{write::ZlibEncoder, Compression};
+        use std::io::Write;
+
+        let body = (0..2000)
+            .map(|i| format!("line {} of a long body\n", i))
+            .collect::<String>();
+
+        let mut zlib = ZlibEncoder::new(Vec::new(), Compression::default());
+        zlib.write_all(body.as_bytes()).unwrap();
+        let zlib = zlib.finish().unwrap();
+
+        let partial = inflate(&zlib[..zlib.len() / 2], 0);
+
+        assert!(!partial.kept.is_empty());
+        assert!(partial.kept.len() < body.len());
+        assert!(body.as_bytes().starts_with(&partial.kept));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// compressed bodies larger than max_body_size have no Content-Length once decompressed, so
+    /// their full decompressed size should be counted, while only max_body_size bytes are kept
+    async fn from_reports_full_size_of_truncated_compressed_bodies() {
+        use flate2::{
+            write::{GzEncoder, ZlibEncoder},
+            Compression,
+        };
+        use httpmock::{Method::GET, MockServer};
+        use std::io::Write;
+
+        let srv = MockServer::start();
+        let body = "A".repeat(4096);
+
+        let mut gzip = GzEncoder::new(Vec::new(), Compression::default());
+        gzip.write_all(body.as_bytes()).unwrap();
+        let gzip = gzip.finish().unwrap();
+
+        let mut zlib = ZlibEncoder::new(Vec::new(), Compression::default());
+        zlib.write_all(body.as_bytes()).unwrap();
+        let zlib = zlib.finish().unwrap();
+
+        srv.mock(|when, then| {
+            when.method(GET).path("/gzip");
+            then.status(200)
+                .header("Content-Encoding", "gzip")
+                .body(&gzip);
+        });
+
+        srv.mock(|when, then| {
+            when.method(GET).path("/deflate");
+            then.status(200)
+                .header("Content-Encoding", "deflate")
+                .body(&zlib);
+        });
+
+        for path in &["/gzip", "/deflate"] {
+            let response = reqwest::get(&srv.url(*path)).await.unwrap();
+            let truncated = FeroxResponse::from(response, true, 1000, OutputLevel::Default).await;
+
+            assert!(truncated.truncated(), "{}", path);
+            assert_eq!(truncated.text(), &body[..1000], "{}", path);
+            assert_eq!(truncated.content_length(), 4096, "{}", path);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// a body larger than max_body_size should be truncated, with the truncation recorded and
+    /// the reported content length left intact
+    async fn from_truncates_body_larger_than_max_body_size() {
+        use httpmock::{Method::GET, MockServer};
+
+        let srv = MockServer::start();
+        let body = "A".repeat(4096);
+
+        let mock = srv.mock(|when, then| {
+            when.method(GET).path("/backup.zip");
+            then.status(200).body(&body);
+        });
+
+        let url = Url::parse(&srv.url("/backup.zip")).unwrap();
+
+        let response = reqwest::get(url.clone()).await.unwrap();
+        let truncated = FeroxResponse::from(response, true, 1000, OutputLevel::Default).await;
+
+        assert!(truncated.truncated());
+        assert_eq!(truncated.text().len(), 1000);
+        assert_eq!(truncated.content_length(), 4096);
+        assert!(truncated.is_file());
+        assert!(truncated.as_json().unwrap().contains(r#""truncated":true"#));
+
+        let response = reqwest::get(url).await.unwrap();
+        let untouched = FeroxResponse::from(response, true, 0, OutputLevel::Default).await;
+
+        assert!(!untouched.truncated());
+        assert_eq!(untouched.text(), body);
+        assert_eq!(mock.hits(), 2);
+    }
+
+    #[test]
+    /// a 3xx should show where its Location header points, both as text and json
+    fn as_str_and_as_json_show_location_of_redirect() {
+        let mut response = FeroxResponse::default();
+        response.set_url("http://localhost/admin");
+        response.set_status(StatusCode::MOVED_PERMANENTLY);
+        response
+            .headers
+            .insert("Location", HeaderValue::from_static("/admin/"));
+
+        assert_eq!(
+            response.location(),
+            Some(Url::parse("http://localhost/admin/").unwrap())
+        );
+        assert!(response.is_redirect());
+        assert!(response
+            .as_str()
+            .contains("http://localhost/admin => http://localhost/admin/"));
+        assert!(response.as_json().unwrap().contains(
+            r#""location":"http://localhost/admin/","original_url":"","redirect_hops":0"#
+        ));
+
+        response.output_level = OutputLevel::Silent;
+        assert_eq!(response.as_str(), "http://localhost/admin\n");
+    }
+
+    #[test]
+    /// the extension that was requested should be part of the json and survive a round trip
+    fn extension_is_serialized_and_deserialized() {
+        let mut response = FeroxResponse::default();
+        response.set_url("http://localhost/index.php");
+        response.set_extension(Some("php"));
+
+        let json = response.as_json().unwrap();
+        assert!(json.contains(r#""path":"/index.php","extension":"php""#));
+
+        let deserialized: FeroxResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.extension(), Some("php"));
+
+        response.set_extension(None);
+        let json = response.as_json().unwrap();
+        assert!(json.contains(r#""extension":"""#));
+
+        let deserialized: FeroxResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.extension(), None);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// following a redirect chain should record where it started and how many hops it took
+    async fn set_redirect_origin_records_followed_chain() {
+        use httpmock::{Method::GET, MockServer};
+
+        let srv = MockServer::start();
+
+        let first = srv.mock(|when, then| {
+            when.method(GET).path("/old");
+            then.status(301).header("Location", &srv.url("/older"));
+        });
+
+        let second = srv.mock(|when, then| {
+            when.method(GET).path("/older");
+            then.status(302).header("Location", &srv.url("/new"));
+        });
+
+        let last = srv.mock(|when, then| {
+            when.method(GET).path("/new");
+            then.status(200).body("made it");
+        });
+
+        let client = client::initialize(
+            client::Timeouts::from(5),
+            "feroxbuster",
+            true,
+            client::TlsVerification::Full,
+            "auto",
+            &HashMap::new(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let url = Url::parse(&srv.url("/old")).unwrap();
+        let (response, hops) = client::with_redirect_hops(client.get(url.clone()).send()).await;
+
+        let mut followed =
+            FeroxResponse::from(response.unwrap(), true, 0, OutputLevel::Default).await;
+        followed.set_redirect_origin(&url, hops);
+
+        assert_eq!(followed.url().as_str(), srv.url("/new"));
+        assert_eq!(followed.original_url(), Some(&url));
+        assert_eq!(followed.redirect_hops(), 2);
+        assert!(followed.is_redirect());
+        assert!(followed.as_str().contains(&format!(
+            "{} => {} (2 hops)",
+            srv.url("/old"),
+            srv.url("/new")
+        )));
+        assert_eq!(first.hits(), 1);
+        assert_eq!(second.hits(), 1);
+        assert_eq!(last.hits(), 1);
+
+        // each request gets its own count, and nothing is recorded outside of with_redirect_hops
+        let (response, hops) = client::with_redirect_hops(client.get(srv.url("/new")).send()).await;
+        assert_eq!(response.unwrap().status(), 200);
+        assert_eq!(hops, 0);
+
+        let response = client.get(url.clone()).send().await.unwrap();
+        assert_eq!(response.url().as_str(), srv.url("/new"));
+    }
+
+    #[test]
+    /// well-known content types are trusted, everything else is decided by the body
+    fn is_text_uses_content_type_then_sniffs_body() {
+        let with_type = |content_type: &str, body: &str| {
+            let mut response = FeroxResponse::default();
+            response.set_text(body);
+
+            if !content_type.is_empty() {
+                response
+                    .headers
+                    .insert(CONTENT_TYPE, HeaderValue::from_str(content_type).unwrap());
+            }
+
+            response.is_text()
+        };
+
+        assert!(with_type("text/html; charset=UTF-8", "<a href=\"/admin\">"));
+        assert!(with_type("application/json", "{}"));
+        assert!(with_type("application/javascript", "var x;"));
+        assert!(with_type("image/svg+xml", "<svg></svg>"));
+        assert!(with_type("text/css", "body {}"));
+
+        // the header wins, even if the body looks like text
+        assert!(!with_type(
+            "image/png",
+            "'/homepage/assets/img/icons/handshake.svg'"
+        ));
+        assert!(!with_type("font/woff2", "wOF2"));
+        assert!(!with_type("application/zip", "PK"));
+
+        // missing and generic headers fall back to the body
+        assert!(with_type("", "<html><a href=\"/admin\"></a></html>"));
+        assert!(with_type("application/octet-stream", "plain old text\n"));
+        assert!(!with_type("", "\u{89}PNG\r\n\u{1a}\n\0\0\0\rIHDR"));
+        assert!(!with_type(
+            "application/octet-stream",
+            &"\u{fffd}\u{fffd}ab\u{1}\u{2}".repeat(20)
+        ));
+        assert!(with_type("", ""));
+    }
 }
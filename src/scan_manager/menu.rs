@@ -1,8 +1,39 @@
-use crate::progress::PROGRESS_BAR;
+use crate::{
+    filters::{LinesFilter, RegexFilter, SizeFilter, StatusCodeFilter, WordsFilter},
+    progress::PROGRESS_BAR,
+    traits::FeroxFilter,
+};
 use console::{measure_text_width, pad_str, style, Alignment, Term};
 use indicatif::ProgressDrawTarget;
+use regex::Regex;
+use std::str::FromStr;
 
-/// Interactive scan cancellation menu
+/// A single line of user input to the interactive menu
+#[derive(Debug)]
+pub(super) enum MenuCmd {
+    /// cancel the scans at the given indexes, skipping confirmation when true
+    Cancel(Vec<usize>, bool),
+
+    /// append the given extensions to words that haven't been requested yet (-x|--extensions)
+    AddExtensions(Vec<String>),
+
+    /// filter out responses that haven't been reported yet (--filter-*)
+    AddFilters(Vec<Box<dyn FeroxFilter>>),
+
+    /// replace the requests per second limit (--rate-limit)
+    SetRateLimit(usize),
+
+    /// print everything that's been found so far
+    Findings,
+
+    /// leave the menu and resume scanning
+    Resume,
+
+    /// input that couldn't be understood, along with the reason why
+    Invalid(String),
+}
+
+/// Interactive scan management menu
 #[derive(Debug)]
 pub(super) struct Menu {
     /// character to use as visual separator of lines
@@ -40,7 +71,7 @@ impl Menu {
         let name = format!(
             "{} {} {}",
             "💀",
-            style("Scan Cancel Menu").bright().yellow(),
+            style("Scan Management Menu").bright().yellow(),
             "💀"
         );
 
@@ -51,7 +82,43 @@ impl Menu {
             style("ex").cyan(),
         );
 
-        let longest = measure_text_width(&instructions).max(measure_text_width(&name));
+        let commands = [
+            format!(
+                "{} php,bak   add extensions to words not yet requested",
+                style("extensions").cyan()
+            ),
+            format!(
+                "{} 404,500   {} 1234   {} 12   {} 3   {} ^Not Found$",
+                style("filter-status").cyan(),
+                style("filter-size").cyan(),
+                style("filter-words").cyan(),
+                style("filter-lines").cyan(),
+                style("filter-regex").cyan(),
+            ),
+            format!(
+                "{} 100   change requests per second (0 for no limit)",
+                style("rate-limit").cyan()
+            ),
+            format!(
+                "{}   show what's been found so far",
+                style("findings").cyan()
+            ),
+            format!(
+                "Press {} on an empty line to {} scanning",
+                style("Enter").yellow(),
+                style("resume").green()
+            ),
+        ];
+
+        let longest = commands
+            .iter()
+            .map(|command| measure_text_width(command))
+            .chain(vec![
+                measure_text_width(&instructions),
+                measure_text_width(&name),
+            ])
+            .max()
+            .unwrap_or_default();
 
         let border = separator.repeat(longest);
 
@@ -59,7 +126,15 @@ impl Menu {
         let padded_force = pad_str(&force_msg, longest, Alignment::Center, None);
 
         let header = format!("{}\n{}\n{}", border, padded_name, border);
-        let footer = format!("{}\n{}\n{}\n{}", border, instructions, padded_force, border);
+        let footer = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}",
+            border,
+            instructions,
+            padded_force,
+            border,
+            commands.join("\n"),
+            border
+        );
 
         Self {
             separator,
@@ -161,15 +236,124 @@ impl Menu {
         nums
     }
 
-    /// get comma-separated list of scan indexes from the user
-    pub(super) fn get_scans_from_user(&self) -> Option<(Vec<usize>, bool)> {
-        if let Ok(line) = self.term.read_line() {
+    /// split a list of values separated by commas and/or spaces, parsing each one as a `T`
+    fn split_to_values<T: FromStr>(&self, values: &str) -> Result<Vec<T>, String> {
+        let parsed = values
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|value| !value.is_empty())
+            .map(|value| {
+                value
+                    .parse::<T>()
+                    .map_err(|_| format!("Found invalid value: {}", value))
+            })
+            .collect::<Result<Vec<T>, String>>()?;
+
+        if parsed.is_empty() {
+            return Err(String::from("Expected at least one value"));
+        }
+
+        Ok(parsed)
+    }
+
+    /// parse a single line of user input into a MenuCmd
+    pub(super) fn parse_command(&self, line: &str) -> MenuCmd {
+        let line = line.trim();
+
+        if line.is_empty() || line == "resume" {
+            return MenuCmd::Resume;
+        }
+
+        if line.starts_with(|c: char| c.is_ascii_digit()) {
+            // list of indexes/ranges, same as it's always been
             let force = line.contains("-f");
             let line = line.replace("-f", "");
-            Some((self.split_to_nums(&line), force))
-        } else {
-            None
+            return MenuCmd::Cancel(self.split_to_nums(&line), force);
         }
+
+        let (command, args) = match line.find(char::is_whitespace) {
+            Some(idx) => (&line[..idx], line[idx..].trim()),
+            None => (line, ""),
+        };
+
+        let parsed = match command {
+            "findings" => Ok(MenuCmd::Findings),
+            "extensions" => self.split_to_values::<String>(args).map(|extensions| {
+                MenuCmd::AddExtensions(
+                    extensions
+                        .into_iter()
+                        .map(|ext| ext.trim_start_matches('.').to_string())
+                        .filter(|ext| !ext.is_empty())
+                        .collect(),
+                )
+            }),
+            "rate-limit" => args
+                .parse::<usize>()
+                .map(MenuCmd::SetRateLimit)
+                .map_err(|_| format!("Found invalid rate limit: {:?}", args)),
+            "filter-status" => self.split_to_values::<u16>(args).map(|codes| {
+                MenuCmd::AddFilters(
+                    codes
+                        .into_iter()
+                        .map(|filter_code| {
+                            Box::new(StatusCodeFilter { filter_code }) as Box<dyn FeroxFilter>
+                        })
+                        .collect(),
+                )
+            }),
+            "filter-size" => self.split_to_values::<u64>(args).map(|sizes| {
+                MenuCmd::AddFilters(
+                    sizes
+                        .into_iter()
+                        .map(|content_length| {
+                            Box::new(SizeFilter { content_length }) as Box<dyn FeroxFilter>
+                        })
+                        .collect(),
+                )
+            }),
+            "filter-words" => self.split_to_values::<usize>(args).map(|counts| {
+                MenuCmd::AddFilters(
+                    counts
+                        .into_iter()
+                        .map(|word_count| {
+                            Box::new(WordsFilter { word_count }) as Box<dyn FeroxFilter>
+                        })
+                        .collect(),
+                )
+            }),
+            "filter-lines" => self.split_to_values::<usize>(args).map(|counts| {
+                MenuCmd::AddFilters(
+                    counts
+                        .into_iter()
+                        .map(|line_count| {
+                            Box::new(LinesFilter { line_count }) as Box<dyn FeroxFilter>
+                        })
+                        .collect(),
+                )
+            }),
+            // an empty regex would filter out everything
+            "filter-regex" if args.is_empty() => Err(String::from("Expected a regex")),
+            // the rest of the line is the regex, commas/spaces included
+            "filter-regex" => Regex::new(args)
+                .map(|compiled| {
+                    MenuCmd::AddFilters(vec![Box::new(RegexFilter {
+                        raw_string: args.to_string(),
+                        compiled,
+                    })])
+                })
+                .map_err(|e| format!("Found invalid regex: {}", e)),
+            _ => Err(format!("Unknown command: {}", command)),
+        };
+
+        parsed.unwrap_or_else(MenuCmd::Invalid)
+    }
+
+    /// get a single command from the user
+    pub(super) fn get_command_from_user(&self) -> Option<MenuCmd> {
+        self.term.write_str("> ").unwrap_or_default();
+        self.term
+            .read_line()
+            .ok()
+            .map(|line| self.parse_command(&line))
     }
 
     /// Given a url, confirm with user that we should cancel
@@ -8,10 +8,10 @@ mod state;
 #[cfg(test)]
 mod tests;
 
-pub(self) use menu::Menu;
+pub(self) use menu::{Menu, MenuCmd};
 pub use order::ScanOrder;
 pub use response_container::FeroxResponses;
 pub use scan::{FeroxScan, ScanStatus, ScanType};
-pub use scan_container::{FeroxScans, PAUSE_SCAN};
+pub use scan_container::{FeroxScans, InFlight, PAUSE_SCAN};
 pub use state::FeroxState;
 pub use utils::{resume_scan, start_max_time_thread};
@@ -3,6 +3,7 @@ use crate::{
     config::OutputLevel,
     progress::{add_bar, BarType},
     scanner::PolicyTrigger,
+    url::FeroxUrl,
 };
 use anyhow::Result;
 use console::style;
@@ -32,6 +33,9 @@ pub struct FeroxScan {
     /// The URL that to be scanned
     pub(super) url: String,
 
+    /// `url` as normalized by `FeroxUrl::normalize_url`, used for deduplication
+    pub(super) normalized_url: String,
+
     /// The type of scan
     pub(super) scan_type: ScanType,
 
@@ -62,6 +66,13 @@ pub struct FeroxScan {
     /// tracker for total number of errors encountered by the FeroxScan instance
     pub(super) errors: AtomicUsize,
 
+    /// tracker for total number of requests issued by the FeroxScan instance's scanner
+    pub(super) requests_issued: AtomicUsize,
+
+    /// tracker for total number of requests the FeroxScan instance's scanner didn't make because
+    /// of the per-directory limit
+    pub(super) requests_skipped: AtomicUsize,
+
     /// tracker for the time at which this scan was started
     pub(super) start_time: Instant,
 }
@@ -79,10 +90,13 @@ impl Default for FeroxScan {
             num_requests: 0,
             scan_order: ScanOrder::Latest,
             url: String::new(),
+            normalized_url: String::new(),
             progress_bar: Mutex::new(None),
             scan_type: ScanType::File,
             output_level: Default::default(),
             errors: Default::default(),
+            requests_issued: Default::default(),
+            requests_skipped: Default::default(),
             status_429s: Default::default(),
             status_403s: Default::default(),
             start_time: Instant::now(),
@@ -191,6 +205,7 @@ impl FeroxScan {
     ) -> Arc<Self> {
         Arc::new(Self {
             url: url.to_string(),
+            normalized_url: Self::normalize(url, scan_type),
             scan_type,
             scan_order,
             num_requests,
@@ -200,6 +215,21 @@ impl FeroxScan {
         })
     }
 
+    /// normalize the given url the way a scan of the given type would be (see
+    /// `FeroxUrl::normalize_url`)
+    pub(super) fn normalize(url: &str, scan_type: ScanType) -> String {
+        FeroxUrl::normalize_url(url, matches!(scan_type, ScanType::Directory))
+    }
+
+    /// determine whether or not the given url refers to the same file/directory as this scan,
+    /// given the url normalized as a file and as a directory
+    pub(super) fn is_same_url(&self, as_file: &str, as_directory: &str) -> bool {
+        match self.scan_type {
+            ScanType::File => self.normalized_url == as_file,
+            ScanType::Directory => self.normalized_url == as_directory,
+        }
+    }
+
     /// Mark the scan as complete and stop the scan's progress bar
     pub fn finish(&self) -> Result<()> {
         self.set_status(ScanStatus::Complete)?;
@@ -258,6 +288,36 @@ impl FeroxScan {
         self.errors.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// increment the number of requests issued by this scan and determine whether or not the
+    /// new request fits within the given per-directory limit (a limit of 0 means no limit); a
+    /// request that doesn't fit is counted as skipped
+    pub(crate) fn try_add_request(&self, limit: usize) -> bool {
+        let issued = self.requests_issued.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if limit == 0 || issued <= limit {
+            return true;
+        }
+
+        self.add_skipped_request();
+        false
+    }
+
+    /// increment the number of requests that weren't made due to the per-directory limit
+    pub(crate) fn add_skipped_request(&self) {
+        self.requests_skipped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// number of requests that weren't made due to the per-directory limit
+    pub fn skipped_requests(&self) -> usize {
+        self.requests_skipped.load(Ordering::Relaxed)
+    }
+
+    /// determine whether or not this scan has issued its maximum number of requests (a limit of 0
+    /// means no limit)
+    pub fn reached_max_requests(&self, limit: usize) -> bool {
+        limit > 0 && self.requests_issued.load(Ordering::Relaxed) >= limit
+    }
+
     /// simple wrapper to call the appropriate getter based on the given PolicyTrigger
     pub fn num_errors(&self, trigger: PolicyTrigger) -> usize {
         match trigger {
@@ -396,12 +456,14 @@ impl<'de> Deserialize<'de> for FeroxScan {
             }
         }
 
+        scan.normalized_url = Self::normalize(&scan.url, scan.scan_type);
+
         Ok(scan)
     }
 }
 
 /// Simple enum used to flag a `FeroxScan` as likely a directory or file
-#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
 pub enum ScanType {
     /// Just a file being requested
     File,
@@ -474,12 +536,43 @@ mod tests {
         assert_eq!(scan.num_errors(PolicyTrigger::Status429), 3);
     }
 
+    #[test]
+    /// ensure that try_add_request and reached_max_requests respect the given limit, that only
+    /// requests past the limit are counted as skipped, and that a limit of 0 is treated as no
+    /// limit
+    fn try_add_request_respects_limit() {
+        let scan = FeroxScan::new(
+            "http://localhost",
+            ScanType::Directory,
+            ScanOrder::Latest,
+            1000,
+            OutputLevel::Default,
+            None,
+        );
+
+        assert!(!scan.reached_max_requests(2));
+        assert!(scan.try_add_request(2));
+        assert!(scan.try_add_request(2));
+
+        // exactly at the limit, nothing has been skipped yet
+        assert!(scan.reached_max_requests(2));
+        assert_eq!(scan.skipped_requests(), 0);
+
+        assert!(!scan.try_add_request(2));
+        assert_eq!(scan.skipped_requests(), 1);
+
+        assert!(!scan.reached_max_requests(0));
+        assert!(scan.try_add_request(0));
+        assert_eq!(scan.skipped_requests(), 1);
+    }
+
     #[test]
     /// ensure that requests_per_second returns the correct values
     fn requests_per_second_returns_correct_values() {
         let scan = FeroxScan {
             id: "".to_string(),
             url: "".to_string(),
+            normalized_url: "".to_string(),
             scan_type: ScanType::Directory,
             scan_order: ScanOrder::Initial,
             num_requests: 0,
@@ -490,6 +583,8 @@ mod tests {
             status_403s: Default::default(),
             status_429s: Default::default(),
             errors: Default::default(),
+            requests_issued: Default::default(),
+            requests_skipped: Default::default(),
             start_time: Instant::now(),
         };
 
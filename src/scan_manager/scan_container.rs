@@ -2,9 +2,14 @@ use super::scan::ScanType;
 use super::*;
 use crate::{
     config::OutputLevel,
+    event_handlers::{
+        Command::{AddExtensions, AddFilter, AddToUsizeField, SetRateLimit},
+        Handles,
+    },
     progress::PROGRESS_PRINTER,
     progress::{add_bar, BarType},
     scanner::RESPONSES,
+    statistics::StatField::{ExpectedPerScan, TotalExpected},
     traits::FeroxSerialize,
     SLEEP_DURATION,
 };
@@ -31,6 +36,32 @@ static INTERACTIVE_BARRIER: AtomicUsize = AtomicUsize::new(0);
 /// Atomic boolean flag, used to determine whether or not a scan should pause or resume
 pub static PAUSE_SCAN: AtomicBool = AtomicBool::new(false);
 
+/// Number of words currently being requested, across all scans; the interactive menu waits for
+/// this to reach 0 before it's shown
+static REQUESTS_IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+/// Marks a word as being requested for as long as it's alive, so that pausing a scan can wait for
+/// in-flight requests to drain
+#[derive(Debug)]
+pub struct InFlight;
+
+/// implementation of InFlight
+impl InFlight {
+    /// mark a word as being requested
+    pub fn start() -> Self {
+        REQUESTS_IN_FLIGHT.fetch_add(1, Ordering::AcqRel);
+        Self
+    }
+}
+
+/// Drop implementation for InFlight
+impl Drop for InFlight {
+    /// the word's requests are done (or it's waiting on a pause and hasn't been requested yet)
+    fn drop(&mut self) {
+        REQUESTS_IN_FLIGHT.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
 /// Container around a locked hashset of `FeroxScan`s, adds wrappers for insertion and searching
 #[derive(Debug, Default)]
 pub struct FeroxScans {
@@ -88,7 +119,7 @@ impl FeroxScans {
     pub fn insert(&self, scan: Arc<FeroxScan>) -> bool {
         // If the container did contain the scan, set sentry to false
         // If the container did not contain the scan, set sentry to true
-        let sentry = !self.contains(&scan.url);
+        let sentry = !self.contains_scan(&scan);
 
         if sentry {
             // can't update the internal container while the scan itself is locked, so first
@@ -139,22 +170,35 @@ impl FeroxScans {
 
     /// Simple check for whether or not a FeroxScan is contained within the inner container based
     /// on the given URL
+    ///
+    /// urls are compared after normalization, so `http://localhost/a` and `http://localhost/a/`
+    /// are considered the same when they belong to a directory scan, but not a file scan
     pub fn contains(&self, url: &str) -> bool {
+        self.get_scan_by_url(url).is_some()
+    }
+
+    /// Check whether or not a scan of the same type as the given scan, and for the same
+    /// (normalized) url, is already in the inner container
+    ///
+    /// unlike `contains`, a file scan of `/a` and a directory scan of `/a/` aren't duplicates
+    fn contains_scan(&self, scan: &FeroxScan) -> bool {
         if let Ok(scans) = self.scans.read() {
-            for scan in scans.iter() {
-                if scan.url == url {
-                    return true;
-                }
-            }
+            return scans.iter().any(|known| {
+                known.scan_type == scan.scan_type && known.normalized_url == scan.normalized_url
+            });
         }
         false
     }
 
-    /// Find and return a `FeroxScan` based on the given URL
+    /// Find and return a `FeroxScan` based on the given URL (normalized the same way as in
+    /// `contains`)
     pub fn get_scan_by_url(&self, url: &str) -> Option<Arc<FeroxScan>> {
+        let as_file = FeroxScan::normalize(url, ScanType::File);
+        let as_directory = FeroxScan::normalize(url, ScanType::Directory);
+
         if let Ok(guard) = self.scans.read() {
             for scan in guard.iter() {
-                if scan.url == url {
+                if scan.is_same_url(&as_file, &as_directory) {
                     return Some(scan.clone());
                 }
             }
@@ -197,6 +241,41 @@ impl FeroxScans {
         log::trace!("enter: get_sub_paths_from_path -> None");
         None
     }
+
+    /// find the initial (i.e. user-provided target) directory scan that the given url lives
+    /// beneath; the deepest target wins when more than one matches
+    pub fn get_initial_scan_by_url(&self, url: &str) -> Option<Arc<FeroxScan>> {
+        log::trace!("enter: get_initial_scan_by_url({})", url);
+
+        let mut found: Option<Arc<FeroxScan>> = None;
+
+        if let Ok(guard) = self.scans.read() {
+            for scan in guard.iter() {
+                if !matches!(scan.scan_order, ScanOrder::Initial)
+                    || !matches!(scan.scan_type, ScanType::Directory)
+                {
+                    continue;
+                }
+
+                let target = scan.url.trim_end_matches('/');
+
+                if url != target && !url.starts_with(&format!("{}/", target)) {
+                    continue;
+                }
+
+                if found
+                    .as_ref()
+                    .map_or(true, |f| f.url.len() < scan.url.len())
+                {
+                    found = Some(scan.clone());
+                }
+            }
+        }
+
+        log::trace!("exit: get_initial_scan_by_url -> {:?}", found);
+        found
+    }
+
     /// add one to either 403 or 429 tracker in the scan related to the given url
     pub fn increment_status_code(&self, url: &str, code: StatusCode) {
         if let Some(scan) = self.get_base_scan_by_url(url) {
@@ -299,8 +378,110 @@ impl FeroxScans {
         num_cancelled
     }
 
-    /// CLI menu that allows for interactive cancellation of recursed-into directories
-    async fn interactive_menu(&self) -> usize {
+    /// make room in the progress bars of active scans for `added` more requests per word that's
+    /// yet to be requested, given the number of requests that were being made per word
+    ///
+    /// scans created from here on out get the larger bar as well; returns the number of requests
+    /// that were added across all active scans
+    pub(super) fn grow_bars(&self, per_word: u64, added: u64) -> u64 {
+        let per_word = per_word.max(1);
+        let mut total_added = 0;
+
+        if let Ok(scans) = self.scans.read() {
+            for scan in scans.iter() {
+                if !scan.is_active() {
+                    continue;
+                }
+
+                let bar = scan.progress_bar();
+                let remaining_words = bar.length().saturating_sub(bar.position()) / per_word;
+
+                bar.inc_length(remaining_words * added);
+                total_added += remaining_words * added;
+            }
+        }
+
+        if let Ok(mut bar_length) = self.bar_length.lock() {
+            *bar_length = *bar_length / per_word * (per_word + added);
+        }
+
+        total_added
+    }
+
+    /// add any extensions that aren't already in use to the scope, and account for the extra
+    /// requests in the progress bars and statistics
+    async fn add_extensions(&self, extensions: Vec<String>, handles: &Handles) -> Result<()> {
+        let current = handles.scope.data.extensions();
+
+        let new: Vec<String> = extensions
+            .into_iter()
+            .filter(|extension| !current.contains(extension))
+            .collect();
+
+        if new.is_empty() {
+            self.menu.println("Those extensions are already in use");
+            return Ok(());
+        }
+
+        let per_word = current.len() as u64 + 1;
+        let added = new.len() as u64;
+        let per_scan = self.bar_length.lock().map_or(0, |length| *length) / per_word * added;
+
+        self.menu
+            .println(&format!("Adding extensions: {}", new.join(", ")));
+
+        handles.scope.send(AddExtensions(new))?;
+        handles.scope.sync().await?;
+
+        let total_added = self.grow_bars(per_word, added);
+
+        handles
+            .stats
+            .send(AddToUsizeField(ExpectedPerScan, per_scan as usize))?;
+        handles
+            .stats
+            .send(AddToUsizeField(TotalExpected, total_added as usize))?;
+
+        Ok(())
+    }
+
+    /// send a single command entered in the interactive menu to the handler responsible for it;
+    /// returns the number of requests that were cancelled
+    async fn run_command(&self, command: MenuCmd, handles: &Handles) -> Result<usize> {
+        match command {
+            MenuCmd::Cancel(indexes, force) => return Ok(self.cancel_scans(indexes, force).await),
+            MenuCmd::AddExtensions(extensions) => self.add_extensions(extensions, handles).await?,
+            MenuCmd::AddFilters(filters) => {
+                for filter in filters {
+                    self.menu.println(&format!("Adding filter: {:?}", filter));
+                    handles.filters.send(AddFilter(filter))?;
+                }
+                handles.filters.sync().await?;
+            }
+            MenuCmd::SetRateLimit(limit) => {
+                self.menu
+                    .println(&format!("Setting rate limit to {}/s (0 = no limit)", limit));
+                handles.scope.send(SetRateLimit(limit))?;
+                handles.scope.sync().await?;
+            }
+            MenuCmd::Findings => {
+                if let Ok(responses) = RESPONSES.responses.read() {
+                    for response in responses.iter() {
+                        self.menu.println(response.as_str().trim_end());
+                    }
+                }
+            }
+            MenuCmd::Invalid(reason) => self.menu.println(&reason),
+            MenuCmd::Resume => {}
+        }
+
+        Ok(0)
+    }
+
+    /// CLI menu that allows for interactive cancellation of recursed-into directories as well as
+    /// changes to the extensions, filters, and rate limit applied to requests that haven't been
+    /// made yet
+    async fn interactive_menu(&self, handles: &Handles) -> usize {
         self.menu.hide_progress_bars();
         self.menu.clear_screen();
         self.menu.print_header();
@@ -309,9 +490,16 @@ impl FeroxScans {
 
         let mut num_cancelled = 0_usize;
 
-        if let Some((input, force)) = self.menu.get_scans_from_user() {
-            num_cancelled += self.cancel_scans(input, force).await;
-        };
+        while let Some(command) = self.menu.get_command_from_user() {
+            if matches!(command, MenuCmd::Resume) {
+                break;
+            }
+
+            match self.run_command(command, handles).await {
+                Ok(cancelled) => num_cancelled += cancelled,
+                Err(e) => self.menu.println(&format!("Could not apply change: {}", e)),
+            }
+        }
 
         self.menu.clear_screen();
         self.menu.show_progress_bars();
@@ -319,6 +507,31 @@ impl FeroxScans {
         num_cancelled
     }
 
+    /// wait for words that were already being requested when the scan was paused to finish, so
+    /// their results don't end up in the middle of the menu
+    async fn drain_requests(&self) {
+        let mut interval = time::interval(time::Duration::from_millis(SLEEP_DURATION / 5));
+        let mut notified = false;
+
+        loop {
+            interval.tick().await;
+
+            let in_flight = REQUESTS_IN_FLIGHT.load(Ordering::Acquire);
+
+            if in_flight == 0 {
+                break;
+            }
+
+            if !notified {
+                PROGRESS_PRINTER.println(format!(
+                    "Pausing; waiting for {} in-flight requests to finish...",
+                    in_flight
+                ));
+                notified = true;
+            }
+        }
+    }
+
     /// prints all known responses that the scanner has already seen
     pub fn print_known_responses(&self) {
         if let Ok(mut responses) = RESPONSES.responses.write() {
@@ -364,7 +577,10 @@ impl FeroxScans {
     ///
     /// When the value stored in `PAUSE_SCAN` becomes `false`, the function returns, exiting the busy
     /// loop
-    pub async fn pause(&self, get_user_input: bool) -> usize {
+    ///
+    /// When `handles` are given, the first thread to pause waits for in-flight requests to drain
+    /// and then drops into the interactive menu, where changes are sent to the running handlers
+    pub async fn pause(&self, handles: Option<Arc<Handles>>) -> usize {
         // function uses tokio::time, not std
 
         // local testing showed a pretty slow increase (less than linear) in CPU usage as # of
@@ -375,8 +591,9 @@ impl FeroxScans {
         if INTERACTIVE_BARRIER.load(Ordering::Relaxed) == 0 {
             INTERACTIVE_BARRIER.fetch_add(1, Ordering::Relaxed);
 
-            if get_user_input {
-                num_cancelled += self.interactive_menu().await;
+            if let Some(handles) = handles {
+                self.drain_requests().await;
+                num_cancelled += self.interactive_menu(&handles).await;
                 PAUSE_SCAN.store(false, Ordering::Relaxed);
                 self.print_known_responses();
             }
@@ -465,6 +682,36 @@ impl FeroxScans {
         self.add_scan(&url, ScanType::Directory, scan_order)
     }
 
+    /// Given a url, create a new `FeroxScan` and add it to `FeroxScans` as a Directory Scan that is
+    /// never started (i.e. it matched --dont-recurse-path); no progress bar is created and the
+    /// scan is marked `Complete` so that it's not considered active
+    ///
+    /// If `FeroxScans` did not already contain the scan, return true; otherwise return false
+    ///
+    /// Also return a reference to the new `FeroxScan`
+    pub fn add_skipped_directory_scan(
+        &self,
+        url: &str,
+        scan_order: ScanOrder,
+    ) -> (bool, Arc<FeroxScan>) {
+        let ferox_scan = FeroxScan::new(
+            &url,
+            ScanType::Directory,
+            scan_order,
+            0,
+            self.output_level,
+            None,
+        );
+
+        ferox_scan
+            .set_status(ScanStatus::Complete)
+            .unwrap_or_else(|e| log::warn!("Could not mark scan complete: {}", e));
+
+        let response = self.insert(ferox_scan.clone());
+
+        (response, ferox_scan)
+    }
+
     /// Given a url, create a new `FeroxScan` and add it to `FeroxScans` as a File Scan
     ///
     /// If `FeroxScans` did not already contain the scan, return true; otherwise return false
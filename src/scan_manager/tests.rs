@@ -2,10 +2,11 @@ use super::*;
 use crate::{
     config::{Configuration, OutputLevel},
     event_handlers::Handles,
+    filters::StatusCodeFilter,
     response::FeroxResponse,
     scanner::RESPONSES,
     statistics::Stats,
-    traits::FeroxSerialize,
+    traits::{FeroxFilter, FeroxSerialize},
     SLEEP_DURATION, VERSION,
 };
 use indicatif::ProgressBar;
@@ -41,7 +42,7 @@ async fn scanner_pause_scan_with_finished_spinner() {
         PAUSE_SCAN.store(false, Ordering::Relaxed);
     });
 
-    urls.pause(false).await;
+    urls.pause(None).await;
 
     assert!(now.elapsed() > expected);
 }
@@ -134,6 +135,51 @@ fn add_url_to_list_of_scanned_urls_with_known_url_without_slash() {
     assert!(!result);
 }
 
+#[test]
+/// directory scans that only differ by a trailing slash, duplicate slashes, or default port are
+/// the same scan
+fn add_directory_scan_normalizes_known_urls() {
+    let urls = FeroxScans::default();
+
+    let (added, _) = urls.add_skipped_directory_scan("http://localhost/a", ScanOrder::Latest);
+    assert!(added);
+
+    for variant in &[
+        "http://localhost/a/",
+        "http://localhost:80/a",
+        "http://localhost//a//",
+    ] {
+        let (added, _) = urls.add_skipped_directory_scan(*variant, ScanOrder::Latest);
+        assert!(!added, "{} was added twice", variant);
+        assert!(urls.contains(*variant));
+    }
+
+    assert_eq!(urls.scans.read().unwrap().len(), 1);
+}
+
+#[test]
+/// a file and a directory w/ the same path aren't duplicates, nor are two files whose paths only
+/// differ by a trailing slash
+fn add_file_scan_keeps_files_and_directories_apart() {
+    let urls = FeroxScans::default();
+
+    let (added, _) = urls.add_skipped_directory_scan("http://localhost/a/", ScanOrder::Latest);
+    assert!(added);
+
+    let (added, _) = urls.add_file_scan("http://localhost/a", ScanOrder::Latest);
+    assert!(added);
+
+    let (added, _) = urls.add_file_scan("http://localhost/b", ScanOrder::Latest);
+    assert!(added);
+    assert!(urls.contains("http://localhost/b"));
+    assert!(!urls.contains("http://localhost/b/"));
+
+    let (added, _) = urls.add_file_scan("http://localhost/b/", ScanOrder::Latest);
+    assert!(added);
+
+    assert_eq!(urls.scans.read().unwrap().len(), 4);
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 /// just increasing coverage, no real expectations
 async fn call_display_scans() {
@@ -303,7 +349,7 @@ fn ferox_scans_serialize() {
 #[test]
 /// given a FeroxResponses, test that it serializes into the proper JSON entry
 fn ferox_responses_serialize() {
-    let json_response = r#"{"type":"response","url":"https://nerdcore.com/css","path":"/css","wildcard":true,"status":301,"content_length":173,"line_count":10,"word_count":16,"headers":{"server":"nginx/1.16.1"}}"#;
+    let json_response = r#"{"type":"response","url":"https://nerdcore.com/css","path":"/css","extension":"","wildcard":true,"status":301,"content_length":173,"line_count":10,"word_count":16,"truncated":false,"location":"","original_url":"","redirect_hops":0,"headers":{"server":"nginx/1.16.1"}}"#;
     let response: FeroxResponse = serde_json::from_str(json_response).unwrap();
 
     let responses = FeroxResponses::default();
@@ -321,7 +367,7 @@ fn ferox_responses_serialize() {
 /// given a FeroxResponse, test that it serializes into the proper JSON entry
 fn ferox_response_serialize_and_deserialize() {
     // deserialize
-    let json_response = r#"{"type":"response","url":"https://nerdcore.com/css","path":"/css","wildcard":true,"status":301,"content_length":173,"line_count":10,"word_count":16,"headers":{"server":"nginx/1.16.1"}}"#;
+    let json_response = r#"{"type":"response","url":"https://nerdcore.com/css","path":"/css","extension":"","wildcard":true,"status":301,"content_length":173,"line_count":10,"word_count":16,"truncated":false,"location":"","original_url":"","redirect_hops":0,"headers":{"server":"nginx/1.16.1"}}"#;
     let response: FeroxResponse = serde_json::from_str(json_response).unwrap();
 
     assert_eq!(response.url().as_str(), "https://nerdcore.com/css");
@@ -379,7 +425,7 @@ fn feroxstates_feroxserialize_implementation() {
 
     let json_state = ferox_state.as_json().unwrap();
     let expected = format!(
-        r#"{{"scans":[{{"id":"{}","url":"https://spiritanimal.com","scan_type":"Directory","status":"NotStarted","num_requests":0}}],"config":{{"type":"configuration","wordlist":"/usr/share/seclists/Discovery/Web-Content/raft-medium-directories.txt","config":"","proxy":"","replay_proxy":"","target_url":"","status_codes":[200,204,301,302,307,308,401,403,405],"replay_codes":[200,204,301,302,307,308,401,403,405],"filter_status":[],"threads":50,"timeout":7,"verbosity":0,"silent":false,"quiet":false,"auto_bail":false,"auto_tune":false,"json":false,"output":"","debug_log":"","user_agent":"feroxbuster/{}","redirects":false,"insecure":false,"extensions":[],"headers":{{}},"queries":[],"no_recursion":false,"extract_links":false,"add_slash":false,"stdin":false,"depth":4,"scan_limit":0,"parallel":0,"rate_limit":0,"filter_size":[],"filter_line_count":[],"filter_word_count":[],"filter_regex":[],"dont_filter":false,"resumed":false,"resume_from":"","save_state":false,"time_limit":"","filter_similar":[],"url_denylist":[]}},"responses":[{{"type":"response","url":"https://nerdcore.com/css","path":"/css","wildcard":true,"status":301,"content_length":173,"line_count":10,"word_count":16,"headers":{{"server":"nginx/1.16.1"}}}}]"#,
+        r#"{{"scans":[{{"id":"{}","url":"https://spiritanimal.com","scan_type":"Directory","status":"NotStarted","num_requests":0}}],"config":{{"type":"configuration","wordlist":["/usr/share/seclists/Discovery/Web-Content/raft-medium-directories.txt"],"config":"","proxy":"","proxy_all":false,"replay_proxy":"","target_url":"","status_codes":[200,204,301,302,307,308,401,403,405],"replay_codes":[200,204,301,302,307,308,401,403,405],"filter_status":[],"threads":50,"timeout":7,"connect_timeout":0,"read_timeout":0,"verbosity":0,"silent":false,"quiet":false,"auto_bail":false,"auto_tune":false,"auto_throttle":false,"respect_crawl_delay":false,"json":false,"output_format":"text","output":"","debug_log":"","user_agent":"feroxbuster/{}","redirects":false,"insecure":false,"insecure_hostname_mismatch":false,"http_version":"auto","extensions":[],"transform_case":[],"headers":{{}},"basic_auth":"","bearer":"","queries":[],"no_recursion":false,"dont_recurse_path":[],"extract_links":false,"collect_intel":false,"decode_slashes":false,"add_slash":false,"stdin":false,"depth":4,"scan_limit":0,"parallel":0,"rate_limit":0,"max_requests_per_dir":0,"max_body_size":5242880,"filter_size":[],"filter_line_count":[],"filter_word_count":[],"filter_regex":[],"match_status":[],"match_size":[],"match_word_count":[],"match_line_count":[],"dont_filter":false,"ignore_wildcard_warning":false,"auto_filter":false,"redirects_only":false,"resumed":false,"resume_from":"","save_state":false,"time_limit":"","dry_run":false,"filter_similar":[],"similarity_threshold":95,"url_denylist":[],"dont_scan_case_sensitive":false,"notify_url":"","notify_on":[]}},"responses":[{{"type":"response","url":"https://nerdcore.com/css","path":"/css","extension":"","wildcard":true,"status":301,"content_length":173,"line_count":10,"word_count":16,"truncated":false,"location":"","original_url":"","redirect_hops":0,"headers":{{"server":"nginx/1.16.1"}}}}]"#,
         saved_id, VERSION
     );
     println!("{}\n{}", expected, json_state);
@@ -431,6 +477,7 @@ fn feroxscan_display() {
     let scan = FeroxScan {
         id: "".to_string(),
         url: String::from("http://localhost"),
+        normalized_url: String::from("http://localhost/"),
         scan_order: ScanOrder::Latest,
         scan_type: Default::default(),
         num_requests: 0,
@@ -442,6 +489,8 @@ fn feroxscan_display() {
         task: tokio::sync::Mutex::new(None),
         progress_bar: std::sync::Mutex::new(None),
         errors: Default::default(),
+        requests_issued: Default::default(),
+        requests_skipped: Default::default(),
     };
 
     let not_started = format!("{}", scan);
@@ -475,6 +524,7 @@ async fn ferox_scan_abort() {
     let scan = FeroxScan {
         id: "".to_string(),
         url: String::from("http://localhost"),
+        normalized_url: String::from("http://localhost/"),
         scan_order: ScanOrder::Latest,
         scan_type: Default::default(),
         num_requests: 0,
@@ -488,6 +538,8 @@ async fn ferox_scan_abort() {
         }))),
         progress_bar: std::sync::Mutex::new(None),
         errors: Default::default(),
+        requests_issued: Default::default(),
+        requests_skipped: Default::default(),
     };
 
     scan.abort().await.unwrap();
@@ -526,6 +578,90 @@ fn split_to_nums_is_correct() {
     assert!(menu.split_to_nums("\n").is_empty());
 }
 
+#[test]
+/// each kind of menu input should be parsed into the matching MenuCmd
+fn menu_parse_command_is_correct() {
+    let menu = Menu::new();
+
+    assert!(matches!(menu.parse_command("\n"), MenuCmd::Resume));
+    assert!(matches!(menu.parse_command(" resume "), MenuCmd::Resume));
+    assert!(matches!(menu.parse_command("findings"), MenuCmd::Findings));
+
+    match menu.parse_command("3-5 -f") {
+        MenuCmd::Cancel(nums, force) => {
+            assert_eq!(nums, vec![3, 4, 5]);
+            assert!(force);
+        }
+        other => panic!("{:?}", other),
+    }
+
+    match menu.parse_command("extensions .php, bak  old") {
+        MenuCmd::AddExtensions(extensions) => assert_eq!(extensions, vec!["php", "bak", "old"]),
+        other => panic!("{:?}", other),
+    }
+
+    match menu.parse_command("filter-status 404,500") {
+        MenuCmd::AddFilters(filters) => {
+            assert_eq!(filters.len(), 2);
+            assert!(filters[1].box_eq(StatusCodeFilter { filter_code: 500 }.as_any()));
+        }
+        other => panic!("{:?}", other),
+    }
+
+    match menu.parse_command("filter-regex ^Not Found, sorry$") {
+        MenuCmd::AddFilters(filters) => {
+            assert_eq!(filters.len(), 1);
+            assert!(format!("{:?}", filters[0]).contains("^Not Found, sorry$"));
+        }
+        other => panic!("{:?}", other),
+    }
+
+    assert!(matches!(
+        menu.parse_command("rate-limit 25"),
+        MenuCmd::SetRateLimit(25)
+    ));
+
+    for invalid in &[
+        "rate-limit fast",
+        "filter-size",
+        "filter-words 12,many",
+        "filter-regex",
+        "filter-regex (",
+        "scan-harder",
+    ] {
+        assert!(
+            matches!(menu.parse_command(invalid), MenuCmd::Invalid(_)),
+            "{}",
+            invalid
+        );
+    }
+}
+
+#[test]
+/// adding extensions mid-scan should grow the bars of active scans by the number of words they
+/// have left, as well as the bar length used for new scans
+fn grow_bars_accounts_for_remaining_words() {
+    let urls = FeroxScans::new(OutputLevel::Default);
+    urls.set_bar_length(20);
+
+    let (_, running) = urls.add_directory_scan("http://localhost/running/", ScanOrder::Latest);
+    running.set_status(ScanStatus::Running).unwrap();
+    running.progress_bar().set_position(8);
+
+    let (_, done) = urls.add_directory_scan("http://localhost/done/", ScanOrder::Latest);
+    done.finish().unwrap();
+
+    // 2 requests per word (1 extension), 6 words left to request on the running scan
+    let added = urls.grow_bars(2, 1);
+
+    assert_eq!(added, 6);
+    assert_eq!(running.progress_bar().length(), 26);
+    assert_eq!(done.progress_bar().length(), 20);
+
+    let (_, new) = urls.add_directory_scan("http://localhost/new/", ScanOrder::Latest);
+    assert_eq!(new.progress_bar().length(), 30);
+}
+
 #[test]
 /// given a deep url, find the correct scan
 fn get_base_scan_by_url_finds_correct_scan() {
@@ -592,3 +728,28 @@ fn get_base_scan_by_url_finds_correct_scan_with_trailing_slash() {
         scan.id
     );
 }
+
+#[test]
+/// get_initial_scan_by_url should return the deepest initial directory scan that contains the url
+fn get_initial_scan_by_url_finds_deepest_target() {
+    let scans = FeroxScans::default();
+
+    scans.add_directory_scan("http://localhost/", ScanOrder::Initial);
+    scans.add_directory_scan("http://localhost/app", ScanOrder::Initial);
+    scans.add_directory_scan("http://localhost/app/js/", ScanOrder::Latest);
+    scans.add_file_scan("http://localhost/app/js/main.js", ScanOrder::Initial);
+
+    let found = scans
+        .get_initial_scan_by_url("http://localhost/app/js/main.js")
+        .unwrap();
+    assert_eq!(found.url, "http://localhost/app");
+
+    let found = scans
+        .get_initial_scan_by_url("http://localhost/application/main.js")
+        .unwrap();
+    assert_eq!(found.url, "http://localhost/");
+
+    assert!(scans
+        .get_initial_scan_by_url("http://127.0.0.1/app/js/main.js")
+        .is_none());
+}
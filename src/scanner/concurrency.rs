@@ -0,0 +1,297 @@
+use std::{cmp::max, sync::Mutex};
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// lowest number of concurrent requests a directory scan is tuned down to (--auto-tune)
+const MIN_CONCURRENCY: usize = 1;
+
+/// --threads is multiplied by this value to get the highest number of concurrent requests a
+/// directory scan is tuned up to (--auto-tune)
+const MAX_CONCURRENCY_FACTOR: usize = 2;
+
+/// minimum number of responses seen before an adjustment is considered; keeps a single timeout
+/// at low concurrency from halving the pool
+const MIN_WINDOW: usize = 10;
+
+/// ratio of unhealthy responses (errors/timeouts/429s/503s) within a window at which concurrency
+/// is lowered
+const UNHEALTHY_RATIO: f64 = 0.10;
+
+/// bookkeeping for a ConcurrencyTuner, kept behind a single lock
+#[derive(Debug, Default)]
+struct TunerState {
+    /// number of requests currently allowed to be in-flight
+    current: usize,
+
+    /// number of permits that still need to be taken out of circulation after lowering
+    /// `current`; permits that are in use can't be forgotten until they're returned
+    excess: usize,
+
+    /// number of responses seen since the last adjustment
+    requests: usize,
+
+    /// number of unhealthy responses seen since the last adjustment
+    unhealthy: usize,
+}
+
+/// Sizes the pool of concurrent requests for a single directory scan (--auto-tune)
+///
+/// concurrency starts at --threads and is adjusted once per window of responses (one response
+/// per in-flight request, or MIN_WINDOW, whichever is higher); a window where at least 10% of
+/// responses were unhealthy halves the concurrency, while a window without any unhealthy
+/// responses raises it by 10%. Concurrency stays between MIN_CONCURRENCY and twice --threads.
+#[derive(Debug)]
+pub(super) struct ConcurrencyTuner {
+    /// url of the directory being scanned, used for logging adjustments
+    target_url: String,
+
+    /// permits handed out to requests, one per in-flight word
+    permits: Semaphore,
+
+    /// lower bound on the number of in-flight requests
+    min: usize,
+
+    /// upper bound on the number of in-flight requests
+    max: usize,
+
+    /// current concurrency and the outcomes seen since the last adjustment
+    state: Mutex<TunerState>,
+}
+
+/// permission to make requests, handed out by a ConcurrencyTuner
+///
+/// returns itself to the tuner when dropped, unless the tuner's concurrency was lowered while
+/// the permit was in use, in which case it's taken out of circulation
+pub(super) struct ConcurrencyPermit<'a> {
+    /// the underlying semaphore permit; always Some until dropped
+    permit: Option<SemaphorePermit<'a>>,
+
+    /// tuner that handed out the permit
+    tuner: &'a ConcurrencyTuner,
+}
+
+/// Drop implementation for ConcurrencyPermit
+impl Drop for ConcurrencyPermit<'_> {
+    /// forget the permit if there are more permits in circulation than the tuner wants
+    fn drop(&mut self) {
+        if let Some(permit) = self.permit.take() {
+            if let Ok(mut state) = self.tuner.state.lock() {
+                if state.excess > 0 {
+                    state.excess -= 1;
+                    permit.forget();
+                }
+            }
+        }
+    }
+}
+
+/// implementation of ConcurrencyTuner
+impl ConcurrencyTuner {
+    /// given the number of threads a scan would normally use, create a new ConcurrencyTuner
+    pub fn new(threads: usize, target_url: &str) -> Self {
+        let min = MIN_CONCURRENCY;
+        let max = max(threads * MAX_CONCURRENCY_FACTOR, min);
+        let current = threads.max(min).min(max);
+
+        Self {
+            target_url: target_url.to_string(),
+            permits: Semaphore::new(current),
+            min,
+            max,
+            state: Mutex::new(TunerState {
+                current,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// highest number of concurrent requests this tuner will ever allow
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// number of concurrent requests currently allowed
+    #[cfg(test)]
+    pub fn current(&self) -> usize {
+        self.state.lock().map_or(self.min, |state| state.current)
+    }
+
+    /// wait until another request is allowed to be in-flight
+    pub async fn acquire(&self) -> Option<ConcurrencyPermit<'_>> {
+        // the semaphore is never closed, so acquire can't fail
+        let permit = self.permits.acquire().await.ok()?;
+
+        Some(ConcurrencyPermit {
+            permit: Some(permit),
+            tuner: self,
+        })
+    }
+
+    /// record the outcome of a single request; `healthy` is false for errors/timeouts and for
+    /// responses where the target asked us to slow down (429/503)
+    ///
+    /// returns the new concurrency when an adjustment was made
+    pub fn record(&self, healthy: bool) -> Option<usize> {
+        let mut state = self.state.lock().ok()?;
+
+        state.requests += 1;
+
+        if !healthy {
+            state.unhealthy += 1;
+        }
+
+        if state.requests < max(state.current, MIN_WINDOW) {
+            // still filling up the current window
+            return None;
+        }
+
+        let requests = state.requests;
+        let unhealthy = state.unhealthy;
+        let previous = state.current;
+
+        state.requests = 0;
+        state.unhealthy = 0;
+
+        let ratio = unhealthy as f64 / requests as f64;
+
+        let next = if ratio >= UNHEALTHY_RATIO {
+            // back off hard when the target is struggling
+            max(previous / 2, self.min)
+        } else if unhealthy == 0 {
+            // and ease back in when it's not
+            (previous + max(previous / 10, 1)).min(self.max)
+        } else {
+            previous
+        };
+
+        if next == previous {
+            return None;
+        }
+
+        if next > previous {
+            let mut to_add = next - previous;
+
+            // permits that haven't been forgotten yet can simply be kept instead
+            let kept = state.excess.min(to_add);
+            state.excess -= kept;
+            to_add -= kept;
+
+            self.permits.add_permits(to_add);
+        } else {
+            state.excess += previous - next;
+
+            // take idle permits out of circulation right away, the rest are forgotten as they're
+            // returned
+            while state.excess > 0 {
+                match self.permits.try_acquire() {
+                    Ok(permit) => {
+                        permit.forget();
+                        state.excess -= 1;
+                    }
+                    Err(_) => break,
+                }
+            }
+        }
+
+        state.current = next;
+
+        log::info!(
+            "auto-tune: {} concurrency for {} from {} to {} ({} of {} responses were errors/429s/503s)",
+            if next > previous { "raised" } else { "lowered" },
+            self.target_url,
+            previous,
+            next,
+            unhealthy,
+            requests
+        );
+
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// record the given number of healthy/unhealthy responses, returning the last adjustment
+    fn record_many(tuner: &ConcurrencyTuner, healthy: usize, unhealthy: usize) -> Option<usize> {
+        let mut adjusted = None;
+
+        for i in 0..healthy + unhealthy {
+            if let Some(next) = tuner.record(i >= unhealthy) {
+                adjusted = Some(next);
+            }
+        }
+
+        adjusted
+    }
+
+    #[test]
+    /// a new tuner starts at --threads and is bounded by MIN_CONCURRENCY and twice --threads
+    fn concurrency_tuner_new_sets_bounds() {
+        let tuner = ConcurrencyTuner::new(50, "http://localhost");
+        assert_eq!(tuner.current(), 50);
+        assert_eq!(tuner.max(), 100);
+        assert_eq!(tuner.min, MIN_CONCURRENCY);
+        assert_eq!(tuner.permits.available_permits(), 50);
+
+        let tuner = ConcurrencyTuner::new(0, "http://localhost");
+        assert_eq!(tuner.current(), MIN_CONCURRENCY);
+        assert_eq!(tuner.max(), MIN_CONCURRENCY);
+    }
+
+    #[test]
+    /// a window of healthy responses raises concurrency, unhealthy ones lower it, and it never
+    /// leaves its bounds
+    fn concurrency_tuner_adjusts_within_bounds() {
+        let tuner = ConcurrencyTuner::new(20, "http://localhost");
+
+        assert_eq!(record_many(&tuner, 20, 0), Some(22));
+        assert_eq!(tuner.permits.available_permits(), 22);
+
+        assert_eq!(record_many(&tuner, 18, 4), Some(11));
+        assert_eq!(tuner.permits.available_permits(), 11);
+
+        // some errors, but not enough to lower it, nor few enough to raise it
+        assert_eq!(record_many(&tuner, 10, 1), None);
+        assert_eq!(tuner.current(), 11);
+
+        for _ in 0..10 {
+            record_many(&tuner, 0, 10);
+        }
+        assert_eq!(tuner.current(), MIN_CONCURRENCY);
+
+        for _ in 0..50 {
+            record_many(&tuner, 50, 0);
+        }
+        assert_eq!(tuner.current(), 40);
+        assert_eq!(tuner.permits.available_permits(), 40);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// permits that are in use while concurrency is lowered should be taken out of circulation
+    /// when they're returned
+    async fn concurrency_tuner_forgets_permits_in_use_when_lowered() {
+        let tuner = ConcurrencyTuner::new(4, "http://localhost");
+
+        let first = tuner.acquire().await.unwrap();
+        let second = tuner.acquire().await.unwrap();
+        let third = tuner.acquire().await.unwrap();
+
+        assert_eq!(record_many(&tuner, 0, 10), Some(2));
+
+        // the single idle permit was forgotten right away
+        assert_eq!(tuner.permits.available_permits(), 0);
+
+        drop(first);
+        assert_eq!(tuner.permits.available_permits(), 0);
+
+        drop(second);
+        drop(third);
+        assert_eq!(tuner.permits.available_permits(), 2);
+
+        // raising concurrency again should hand out new permits
+        assert_eq!(record_many(&tuner, 10, 0), Some(3));
+        assert_eq!(tuner.permits.available_permits(), 3);
+    }
+}
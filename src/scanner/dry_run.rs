@@ -0,0 +1,112 @@
+use std::{io::Write, sync::Arc};
+
+use anyhow::Result;
+
+use crate::{event_handlers::Handles, url::FeroxUrl, utils::should_deny_url};
+
+/// Write every url that would be requested during the initial scan of each target to `writer`,
+/// one per line, without sending a single request (--dry-run)
+///
+/// urls are generated the same way they are during a scan, so extensions, --add-slash,
+/// --dont-scan and --max-requests-per-dir are all taken into account. recursion and link
+/// extraction depend on responses, so they can't be planned ahead of time.
+///
+/// returns the number of urls written
+pub fn dry_run<W: Write>(
+    targets: &[String],
+    words: &[String],
+    handles: Arc<Handles>,
+    writer: &mut W,
+) -> Result<usize> {
+    log::trace!(
+        "enter: dry_run({:?}, [{} words...], {:?})",
+        targets,
+        words.len(),
+        handles
+    );
+
+    let should_test_deny = !handles.config.url_denylist.is_empty();
+    let budget = handles.config.max_requests_per_dir;
+
+    let mut total = 0;
+
+    for target in targets {
+        let ferox_url = FeroxUrl::from_string(target, handles.clone());
+        let mut planned = 0;
+
+        'words: for word in words {
+            for url in ferox_url.formatted_urls(word)? {
+                if budget > 0 && planned >= budget {
+                    // this directory has used up its budget (--max-requests-per-dir)
+                    break 'words;
+                }
+
+                if should_test_deny && should_deny_url(&url, handles.clone())? {
+                    continue;
+                }
+
+                writeln!(writer, "{}", url)?;
+                planned += 1;
+            }
+        }
+
+        total += planned;
+    }
+
+    writer.flush()?;
+
+    log::trace!("exit: dry_run -> {}", total);
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Configuration;
+
+    #[test]
+    /// each word should produce one url per extension, in wordlist order, for each target
+    fn dry_run_writes_each_url_once_per_extension() {
+        let config = Configuration {
+            extensions: vec![String::from("php")],
+            ..Default::default()
+        };
+
+        let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+        let targets = vec![
+            String::from("http://localhost"),
+            String::from("http://127.0.0.1/api/"),
+        ];
+        let words = vec![String::from("admin"), String::from("login")];
+
+        let mut output = Vec::new();
+        let total = dry_run(&targets, &words, handles, &mut output).unwrap();
+
+        assert_eq!(total, 8);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "http://localhost/admin\nhttp://localhost/admin.php\nhttp://localhost/login\n\
+             http://localhost/login.php\nhttp://127.0.0.1/api/admin\nhttp://127.0.0.1/api/admin.php\n\
+             http://127.0.0.1/api/login\nhttp://127.0.0.1/api/login.php\n"
+        );
+    }
+
+    #[test]
+    /// --max-requests-per-dir should cap the number of urls planned for each target
+    fn dry_run_honors_max_requests_per_dir() {
+        let config = Configuration {
+            max_requests_per_dir: 3,
+            ..Default::default()
+        };
+
+        let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+        let targets = vec![String::from("http://localhost")];
+        let words: Vec<_> = (0..10).map(|i| i.to_string()).collect();
+
+        let mut output = Vec::new();
+        let total = dry_run(&targets, &words, handles, &mut output).unwrap();
+
+        assert_eq!(total, 3);
+        assert_eq!(String::from_utf8(output).unwrap().lines().count(), 3);
+    }
+}
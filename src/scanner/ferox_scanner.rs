@@ -1,23 +1,28 @@
 use std::{ops::Deref, sync::atomic::Ordering, sync::Arc, time::Instant};
 
 use anyhow::{bail, Result};
-use futures::{stream, StreamExt};
+use console::style;
+use futures::{future, stream, StreamExt};
+use indicatif::ProgressBar;
 use lazy_static::lazy_static;
+use reqwest::Url;
 use tokio::sync::Semaphore;
 
 use crate::{
+    config::OutputLevel,
     event_handlers::{
         Command::{AddError, AddToF64Field, SubtractFromUsizeField},
-        Handles,
+        FeroxEvent, Handles,
     },
-    extractor::{ExtractionTarget::RobotsTxt, ExtractorBuilder},
+    extractor::{ExtractionTarget::RobotsTxt, ExtractorBuilder, RobotsCache},
     heuristics,
-    scan_manager::{FeroxResponses, ScanOrder, ScanStatus, PAUSE_SCAN},
+    progress::PROGRESS_PRINTER,
+    scan_manager::{FeroxResponses, InFlight, ScanOrder, ScanStatus, PAUSE_SCAN},
     statistics::{
         StatError::Other,
         StatField::{DirScanTimes, TotalExpected},
     },
-    utils::fmt_err,
+    utils::{ferox_print, fmt_err, status_colorizer},
 };
 
 use super::requester::Requester;
@@ -65,6 +70,31 @@ impl FeroxScanner {
         }
     }
 
+    /// look up the Crawl-delay of the host being scanned and, when it has one, have every
+    /// request to that host wait for it
+    async fn set_crawl_delay(&self) -> Result<()> {
+        log::trace!("enter: set_crawl_delay");
+
+        let extractor = ExtractorBuilder::default()
+            .url(&self.target_url)
+            .handles(self.handles.clone())
+            .target(RobotsTxt)
+            .build()?;
+
+        if let Some(delay) = extractor.crawl_delay().await? {
+            let key = RobotsCache::key(&Url::parse(&self.target_url)?);
+
+            if self.handles.crawl_delays.get(&key).is_none() {
+                log::info!("{} asked for a Crawl-delay of {:?}", key, delay);
+            }
+
+            self.handles.crawl_delays.set(&key, delay);
+        }
+
+        log::trace!("exit: set_crawl_delay");
+        Ok(())
+    }
+
     /// Scan a given url using a given wordlist
     ///
     /// This is the primary entrypoint for the scanner
@@ -87,6 +117,13 @@ impl FeroxScanner {
             extractor.request_links(links).await?;
         }
 
+        if self.handles.config.respect_crawl_delay {
+            // robots.txt is cached per host, so this only costs a request for new hosts
+            if let Err(e) = self.set_crawl_delay().await {
+                log::warn!("Could not get Crawl-delay for {}: {}", self.target_url, e);
+            }
+        }
+
         let scanned_urls = self.handles.ferox_scans()?;
 
         let ferox_scan = match scanned_urls.get_scan_by_url(&self.target_url) {
@@ -105,6 +142,10 @@ impl FeroxScanner {
 
         let progress_bar = ferox_scan.progress_bar();
 
+        self.handles.events.publish(FeroxEvent::ScanStarted {
+            url: self.target_url.clone(),
+        });
+
         // When acquire is called and the semaphore has remaining permits, the function immediately
         // returns a permit. However, if no remaining permits are available, acquire (asynchronously)
         // waits until an outstanding permit is dropped, at which point, the freed permit is assigned
@@ -119,13 +160,36 @@ impl FeroxScanner {
             if let Ok(num_reqs) = test.wildcard(&self.target_url).await {
                 progress_bar.inc(num_reqs);
             }
+
+            // --auto-filter probes are made per-directory, meaning each level of recursion
+            // gets its own probe and filter
+            match test.auto_filter(&self.target_url).await {
+                Ok(num_reqs) => progress_bar.inc(num_reqs),
+                Err(e) => log::warn!(
+                    "Could not probe {} for --auto-filter: {}",
+                    self.target_url,
+                    e
+                ),
+            }
         }
 
         let requester = Arc::new(Requester::from(self, ferox_scan.clone())?);
-        let increment_len = (self.handles.config.extensions.len() + 1) as u64;
+        let max_concurrency = requester.max_concurrency();
+        let max_requests = self.handles.config.max_requests_per_dir;
 
         // producer tasks (mp of mpsc); responsible for making requests
         let producers = stream::iter(looping_words.deref().to_owned())
+            .take_while(|_| {
+                // stop submitting words once the directory's budget is spent; any requests
+                // that are already in-flight are still allowed to finish and report
+                if ferox_scan.reached_max_requests(max_requests) {
+                    // there's at least this word left that won't be requested
+                    ferox_scan.add_skipped_request();
+                    return future::ready(false);
+                }
+
+                future::ready(true)
+            })
             .map(|word| {
                 let pb = progress_bar.clone(); // progress bar is an Arc around internal state
                 let scanned_urls_clone = scanned_urls.clone();
@@ -133,11 +197,18 @@ impl FeroxScanner {
                 let handles_clone = self.handles.clone();
                 (
                     tokio::spawn(async move {
+                        // marked as in-flight before checking PAUSE_SCAN, so that a pause can't
+                        // slip in between the check and the request without waiting on it
+                        let mut in_flight = InFlight::start();
+
                         if PAUSE_SCAN.load(Ordering::Acquire) {
                             // for every word in the wordlist, check to see if PAUSE_SCAN is set to true
                             // when true; enter a busy loop that only exits by setting PAUSE_SCAN back
                             // to false
-                            let num_cancelled = scanned_urls_clone.pause(true).await;
+                            // this word hasn't been requested yet, it shouldn't hold up the pause
+                            drop(in_flight);
+                            let num_cancelled =
+                                scanned_urls_clone.pause(Some(handles_clone.clone())).await;
                             if num_cancelled > 0 {
                                 handles_clone
                                     .stats
@@ -146,19 +217,22 @@ impl FeroxScanner {
                                         log::warn!("Could not update overall scan bar: {}", e)
                                     });
                             }
+                            in_flight = InFlight::start();
                         }
-                        requester_clone
-                            .request(&word)
-                            .await
-                            .unwrap_or_else(|e| log::warn!("Requester encountered an error: {}", e))
+                        requester_clone.request(&word).await.unwrap_or_else(|e| {
+                            log::warn!("Requester encountered an error: {}", e)
+                        });
+                        drop(in_flight);
                     }),
                     pb,
                 )
             })
-            .for_each_concurrent(self.handles.config.threads, |(resp, bar)| async move {
+            .for_each_concurrent(max_concurrency, |(resp, bar)| async move {
                 match resp.await {
                     Ok(_) => {
-                        bar.inc(increment_len);
+                        // extensions may have been added from the interactive menu since the
+                        // scan started
+                        bar.inc((self.handles.scope.data.num_extensions() + 1) as u64);
                     }
                     Err(e) => {
                         log::warn!("error awaiting a response: {}", e);
@@ -172,6 +246,12 @@ impl FeroxScanner {
         producers.await;
         log::trace!("done awaiting scan producers");
 
+        if ferox_scan.skipped_requests() > 0 {
+            // only when the limit actually cut the scan short, not when it happened to match
+            // the number of requests to be made
+            self.report_max_requests(&progress_bar, max_requests);
+        }
+
         self.handles.stats.send(AddToF64Field(
             DirScanTimes,
             scan_timer.elapsed().as_secs_f64(),
@@ -179,8 +259,48 @@ impl FeroxScanner {
 
         ferox_scan.finish()?;
 
+        self.handles.events.publish(FeroxEvent::ScanFinished {
+            url: self.target_url.clone(),
+        });
+
         log::trace!("exit: scan_url");
 
         Ok(())
     }
+
+    /// let the user know that a directory stopped early due to --max-requests-per-dir and remove
+    /// the skipped requests from the overall scan bar
+    fn report_max_requests(&self, progress_bar: &ProgressBar, max_requests: usize) {
+        log::info!(
+            "{} reached its limit of {} requests",
+            self.target_url,
+            max_requests
+        );
+
+        if matches!(
+            self.handles.config.output_level,
+            OutputLevel::Default | OutputLevel::Quiet
+        ) {
+            let msg = format!(
+                "{} {:>9} {:>9} {:>9} Stopped scanning {} after {} requests; change this behavior by using {}",
+                status_colorizer("MAX"),
+                "-",
+                "-",
+                "-",
+                self.target_url,
+                max_requests,
+                style("--max-requests-per-dir").yellow()
+            );
+            ferox_print(&msg, &PROGRESS_PRINTER);
+        }
+
+        let num_skipped = progress_bar
+            .length()
+            .saturating_sub(progress_bar.position()) as usize;
+
+        self.handles
+            .stats
+            .send(SubtractFromUsizeField(TotalExpected, num_skipped))
+            .unwrap_or_else(|e| log::warn!("Could not update overall scan bar: {}", e));
+    }
 }
@@ -1,3 +1,5 @@
+mod concurrency;
+mod dry_run;
 mod ferox_scanner;
 mod utils;
 mod init;
@@ -7,6 +9,7 @@ mod limit_heap;
 mod policy_data;
 mod requester;
 
+pub use self::dry_run::dry_run;
 pub use self::ferox_scanner::{FeroxScanner, RESPONSES};
 pub use self::init::initialize;
 pub use self::utils::PolicyTrigger;
@@ -1,34 +1,75 @@
 use std::{
     cmp::max,
-    sync::{atomic::Ordering, Arc, Mutex},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
 };
 
 use anyhow::Result;
 use leaky_bucket::LeakyBucket;
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    Response, StatusCode, Url,
+};
 use tokio::{
     sync::{oneshot, RwLock},
-    time::{sleep, Duration},
+    time::{sleep, sleep_until, Duration, Instant},
 };
 
 use crate::{
-    atomic_load, atomic_store,
+    atomic_load, atomic_store, client,
     config::RequesterPolicy,
     event_handlers::{
-        Command::{self, AddError, SubtractFromUsizeField},
+        Command::{self, AddError, AddToUsizeField, SubtractFromUsizeField},
         Handles,
     },
     extractor::{ExtractionTarget::ResponseBody, ExtractorBuilder},
     response::FeroxResponse,
     scan_manager::{FeroxScan, ScanStatus},
-    statistics::{StatError::Other, StatField::TotalExpected},
+    statistics::{
+        StatError::Other,
+        StatField::{Throttled, TotalExpected},
+    },
     url::FeroxUrl,
     utils::logged_request,
     HIGH_ERROR_RATIO,
 };
 
-use super::{policy_data::PolicyData, FeroxScanner, PolicyTrigger};
+use super::{concurrency::ConcurrencyTuner, policy_data::PolicyData, FeroxScanner, PolicyTrigger};
 use crate::utils::should_deny_url;
-use std::collections::HashSet;
+use std::{collections::HashSet, time::SystemTime};
+
+/// amount of time a scan is paused when a 429/503 doesn't come with a usable Retry-After header
+/// (--auto-throttle)
+const DEFAULT_THROTTLE_WAIT: Duration = Duration::from_secs(5);
+
+/// upper bound on the amount of time a scan is paused by a single Retry-After header; keeps an
+/// overly cautious server from stalling the scan indefinitely (--auto-throttle)
+const MAX_THROTTLE_WAIT: Duration = Duration::from_secs(300);
+
+/// number of times a url whose response asked us to slow down is requested again, once the pause
+/// is over (--auto-throttle)
+const MAX_THROTTLE_RETRIES: usize = 2;
+
+/// parse the Retry-After header, if any, into the amount of time that should be waited
+///
+/// the header's value is either a number of seconds or an http-date; a date in the past results
+/// in a zero wait time, while a missing or unparseable value results in None
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or_else(|_| Duration::from_secs(0)),
+    )
+}
 
 /// Makes multiple requests based on the presence of extensions
 pub(super) struct Requester {
@@ -41,6 +82,10 @@ pub(super) struct Requester {
     /// limits requests per second if present
     rate_limiter: RwLock<Option<LeakyBucket>>,
 
+    /// --rate-limit as of the last request; used to notice the limit being changed from the
+    /// interactive menu
+    scope_rate_limit: AtomicUsize,
+
     /// data regarding policy and metadata about last enforced trigger etc...
     policy_data: PolicyData,
 
@@ -58,13 +103,21 @@ pub(super) struct Requester {
     /// seen; this will satisfy the non-mut self constraint (due to us being behind an Arc, and
     /// the need for a counter)
     tuning_lock: Mutex<usize>,
+
+    /// point in time before which no new requests are sent for this scan, set when the target
+    /// asks us to slow down (--auto-throttle)
+    throttled_until: Mutex<Option<Instant>>,
+
+    /// sizes the number of words being requested at once based on how healthy the target's
+    /// responses are (--auto-tune)
+    concurrency: Option<ConcurrencyTuner>,
 }
 
 /// Requester implementation
 impl Requester {
     /// given a FeroxScanner, create a Requester
     pub fn from(scanner: &FeroxScanner, ferox_scan: Arc<FeroxScan>) -> Result<Self> {
-        let limit = scanner.handles.config.rate_limit;
+        let limit = scanner.handles.scope.data.rate_limit();
 
         let rate_limiter = if limit > 0 {
             Some(Self::build_a_bucket(limit)?)
@@ -77,17 +130,37 @@ impl Requester {
             scanner.handles.config.timeout,
         );
 
+        let concurrency = if scanner.handles.config.auto_tune {
+            Some(ConcurrencyTuner::new(
+                scanner.handles.config.threads,
+                &scanner.target_url,
+            ))
+        } else {
+            None
+        };
+
         Ok(Self {
             ferox_scan,
             policy_data,
             seen_links: RwLock::new(HashSet::<String>::new()),
             rate_limiter: RwLock::new(rate_limiter),
+            scope_rate_limit: AtomicUsize::new(limit),
             handles: scanner.handles.clone(),
             target_url: scanner.target_url.to_owned(),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency,
         })
     }
 
+    /// highest number of words that may be requested at once; --threads, unless the concurrency
+    /// is being tuned (--auto-tune)
+    pub fn max_concurrency(&self) -> usize {
+        self.concurrency
+            .as_ref()
+            .map_or(self.handles.config.threads, |tuner| tuner.max())
+    }
+
     /// build a LeakyBucket, given a rate limit (as requests per second)
     fn build_a_bucket(limit: usize) -> Result<LeakyBucket> {
         let refill = max((limit as f64 / 10.0).round() as usize, 1); // minimum of 1 per second
@@ -241,6 +314,20 @@ impl Requester {
         Ok(())
     }
 
+    /// replace the rate limiter when --rate-limit was changed from the interactive menu since the
+    /// last request; a limit of 0 removes it
+    async fn sync_rate_limit(&self) -> Result<()> {
+        let limit = self.handles.scope.data.rate_limit();
+
+        if self.scope_rate_limit.swap(limit, Ordering::Relaxed) != limit {
+            log::info!("rate limit for {} changed to {}/s", self.target_url, limit);
+            let new_limit = if limit > 0 { Some(limit) } else { None };
+            self.set_rate_limiter(new_limit).await?;
+        }
+
+        Ok(())
+    }
+
     /// enforce auto-tune policy
     async fn tune(&self, trigger: PolicyTrigger) -> Result<()> {
         if atomic_load!(self.policy_data.errors) == 0 {
@@ -297,23 +384,164 @@ impl Requester {
         Ok(())
     }
 
+    /// determine whether or not the given response is the target asking us to slow down
+    fn is_throttling_response(response: &Response) -> bool {
+        matches!(
+            response.status(),
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+        )
+    }
+
+    /// wait out any pause put in place by a previous call to `throttle`
+    async fn wait_for_throttle(&self) {
+        let deadline = *self.throttled_until.lock().unwrap();
+
+        if let Some(deadline) = deadline {
+            if deadline > Instant::now() {
+                sleep_until(deadline).await;
+            }
+        }
+    }
+
+    /// enforce auto-throttle
+    ///
+    /// pauses this scan for as long as the response's Retry-After header asks (or for
+    /// DEFAULT_THROTTLE_WAIT), and halves the scan's rate limit so that requests don't come
+    /// rushing back in once the pause is over
+    async fn throttle(&self, response: &Response) -> Result<()> {
+        let wait = retry_after(response.headers())
+            .unwrap_or(DEFAULT_THROTTLE_WAIT)
+            .min(MAX_THROTTLE_WAIT);
+
+        let now = Instant::now();
+
+        let already_throttled = {
+            let mut guard = self.throttled_until.lock().unwrap();
+            let already_throttled = guard.map_or(false, |deadline| deadline > now);
+
+            // racy threads may see a different Retry-After, the longest pause wins
+            if guard.map_or(true, |deadline| deadline < now + wait) {
+                *guard = Some(now + wait);
+            }
+
+            already_throttled
+        };
+
+        if already_throttled {
+            // in-flight requests that come back while paused don't count as new throttle events
+            return Ok(());
+        }
+
+        log::warn!(
+            "{} responded with {}, pausing {} for {}s",
+            response.url(),
+            response.status(),
+            self.target_url,
+            wait.as_secs()
+        );
+
+        self.handles
+            .stats
+            .send(AddToUsizeField(Throttled, 1))
+            .unwrap_or_else(|e| log::warn!("Could not add to Throttled stat bucket: {}", e));
+
+        let current_limit = match self.rate_limiter.read().await.as_ref() {
+            Some(bucket) => bucket.max(),
+            None => self.ferox_scan.requests_per_second() as usize,
+        };
+
+        if current_limit > 0 {
+            self.set_rate_limiter(Some(max(current_limit / 2, 1)))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// request the given url, waiting out the pause and trying again (up to MAX_THROTTLE_RETRIES
+    /// times) when the target asks us to slow down (--auto-throttle)
+    ///
+    /// responses that only exist to tell us to slow down aren't findings, so None is returned
+    /// when the target is still asking us to slow down after the last retry
+    async fn request_with_retries(&self, url: &Url) -> Result<Option<Response>> {
+        let mut retries = 0;
+
+        loop {
+            let response = logged_request(url, self.handles.clone()).await;
+
+            if let Some(tuner) = &self.concurrency {
+                // errors/timeouts and responses asking us to slow down count against the target
+                tuner.record(matches!(&response, Ok(resp) if !Self::is_throttling_response(resp)));
+            }
+
+            let response = response?;
+
+            if !self.handles.config.auto_throttle || !Self::is_throttling_response(&response) {
+                return Ok(Some(response));
+            }
+
+            self.throttle(&response).await?;
+
+            if retries == MAX_THROTTLE_RETRIES {
+                log::warn!(
+                    "{} still responded with {} after {} retries, skipping it",
+                    url,
+                    response.status(),
+                    retries
+                );
+                self.handles.stats.send(AddError(Other)).unwrap_or_default();
+                return Ok(None);
+            }
+
+            retries += 1;
+
+            self.wait_for_throttle().await;
+
+            if self.rate_limiter.read().await.is_some() {
+                // throttle lowered (or created) the rate limit, the retry is subject to it as well
+                if let Err(e) = self.limit().await {
+                    log::warn!("Could not rate limit scan: {}", e);
+                    self.handles.stats.send(AddError(Other)).unwrap_or_default();
+                }
+            }
+        }
+    }
+
     /// Wrapper for make_request
     ///
     /// Attempts recursion when appropriate and sends Responses to the output handler for processing
     pub async fn request(&self, word: &str) -> Result<()> {
         log::trace!("enter: request({})", word);
 
-        let urls =
-            FeroxUrl::from_string(&self.target_url, self.handles.clone()).formatted_urls(word)?;
+        let urls = FeroxUrl::from_string(&self.target_url, self.handles.clone())
+            .formatted_urls_with_extensions(word)?;
 
         let should_test_deny = !self.handles.config.url_denylist.is_empty();
 
-        for url in urls {
+        // with --auto-tune, wait until the tuner allows another word to be in-flight; the permit
+        // is held until all of the word's requests are done
+        let _permit = match &self.concurrency {
+            Some(tuner) => tuner.acquire().await,
+            None => None,
+        };
+
+        for (url, extension) in urls {
+            if self.handles.config.auto_throttle {
+                // the target told us to back off, wait until it's ready for us again
+                self.wait_for_throttle().await;
+            }
+
+            if let Err(e) = self.sync_rate_limit().await {
+                log::warn!("Could not update rate limit for {}: {}", self.target_url, e);
+            }
+
             // auto_tune is true, or rate_limit was set (mutually exclusive to user)
             // and a rate_limiter has been created
             // short-circuiting the lock access behind the first boolean check
-            let should_tune = self.handles.config.auto_tune || self.handles.config.rate_limit > 0;
-            let should_limit = should_tune && self.rate_limiter.read().await.is_some();
+            let should_tune =
+                self.handles.config.auto_tune || self.handles.scope.data.rate_limit() > 0;
+            let should_limit = (should_tune || self.handles.config.auto_throttle)
+                && self.rate_limiter.read().await.is_some();
 
             if should_limit {
                 // found a rate limiter, limit that junk!
@@ -328,7 +556,27 @@ impl Requester {
                 continue;
             }
 
-            let response = logged_request(&url, self.handles.clone()).await?;
+            if !self
+                .ferox_scan
+                .try_add_request(self.handles.config.max_requests_per_dir)
+            {
+                // this directory has used up its budget (--max-requests-per-dir)
+                continue;
+            }
+
+            if self.handles.config.respect_crawl_delay {
+                // robots.txt asked for some time between requests; this is on top of any rate
+                // limiting above, so whichever is slower wins
+                self.handles.crawl_delays.wait(&url).await;
+            }
+
+            let (response, hops) =
+                client::with_redirect_hops(self.request_with_retries(&url)).await;
+
+            let response = match response? {
+                Some(response) => response,
+                None => continue,
+            };
 
             if (should_tune || self.handles.config.auto_bail)
                 && !atomic_load!(self.policy_data.cooling_down, Ordering::SeqCst)
@@ -352,8 +600,16 @@ impl Requester {
             }
 
             // response came back without error, convert it to FeroxResponse
-            let ferox_response =
-                FeroxResponse::from(response, true, self.handles.config.output_level).await;
+            let mut ferox_response = FeroxResponse::from(
+                response,
+                true,
+                self.handles.config.max_body_size,
+                self.handles.config.output_level,
+            )
+            .await;
+            ferox_response.set_redirect_origin(&url, hops);
+            ferox_response.set_wordlist_entry(word);
+            ferox_response.set_extension(extension.as_deref());
 
             // do recursion if appropriate
             if !self.handles.config.no_recursion {
@@ -424,7 +680,10 @@ mod tests {
     use crate::{
         config::Configuration,
         config::OutputLevel,
-        event_handlers::{FiltersHandler, ScanHandler, StatsHandler, Tasks, TermOutHandler},
+        event_handlers::{
+            EventsHandler, FiltersHandler, ScanHandler, ScopeHandler, StatsHandler, Tasks,
+            TermOutHandler,
+        },
         filters,
         scan_manager::{ScanOrder, ScanType},
         statistics::StatError,
@@ -439,14 +698,21 @@ mod tests {
 
         let (stats_task, stats_handle) = StatsHandler::initialize(configuration.clone());
         let (filters_task, filters_handle) = FiltersHandler::initialize();
-        let (out_task, out_handle) =
-            TermOutHandler::initialize(configuration.clone(), stats_handle.tx.clone());
+        let (scope_task, scope_handle) = ScopeHandler::initialize(configuration.clone());
+        let (events_task, events_handle) = EventsHandler::initialize();
+        let (out_task, out_handle) = TermOutHandler::initialize(
+            configuration.clone(),
+            stats_handle.tx.clone(),
+            events_handle.clone(),
+        );
 
         let handles = Arc::new(Handles::new(
             stats_handle,
             filters_handle,
             out_handle,
             configuration.clone(),
+            scope_handle,
+            events_handle,
         ));
 
         let (scan_task, scan_handle) = ScanHandler::initialize(handles.clone());
@@ -454,7 +720,14 @@ mod tests {
         handles.set_scan_handle(scan_handle);
         filters::initialize(handles.clone()).await.unwrap();
 
-        let tasks = Tasks::new(out_task, stats_task, filters_task, scan_task);
+        let tasks = Tasks::new(
+            out_task,
+            stats_task,
+            filters_task,
+            scan_task,
+            scope_task,
+            events_task,
+        );
 
         (handles, tasks)
     }
@@ -572,9 +845,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: Arc::new(FeroxScan::default()),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: Default::default(),
         };
 
@@ -600,9 +876,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: ferox_scan.clone(),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: Default::default(),
         };
 
@@ -625,9 +904,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: ferox_scan.clone(),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: Default::default(),
         };
 
@@ -665,9 +947,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: ferox_scan.clone(),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: Default::default(),
         };
 
@@ -720,9 +1005,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: req_clone,
             target_url: "http://one/one/stuff.php".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: Default::default(),
         };
 
@@ -754,9 +1042,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: Arc::new(FeroxScan::default()),
             target_url: "http://one/one/stuff.php".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: Default::default(),
         };
 
@@ -776,9 +1067,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: Arc::new(FeroxScan::default()),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: Default::default(),
         };
 
@@ -799,9 +1093,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: Arc::new(FeroxScan::default()),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
         });
 
@@ -829,9 +1126,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: Arc::new(FeroxScan::default()),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
         };
 
@@ -867,9 +1167,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: Arc::new(scan),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(Some(limiter)),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
         };
 
@@ -903,9 +1206,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: Arc::new(scan),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
         };
 
@@ -931,9 +1237,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: Arc::new(FeroxScan::default()),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
         };
 
@@ -974,9 +1283,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: Arc::new(FeroxScan::default()),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(Some(limiter)),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: PolicyData::new(RequesterPolicy::AutoBail, 7),
         };
 
@@ -1017,9 +1329,12 @@ mod tests {
             handles,
             seen_links: RwLock::new(HashSet::<String>::new()),
             tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
             ferox_scan: scan.clone(),
             target_url: "http://localhost".to_string(),
             rate_limiter: RwLock::new(Some(limiter)),
+            scope_rate_limit: AtomicUsize::new(0),
             policy_data: PolicyData::new(RequesterPolicy::AutoTune, 4),
         };
 
@@ -1044,4 +1359,130 @@ mod tests {
         scan.finish().unwrap();
         assert!(start.elapsed().as_millis() >= 2000);
     }
+
+    #[test]
+    /// retry_after should understand both seconds and http-dates, and ignore garbage
+    fn retry_after_parses_seconds_and_dates() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, "7".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(0)));
+
+        let future = SystemTime::now() + Duration::from_secs(120);
+        headers.insert(
+            RETRY_AFTER,
+            httpdate::fmt_http_date(future).parse().unwrap(),
+        );
+        let wait = retry_after(&headers).unwrap();
+        assert!(wait > Duration::from_secs(100) && wait <= Duration::from_secs(120));
+
+        headers.insert(RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// throttle should pause the scan and only count the first of several back to back
+    /// throttling responses; w/o a limiter or known request rate, no limiter is created
+    async fn throttle_pauses_scan_and_lowers_rate_limit() {
+        let srv = httpmock::MockServer::start();
+        let mock = srv.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/slow-down");
+            then.status(429).header("Retry-After", "1");
+        });
+
+        let (handles, _) = setup_requester_test(None).await;
+
+        let requester = Requester {
+            handles,
+            seen_links: RwLock::new(HashSet::<String>::new()),
+            tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
+            ferox_scan: Arc::new(FeroxScan::default()),
+            target_url: srv.url("/"),
+            rate_limiter: RwLock::new(None),
+            scope_rate_limit: AtomicUsize::new(0),
+            policy_data: Default::default(),
+        };
+
+        let response = reqwest::get(&srv.url("/slow-down")).await.unwrap();
+        assert!(Requester::is_throttling_response(&response));
+
+        requester.throttle(&response).await.unwrap();
+        requester.throttle(&response).await.unwrap();
+        requester.handles.stats.sync().await.unwrap();
+
+        assert_eq!(mock.hits(), 1);
+        assert_eq!(requester.handles.stats.data.throttled(), 1);
+        assert!(requester.rate_limiter.read().await.is_none());
+
+        let start = Instant::now();
+        requester.wait_for_throttle().await;
+        assert!(start.elapsed() >= Duration::from_millis(900));
+
+        // the pause is over, so nothing to wait for
+        let start = Instant::now();
+        requester.wait_for_throttle().await;
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// a rate limit changed from the interactive menu should replace (or remove) the requester's
+    /// rate limiter
+    async fn sync_rate_limit_follows_scope() {
+        let (handles, _) = setup_requester_test(None).await;
+        let mut buckets = leaky_bucket::LeakyBuckets::new();
+        let coordinator = buckets.coordinate().unwrap();
+        tokio::spawn(async move { coordinator.await.expect("coordinator errored") });
+        let limiter = buckets.rate_limiter().max(50).build().unwrap();
+
+        let requester = Requester {
+            handles,
+            seen_links: RwLock::new(HashSet::<String>::new()),
+            tuning_lock: Mutex::new(0),
+            throttled_until: Mutex::new(None),
+            concurrency: None,
+            ferox_scan: Arc::new(FeroxScan::default()),
+            target_url: "http://localhost".to_string(),
+            rate_limiter: RwLock::new(Some(limiter)),
+            scope_rate_limit: AtomicUsize::new(0),
+            policy_data: Default::default(),
+        };
+
+        // nothing changed, limiter is left alone
+        requester.sync_rate_limit().await.unwrap();
+        assert!(requester.rate_limiter.read().await.is_some());
+
+        requester
+            .handles
+            .scope
+            .send(Command::SetRateLimit(50))
+            .unwrap();
+        requester.handles.scope.sync().await.unwrap();
+
+        requester.sync_rate_limit().await.unwrap();
+        assert_eq!(requester.scope_rate_limit.load(Ordering::Relaxed), 50);
+        assert_eq!(
+            requester.rate_limiter.read().await.as_ref().unwrap().max(),
+            50
+        );
+
+        requester
+            .handles
+            .scope
+            .send(Command::SetRateLimit(0))
+            .unwrap();
+        requester.handles.scope.sync().await.unwrap();
+
+        requester.sync_rate_limit().await.unwrap();
+        assert_eq!(requester.scope_rate_limit.load(Ordering::Relaxed), 0);
+        assert!(requester.rate_limiter.read().await.is_none());
+    }
 }
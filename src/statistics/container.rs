@@ -21,6 +21,115 @@ use crate::{
 
 use super::{error::StatError, field::StatField};
 
+/// number of slowest responses tracked for the end-of-scan summary and the JSON stats export
+pub const NUM_SLOWEST_RESPONSES: usize = 10;
+
+/// A single entry in the list of slowest responses seen during a scan
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SlowResponse {
+    /// url that was requested
+    pub url: String,
+
+    /// seconds elapsed between sending the request and receiving the response's headers
+    pub elapsed: f64,
+}
+
+/// smallest response time (in seconds) that isn't lumped into the first bucket of `ResponseTimes`
+const LATENCY_BUCKET_MIN: f64 = 0.001;
+
+/// ratio between the upper bounds of neighbouring buckets in `ResponseTimes`
+const LATENCY_BUCKET_GROWTH: f64 = 1.05;
+
+/// number of buckets in `ResponseTimes`; the last one starts a little past 10 minutes and holds
+/// anything slower
+const NUM_LATENCY_BUCKETS: usize = 275;
+
+/// Fixed-size summary of every recorded response time, used to calculate the mean/p95 latency
+///
+/// The mean is exact. Percentiles come from a histogram whose buckets grow by 5% each, so they
+/// stay within a few percent of the true value without keeping (or sorting) every sample
+#[derive(Default, Debug)]
+struct ResponseTimes {
+    /// number of response times recorded
+    count: usize,
+
+    /// sum of all response times recorded, in seconds
+    total: f64,
+
+    /// slowest response time recorded, in seconds
+    max: f64,
+
+    /// number of response times that fell into each bucket, allocated on first use
+    buckets: Vec<usize>,
+}
+
+/// implementation of ResponseTimes
+impl ResponseTimes {
+    /// record a single response time, in seconds
+    fn add(&mut self, seconds: f64) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; NUM_LATENCY_BUCKETS];
+        }
+
+        self.buckets[Self::bucket(seconds)] += 1;
+        self.count += 1;
+        self.total += seconds;
+        self.max = self.max.max(seconds);
+    }
+
+    /// index of the bucket the given response time falls into; bucket `i` holds times greater
+    /// than `LATENCY_BUCKET_MIN * LATENCY_BUCKET_GROWTH^(i - 1)`, up to and including
+    /// `LATENCY_BUCKET_MIN * LATENCY_BUCKET_GROWTH^i`
+    fn bucket(seconds: f64) -> usize {
+        if seconds.is_nan() || seconds <= LATENCY_BUCKET_MIN {
+            return 0;
+        }
+
+        let index = (seconds / LATENCY_BUCKET_MIN).ln() / LATENCY_BUCKET_GROWTH.ln();
+
+        (index.ceil() as usize).min(NUM_LATENCY_BUCKETS - 1)
+    }
+
+    /// mean of all recorded response times (0.0 when nothing has been recorded)
+    fn mean(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.total / self.count as f64
+    }
+
+    /// estimate of the given percentile (nearest-rank, 0.0 - 1.0) of all recorded response times,
+    /// using the geometric middle of the bucket it falls in (0.0 when nothing has been recorded)
+    fn percentile(&self, percentile: f64) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        let rank = ((self.count as f64 * percentile).ceil() as usize).max(1);
+        let mut seen = 0;
+
+        for (index, count) in self.buckets.iter().enumerate() {
+            seen += count;
+
+            if seen < rank {
+                continue;
+            }
+
+            // the catch-all buckets on either end have no meaningful middle
+            if index == 0 {
+                return LATENCY_BUCKET_MIN.min(self.max);
+            } else if index == NUM_LATENCY_BUCKETS - 1 {
+                return self.max;
+            }
+
+            let middle = LATENCY_BUCKET_MIN * LATENCY_BUCKET_GROWTH.powf(index as f64 - 0.5);
+            return middle.min(self.max);
+        }
+
+        self.max
+    }
+}
+
 /// Data collection of statistics related to a scan
 #[derive(Default, Debug)]
 pub struct Stats {
@@ -108,6 +217,14 @@ pub struct Stats {
     /// tracker for number of files found
     resources_discovered: AtomicUsize,
 
+    /// tracker for number of unique email addresses and comments found when `--collect-intel`
+    /// is used
+    intel_found: AtomicUsize,
+
+    /// tracker for number of times a scan was paused due to a 429/503 when `--auto-throttle`
+    /// is used
+    throttled: AtomicUsize,
+
     /// tracker for number of errors triggered during URL formatting
     url_format_errors: AtomicUsize,
 
@@ -126,6 +243,12 @@ pub struct Stats {
     /// tracker for total runtime
     total_runtime: Mutex<Vec<f64>>,
 
+    /// tracker for each response's elapsed time in seconds, used to calculate the mean/p95 latency
+    response_times: Mutex<ResponseTimes>,
+
+    /// tracker for the `NUM_SLOWEST_RESPONSES` slowest responses seen, slowest first
+    slowest_responses: Mutex<Vec<SlowResponse>>,
+
     /// tracker for the number of extensions the user specified
     num_extensions: usize,
 
@@ -154,7 +277,7 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("Stats", 32)?;
+        let mut state = serializer.serialize_struct("Stats", 37)?;
 
         state.serialize_field("type", &self.kind)?;
         state.serialize_field("timeouts", &atomic_load!(self.timeouts))?;
@@ -185,12 +308,17 @@ impl Serialize for Stats {
             "resources_discovered",
             &atomic_load!(self.resources_discovered),
         )?;
+        state.serialize_field("intel_found", &atomic_load!(self.intel_found))?;
+        state.serialize_field("throttled", &atomic_load!(self.throttled))?;
         state.serialize_field("url_format_errors", &atomic_load!(self.url_format_errors))?;
         state.serialize_field("redirection_errors", &atomic_load!(self.redirection_errors))?;
         state.serialize_field("connection_errors", &atomic_load!(self.connection_errors))?;
         state.serialize_field("request_errors", &atomic_load!(self.request_errors))?;
         state.serialize_field("directory_scan_times", &self.directory_scan_times)?;
         state.serialize_field("total_runtime", &self.total_runtime)?;
+        state.serialize_field("mean_response_time", &self.mean_response_time())?;
+        state.serialize_field("p95_response_time", &self.p95_response_time())?;
+        state.serialize_field("slowest_responses", &self.slowest_responses)?;
 
         state.end()
     }
@@ -384,6 +512,20 @@ impl<'a> Deserialize<'a> for Stats {
                         }
                     }
                 }
+                "intel_found" => {
+                    if let Some(num) = value.as_u64() {
+                        if let Ok(parsed) = usize::try_from(num) {
+                            atomic_increment!(stats.intel_found, parsed);
+                        }
+                    }
+                }
+                "throttled" => {
+                    if let Some(num) = value.as_u64() {
+                        if let Ok(parsed) = usize::try_from(num) {
+                            atomic_increment!(stats.throttled, parsed);
+                        }
+                    }
+                }
                 "url_format_errors" => {
                     if let Some(num) = value.as_u64() {
                         if let Ok(parsed) = usize::try_from(num) {
@@ -434,6 +576,14 @@ impl<'a> Deserialize<'a> for Stats {
                         }
                     }
                 }
+                "slowest_responses" => {
+                    if let Ok(slowest) = serde_json::from_value::<Vec<SlowResponse>>(value.clone())
+                    {
+                        for response in slowest {
+                            stats.add_slow_response(response);
+                        }
+                    }
+                }
                 _ => {}
             }
         }
@@ -466,6 +616,16 @@ impl Stats {
         atomic_load!(self.resources_discovered)
     }
 
+    /// public getter for intel_found
+    pub fn intel_found(&self) -> usize {
+        atomic_load!(self.intel_found)
+    }
+
+    /// public getter for throttled
+    pub fn throttled(&self) -> usize {
+        atomic_load!(self.throttled)
+    }
+
     /// public getter for errors
     pub fn errors(&self) -> usize {
         atomic_load!(self.errors)
@@ -491,6 +651,87 @@ impl Stats {
         atomic_load!(self.initial_targets)
     }
 
+    /// public getter for slowest_responses, slowest first
+    pub fn slowest_responses(&self) -> Vec<SlowResponse> {
+        if let Ok(slowest) = self.slowest_responses.lock() {
+            return slowest.clone();
+        }
+        Vec::new()
+    }
+
+    /// mean of all recorded response times, in seconds (0.0 when nothing has been recorded)
+    pub fn mean_response_time(&self) -> f64 {
+        if let Ok(times) = self.response_times.lock() {
+            return times.mean();
+        }
+        0.0
+    }
+
+    /// 95th percentile (nearest-rank) of all recorded response times, in seconds (0.0 when
+    /// nothing has been recorded); approximate, see `ResponseTimes`
+    pub fn p95_response_time(&self) -> f64 {
+        if let Ok(times) = self.response_times.lock() {
+            return times.percentile(0.95);
+        }
+        0.0
+    }
+
+    /// record the time it took for the given url to respond, keeping track of the slowest
+    /// `NUM_SLOWEST_RESPONSES` responses along the way
+    pub fn add_response_time(&self, url: &str, seconds: f64) {
+        if let Ok(mut times) = self.response_times.lock() {
+            times.add(seconds);
+        }
+
+        self.add_slow_response(SlowResponse {
+            url: url.to_string(),
+            elapsed: seconds,
+        });
+    }
+
+    /// insert the given response into `slowest_responses` if it's slow enough to make the cut
+    fn add_slow_response(&self, response: SlowResponse) {
+        if let Ok(mut slowest) = self.slowest_responses.lock() {
+            if slowest.len() >= NUM_SLOWEST_RESPONSES
+                && slowest
+                    .last()
+                    .map_or(false, |fastest| fastest.elapsed >= response.elapsed)
+            {
+                return;
+            }
+
+            let index = slowest
+                .iter()
+                .position(|known| known.elapsed < response.elapsed)
+                .unwrap_or_else(|| slowest.len());
+
+            slowest.insert(index, response);
+            slowest.truncate(NUM_SLOWEST_RESPONSES);
+        }
+    }
+
+    /// human readable summary of response latency, shown at the end of a scan; empty when no
+    /// response times were recorded
+    pub fn latency_summary(&self) -> String {
+        let slowest = self.slowest_responses();
+
+        if slowest.is_empty() {
+            return String::new();
+        }
+
+        let mut summary = format!(
+            "Response times: mean {:.3}s, p95 {:.3}s\nSlowest responses:\n",
+            self.mean_response_time(),
+            self.p95_response_time()
+        );
+
+        for response in slowest {
+            summary.push_str(&format!("  {:>8.3}s {}\n", response.elapsed, response.url));
+        }
+
+        summary
+    }
+
     /// increment `requests` field by one
     pub fn add_request(&self) {
         atomic_increment!(self.requests);
@@ -644,6 +885,12 @@ impl Stats {
             StatField::ResourcesDiscovered => {
                 atomic_increment!(self.resources_discovered, value);
             }
+            StatField::IntelFound => {
+                atomic_increment!(self.intel_found, value);
+            }
+            StatField::Throttled => {
+                atomic_increment!(self.throttled, value);
+            }
             StatField::InitialTargets => {
                 atomic_increment!(self.initial_targets, value);
             }
@@ -692,6 +939,8 @@ impl Stats {
                 self.resources_discovered,
                 atomic_load!(d_stats.resources_discovered)
             );
+            atomic_increment!(self.intel_found, atomic_load!(d_stats.intel_found));
+            atomic_increment!(self.throttled, atomic_load!(d_stats.throttled));
             atomic_increment!(
                 self.url_format_errors,
                 atomic_load!(d_stats.url_format_errors)
@@ -711,6 +960,11 @@ impl Stats {
                     self.update_f64_field(StatField::DirScanTimes, *scan_time);
                 }
             };
+
+            // individual response times aren't saved, so only the slowest responses carry over
+            for response in d_stats.slowest_responses() {
+                self.add_slow_response(response);
+            }
         }
         Ok(())
     }
@@ -889,6 +1143,112 @@ mod tests {
         assert!((stats.total_runtime.lock().unwrap()[0] - 0.0).abs() < f64::EPSILON);
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// when sent Command::AddResponseTime, stats object should reflect the change
+    async fn statistics_handler_records_response_times() -> Result<()> {
+        let (task, handle) = setup_stats_test();
+
+        handle
+            .tx
+            .send(Command::AddResponseTime("http://localhost/a".into(), 0.5))?;
+        handle
+            .tx
+            .send(Command::AddResponseTime("http://localhost/b".into(), 1.5))?;
+
+        teardown_stats_test(handle.tx.clone(), task).await;
+
+        assert!((handle.data.mean_response_time() - 1.0).abs() < f64::EPSILON);
+        assert_eq!(handle.data.slowest_responses()[0].url, "http://localhost/b");
+
+        Ok(())
+    }
+
+    #[test]
+    /// only the NUM_SLOWEST_RESPONSES slowest responses should be kept, slowest first, while
+    /// the mean/p95 account for every response (p95 within the histogram's precision)
+    fn stats_tracks_slowest_responses_and_latency_summary() {
+        let stats = Stats::new(0, false);
+
+        assert!(stats.latency_summary().is_empty());
+        assert!((stats.p95_response_time() - 0.0).abs() < f64::EPSILON);
+
+        for i in 1..=100 {
+            stats.add_response_time(&format!("http://localhost/{}", i), i as f64 / 100.0);
+        }
+
+        let slowest = stats.slowest_responses();
+        assert_eq!(slowest.len(), NUM_SLOWEST_RESPONSES);
+        assert_eq!(slowest[0].url, "http://localhost/100");
+        assert_eq!(slowest[9].url, "http://localhost/91");
+
+        assert!((stats.mean_response_time() - 0.505).abs() < 0.0001);
+        assert!((stats.p95_response_time() - 0.95).abs() < 0.95 * 0.025);
+
+        let summary = stats.latency_summary();
+        assert!(summary.contains("mean 0.505s, p95 0.949s"));
+        assert!(summary.contains("1.000s http://localhost/100"));
+
+        let json = stats.as_json().unwrap();
+        assert!(json.contains(r#""p95_response_time":0.9"#));
+        assert!(
+            json.contains(r#""slowest_responses":[{"url":"http://localhost/100","elapsed":1.0}"#)
+        );
+    }
+
+    #[test]
+    /// ResponseTimes should stay the same size no matter how many times are recorded, and handle
+    /// times that fall outside of its buckets' range
+    fn response_times_are_bounded() {
+        let mut times = ResponseTimes::default();
+        assert!(times.percentile(0.95).abs() < f64::EPSILON);
+
+        for i in 0..100_000 {
+            times.add(0.2 + (i % 10) as f64 / 1000.0);
+        }
+
+        assert_eq!(times.buckets.len(), NUM_LATENCY_BUCKETS);
+        assert_eq!(times.count, 100_000);
+        assert!((times.mean() - 0.2045).abs() < 0.0001);
+        assert!((times.percentile(0.95) - 0.209).abs() < 0.209 * 0.025);
+
+        let mut fast = ResponseTimes::default();
+        fast.add(0.0002);
+        assert!((fast.percentile(0.95) - 0.0002).abs() < f64::EPSILON);
+
+        let mut slow = ResponseTimes::default();
+        slow.add(1200.0);
+        assert_eq!(ResponseTimes::bucket(1200.0), NUM_LATENCY_BUCKETS - 1);
+        assert!((slow.percentile(0.95) - 1200.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    /// Stats::merge_from should carry over previously seen slow responses
+    fn stats_merge_from_keeps_slowest_responses() {
+        let contents = r#"{"statistics":{"type":"statistics","mean_response_time":2.0,"p95_response_time":3.0,"slowest_responses":[{"url":"http://localhost/slow","elapsed":3.0},{"url":"http://localhost/meh","elapsed":1.0}]}}"#;
+        let stats = Stats::new(0, false);
+        stats.add_response_time("http://localhost/now", 2.0);
+
+        let tfile = NamedTempFile::new().unwrap();
+        write(&tfile, contents).unwrap();
+
+        stats.merge_from(tfile.path().to_str().unwrap()).unwrap();
+
+        let urls: Vec<_> = stats
+            .slowest_responses()
+            .into_iter()
+            .map(|response| response.url)
+            .collect();
+
+        assert_eq!(
+            urls,
+            vec![
+                "http://localhost/slow",
+                "http://localhost/now",
+                "http://localhost/meh"
+            ]
+        );
+    }
+
     #[test]
     /// ensure update runtime overwrites the default 0th entry
     fn update_runtime_works() {
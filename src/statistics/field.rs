@@ -25,6 +25,12 @@ pub enum StatField {
     /// Translates to `resources_discovered`
     ResourcesDiscovered,
 
+    /// Translates to `intel_found`
+    IntelFound,
+
+    /// Translates to `throttled`
+    Throttled,
+
     /// Translates to `initial_targets`
     InitialTargets,
 
@@ -5,7 +5,7 @@ mod field;
 #[cfg(test)]
 mod tests;
 
-pub use self::container::Stats;
+pub use self::container::{SlowResponse, Stats, NUM_SLOWEST_RESPONSES};
 pub use self::error::StatError;
 pub use self::field::StatField;
 
@@ -38,25 +38,35 @@ impl FeroxUrl {
     /// If any extensions were passed to the program, each extension will add a
     /// (base_url + word + ext) Url to the vector
     pub fn formatted_urls(&self, word: &str) -> Result<Vec<Url>> {
-        log::trace!("enter: formatted_urls({})", word);
+        Ok(self
+            .formatted_urls_with_extensions(word)?
+            .into_iter()
+            .map(|(url, _)| url)
+            .collect())
+    }
+
+    /// Same as `formatted_urls`, but each `Url` is paired with the extension (if any) that was
+    /// appended to `word` to create it
+    pub fn formatted_urls_with_extensions(&self, word: &str) -> Result<Vec<(Url, Option<String>)>> {
+        log::trace!("enter: formatted_urls_with_extensions({})", word);
 
         let mut urls = vec![];
 
         match self.format(word, None) {
             // default request, i.e. no extension
-            Ok(url) => urls.push(url),
+            Ok(url) => urls.push((url, None)),
             Err(_) => self.handles.stats.send(AddError(UrlFormat))?,
         }
 
-        for ext in self.handles.config.extensions.iter() {
+        for ext in self.handles.scope.data.extensions().iter() {
             match self.format(word, Some(ext)) {
                 // any extensions passed in
-                Ok(url) => urls.push(url),
+                Ok(url) => urls.push((url, Some(ext.to_owned()))),
                 Err(_) => self.handles.stats.send(AddError(UrlFormat))?,
             }
         }
 
-        log::trace!("exit: formatted_urls -> {:?}", urls);
+        log::trace!("exit: formatted_urls_with_extensions -> {:?}", urls);
         Ok(urls)
     }
 
@@ -103,7 +113,7 @@ impl FeroxUrl {
         } else if self.handles.config.add_slash && !word.ends_with('/') {
             // -f used, and word doesn't already end with a /
             format!("{}/", word)
-        } else if word.starts_with("//") {
+        } else if word.starts_with('/') {
             // bug ID'd by @Sicks3c, when a wordlist contains words that begin with 2 forward slashes
             // i.e. //1_40_0/static/js, it gets joined onto the base url in a surprising way
             // ex: https://localhost/ + //1_40_0/static/js -> https://1_40_0/static/js
@@ -111,6 +121,11 @@ impl FeroxUrl {
             // and simply removes prefixed forward slashes if there are two of them. Additionally,
             // trim_start_matches will trim the pattern until it's gone, so even if there are more than
             // 2 /'s, they'll still be trimmed
+            //
+            // a single leading slash has a similar problem: multi-segment words like /api/v1/users
+            // are absolute paths as far as join is concerned, and would replace the base url's
+            // path entirely, ex: https://localhost/admin/ + /api/v1 -> https://localhost/api/v1
+            // the internal slashes of a word are left as-is, they're joined without being encoded
             word.trim_start_matches('/').to_string()
         } else {
             String::from(word)
@@ -124,13 +139,40 @@ impl FeroxUrl {
             log::trace!("exit: format -> {}", joined);
             Ok(joined)
         } else {
-            let with_params =
-                Url::parse_with_params(joined.as_str(), &self.handles.config.queries)?;
+            let with_params = self.merge_queries(joined);
             log::trace!("exit: format_url -> {}", with_params);
             Ok(with_params) // request with params attached
         }
     }
 
+    /// Merge the user's query parameters (-Q/--query) into the given url's query string
+    ///
+    /// parameters already on the url (i.e. an extracted link such as `/search?page=2`) are kept;
+    /// when one shares its name with a user supplied parameter, the user's value wins, so that
+    /// things like session tokens aren't sent twice
+    fn merge_queries(&self, mut url: Url) -> Url {
+        let queries = &self.handles.config.queries;
+
+        let collides = url
+            .query_pairs()
+            .any(|(name, _)| queries.iter().any(|(query, _)| *query == name));
+
+        if collides {
+            // only rebuild the existing query string when necessary, as serializing it again can
+            // change its encoding
+            let kept: Vec<(String, String)> = url
+                .query_pairs()
+                .filter(|(name, _)| !queries.iter().any(|(query, _)| *query == *name))
+                .map(|(name, value)| (name.into_owned(), value.into_owned()))
+                .collect();
+
+            url.query_pairs_mut().clear().extend_pairs(kept);
+        }
+
+        url.query_pairs_mut().extend_pairs(queries);
+        url
+    }
+
     /// Gets the length of a url's path
     pub fn path_length(&self) -> Result<u64> {
         let parsed = Url::parse(&self.target)?;
@@ -172,6 +214,89 @@ impl FeroxUrl {
         0
     }
 
+    /// Rewrite the given url into a canonical form, so that functionally identical urls compare
+    /// equal when deduplicating
+    ///
+    /// - percent-encoded unreserved characters are decoded (`/%7Eadmin` -> `/~admin`)
+    /// - remaining percent-encodings use uppercase hex digits (`%2f` -> `%2F`)
+    /// - consecutive slashes in the path are collapsed (`/a//b` -> `/a/b`)
+    /// - default ports are dropped (`http://localhost:80/` -> `http://localhost/`)
+    ///
+    /// encoded slashes (`%2F`) are only decoded when `decode_slashes` is true, as some servers
+    /// treat `/a%2Fb` differently than `/a/b`
+    pub fn canonicalize_url(url: &Url, decode_slashes: bool) -> Url {
+        log::trace!("enter: canonicalize_url({}, {})", url, decode_slashes);
+
+        let mut canonical = url.clone();
+
+        if canonical.cannot_be_a_base() {
+            log::trace!("exit: canonicalize_url -> {}", canonical);
+            return canonical;
+        }
+
+        // default ports are dropped by the url crate during parsing, so only the path needs work
+        let path = canonical.path().as_bytes();
+        let mut decoded = String::with_capacity(path.len());
+        let mut i = 0;
+
+        while i < path.len() {
+            let mut next = path[i] as char;
+            i += 1;
+
+            if next == '%' && i + 2 <= path.len() {
+                let hex = &canonical.path()[i..i + 2];
+
+                if hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    let byte = u8::from_str_radix(hex, 16).unwrap_or_default();
+                    i += 2;
+
+                    if is_unreserved(byte) || (decode_slashes && byte == b'/') {
+                        next = byte as char;
+                    } else {
+                        decoded.push('%');
+                        decoded.push_str(&hex.to_uppercase());
+                        continue;
+                    }
+                }
+            }
+
+            if next == '/' && decoded.ends_with('/') {
+                // collapse consecutive slashes
+                continue;
+            }
+
+            decoded.push(next);
+        }
+
+        canonical.set_path(&decoded);
+
+        log::trace!("exit: canonicalize_url -> {}", canonical);
+        canonical
+    }
+
+    /// Reduce the given url to the form used when deciding whether two scans are duplicates
+    ///
+    /// the url is canonicalized (see `canonicalize_url`, encoded slashes are left alone) and,
+    /// when `is_directory` is true, its trailing slash is dropped, so that `http://localhost/a`
+    /// and `http://localhost/a/` are the same directory. Files keep their trailing slash, as
+    /// `/a` and `/a/` may well be different resources. Urls that can't be parsed are returned
+    /// as-is.
+    pub fn normalize_url(url: &str, is_directory: bool) -> String {
+        let parsed = match Url::parse(url) {
+            Ok(parsed) => parsed,
+            Err(_) => return url.to_string(),
+        };
+
+        let mut canonical = Self::canonicalize_url(&parsed, false);
+
+        if is_directory && canonical.path().len() > 1 && canonical.path().ends_with('/') {
+            let trimmed = canonical.path().trim_end_matches('/').to_string();
+            canonical.set_path(&trimmed);
+        }
+
+        canonical.to_string()
+    }
+
     /// Simple helper to abstract away adding a forward-slash to a url if not present
     ///
     /// used mostly for deduplication purposes and url state tracking
@@ -221,6 +346,12 @@ impl FeroxUrl {
     }
 }
 
+/// whether or not the given byte is an unreserved character, per RFC 3986 section 2.3; these
+/// characters mean the same thing whether they're percent-encoded or not
+fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~')
+}
+
 /// Display implementation for a FeroxUrl
 impl fmt::Display for FeroxUrl {
     /// formatter for FeroxUrl
@@ -232,7 +363,10 @@ impl fmt::Display for FeroxUrl {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::Configuration;
+    use crate::{
+        config::Configuration,
+        event_handlers::{Command, ScopeHandler},
+    };
 
     #[test]
     /// sending url + word without any extensions should get back one url with the joined word
@@ -264,6 +398,37 @@ mod tests {
         )
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// extensions added from the interactive menu should be used for urls formatted afterwards
+    async fn formatted_urls_uses_extensions_added_mid_scan() {
+        let config = Arc::new(Configuration {
+            extensions: vec![String::from("js")],
+            ..Default::default()
+        });
+
+        let (mut handles, _) = Handles::for_testing(None, Some(config.clone()));
+        let (_task, scope) = ScopeHandler::initialize(config);
+        handles.scope = scope;
+
+        handles
+            .scope
+            .send(Command::AddExtensions(vec![String::from("bak")]))
+            .unwrap();
+        handles.scope.sync().await.unwrap();
+
+        let url = FeroxUrl::from_string("http://localhost", Arc::new(handles));
+        let urls = url.formatted_urls("turbo").unwrap();
+
+        assert_eq!(
+            urls,
+            [
+                Url::parse("http://localhost/turbo").unwrap(),
+                Url::parse("http://localhost/turbo.js").unwrap(),
+                Url::parse("http://localhost/turbo.bak").unwrap()
+            ]
+        )
+    }
+
     #[test]
     /// sending url + word + multiple extensions should get back n+1 urls
     fn formatted_urls_multiple_extensions_returns_n_plus_one_urls() {
@@ -345,6 +510,32 @@ mod tests {
         assert_eq!(depth, 2);
     }
 
+    #[test]
+    /// bracketed ipv6 hosts shouldn't affect the depth of a url
+    fn depth_ipv6_base_url() {
+        let handles = Arc::new(Handles::for_testing(None, None).0);
+
+        let url = FeroxUrl::from_string("http://[::1]:8080", handles.clone());
+        assert_eq!(url.depth().unwrap(), 1);
+
+        let url = FeroxUrl::from_string("http://[::1]:8080/src/", handles);
+        assert_eq!(url.depth().unwrap(), 2);
+    }
+
+    #[test]
+    /// words should be joined onto the path of an ipv6 target, leaving the host and port alone
+    fn format_url_ipv6_base_url() {
+        let handles = Arc::new(Handles::for_testing(None, None).0);
+        let url = FeroxUrl::from_string("http://[::1]:8080/api", handles);
+
+        let formatted = url.format("stuff", Some("php")).unwrap();
+        assert_eq!(formatted.as_str(), "http://[::1]:8080/api/stuff.php");
+        assert_eq!(
+            formatted.host(),
+            Some(reqwest::Url::parse("http://[::1]").unwrap().host().unwrap())
+        );
+    }
+
     #[test]
     /// base url + 1 word + no slash + no extension
     fn format_url_normal() {
@@ -403,6 +594,43 @@ mod tests {
         );
     }
 
+    #[test]
+    /// base url w/ its own query + no word + queries; existing params should be kept
+    fn format_url_merges_queries_with_existing_ones() {
+        let config = Configuration {
+            queries: vec![(String::from("token"), String::from("stuff"))],
+            ..Default::default()
+        };
+
+        let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+        let url = FeroxUrl::from_string("http://localhost/search?page=2", handles);
+        let formatted = url.format("", None).unwrap();
+
+        assert_eq!(
+            formatted,
+            reqwest::Url::parse("http://localhost/search?page=2&token=stuff").unwrap()
+        );
+    }
+
+    #[test]
+    /// a param that's already on the url and also passed via --query should only be sent once,
+    /// w/ the user's value
+    fn format_url_queries_replace_params_of_the_same_name() {
+        let config = Configuration {
+            queries: vec![(String::from("token"), String::from("new"))],
+            ..Default::default()
+        };
+
+        let handles = Arc::new(Handles::for_testing(None, Some(Arc::new(config))).0);
+        let url = FeroxUrl::from_string("http://localhost/search?token=old&page=2", handles);
+        let formatted = url.format("", None).unwrap();
+
+        assert_eq!(
+            formatted,
+            reqwest::Url::parse("http://localhost/search?page=2&token=new").unwrap()
+        );
+    }
+
     #[test]
     #[should_panic]
     /// no base url is an error
@@ -438,6 +666,31 @@ mod tests {
         );
     }
 
+    #[test]
+    /// multi-segment words keep their internal slashes, with or without a slash on the base url
+    fn format_url_multi_segment_word() {
+        let handles = Arc::new(Handles::for_testing(None, None).0);
+
+        for target in &["http://localhost/admin", "http://localhost/admin/"] {
+            let url = FeroxUrl::from_string(target, handles.clone());
+
+            let formatted = url.format("api/v1/users", None).unwrap();
+            assert_eq!(formatted.as_str(), "http://localhost/admin/api/v1/users");
+
+            let formatted = url.format("/api/v1/users", None).unwrap();
+            assert_eq!(formatted.as_str(), "http://localhost/admin/api/v1/users");
+
+            let formatted = url.format("api/v1/users", Some("php")).unwrap();
+            assert_eq!(
+                formatted.as_str(),
+                "http://localhost/admin/api/v1/users.php"
+            );
+
+            let formatted = url.format("api%2Fv1/users", None).unwrap();
+            assert_eq!(formatted.as_str(), "http://localhost/admin/api%2Fv1/users");
+        }
+    }
+
     #[test]
     /// word with two prepended slashes doesn't discard the entire domain
     fn format_url_word_with_two_prepended_slashes() {
@@ -460,4 +713,92 @@ mod tests {
 
         assert!(formatted.is_err());
     }
+
+    #[test]
+    /// directories should lose their trailing slash, files shouldn't
+    fn normalize_url_is_directory_aware() {
+        assert_eq!(
+            FeroxUrl::normalize_url("http://LOCALHOST:80/a//b/", true),
+            "http://localhost/a/b"
+        );
+        assert_eq!(
+            FeroxUrl::normalize_url("http://localhost/a/b", true),
+            "http://localhost/a/b"
+        );
+        assert_eq!(
+            FeroxUrl::normalize_url("http://localhost/a/b/", false),
+            "http://localhost/a/b/"
+        );
+        assert_eq!(
+            FeroxUrl::normalize_url("http://localhost", true),
+            "http://localhost/"
+        );
+        assert_eq!(FeroxUrl::normalize_url("not a url", true), "not a url");
+    }
+
+    #[test]
+    /// duplicate slashes collapse, unreserved characters are decoded, and everything else keeps
+    /// its encoding w/ uppercase hex digits
+    fn canonicalize_url_collapses_and_decodes() {
+        let canonicalize = |url: &str, decode_slashes: bool| {
+            FeroxUrl::canonicalize_url(&Url::parse(url).unwrap(), decode_slashes).to_string()
+        };
+
+        assert_eq!(
+            canonicalize("http://localhost/a//b", false),
+            "http://localhost/a/b"
+        );
+        assert_eq!(
+            canonicalize("http://localhost//a///b/", false),
+            "http://localhost/a/b/"
+        );
+        assert_eq!(
+            canonicalize("http://localhost/%7Eadm%69n", false),
+            "http://localhost/~admin"
+        );
+        assert_eq!(
+            canonicalize("http://localhost/a%2fb", false),
+            "http://localhost/a%2Fb"
+        );
+        assert_eq!(
+            canonicalize("http://localhost/a%20b", false),
+            "http://localhost/a%20b"
+        );
+        assert_eq!(
+            canonicalize("http://localhost/100%", false),
+            "http://localhost/100%"
+        );
+        assert_eq!(
+            canonicalize("http://localhost:80/a", false),
+            "http://localhost/a"
+        );
+        assert_eq!(
+            canonicalize("https://localhost:443/a", false),
+            "https://localhost/a"
+        );
+        assert_eq!(
+            canonicalize("http://localhost:8080/a", false),
+            "http://localhost:8080/a"
+        );
+        assert_eq!(
+            canonicalize("http://localhost/a//b?x=//y", false),
+            "http://localhost/a/b?x=//y"
+        );
+    }
+
+    #[test]
+    /// encoded slashes are only decoded when asked to, and collapse like any other slash once
+    /// they are
+    fn canonicalize_url_decodes_slashes_when_asked() {
+        let url = Url::parse("http://localhost/a%2Fb/%2F%2Fc").unwrap();
+
+        assert_eq!(
+            FeroxUrl::canonicalize_url(&url, false).as_str(),
+            "http://localhost/a%2Fb/%2F%2Fc"
+        );
+        assert_eq!(
+            FeroxUrl::canonicalize_url(&url, true).as_str(),
+            "http://localhost/a/b/c"
+        );
+    }
 }
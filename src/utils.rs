@@ -1,20 +1,23 @@
 use anyhow::{bail, Context, Result};
 use console::{strip_ansi_codes, style, user_attended};
 use indicatif::ProgressBar;
-use reqwest::{Client, Response, StatusCode, Url};
+use lazy_static::lazy_static;
+use reqwest::{Client, RequestBuilder, Response, StatusCode, Url};
 #[cfg(not(target_os = "windows"))]
 use rlimit::{getrlimit, setrlimit, Resource, Rlim};
 use std::{
+    collections::HashSet,
     fs,
     io::{self, BufWriter, Write},
-    sync::Arc,
+    sync::{Arc, RwLock},
+    time::Instant,
 };
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
     config::OutputLevel,
     event_handlers::{
-        Command::{self, AddError, AddStatus},
+        Command::{self, AddError, AddResponseTime, AddStatus},
         Handles,
     },
     progress::PROGRESS_PRINTER,
@@ -23,6 +26,36 @@ use crate::{
     traits::FeroxSerialize,
 };
 
+/// Function called with every request right before it's sent; see `set_request_hook`
+pub type RequestHook = dyn Fn(&Url, RequestBuilder) -> RequestBuilder + Send + Sync;
+
+lazy_static! {
+    /// hook registered by an application embedding feroxbuster, if any
+    static ref REQUEST_HOOK: RwLock<Option<Arc<RequestHook>>> = RwLock::new(None);
+}
+
+/// Register a hook that gets a chance to modify each request just before it's sent
+///
+/// The hook receives the url being requested along with the `RequestBuilder` for it and returns
+/// the builder that should be sent, i.e. to add a signature, a rotating CSRF token, or a
+/// per-request nonce that can't be expressed w/ static headers. Only one hook can be registered,
+/// registering another replaces the first.
+pub fn set_request_hook<F>(hook: F)
+where
+    F: Fn(&Url, RequestBuilder) -> RequestBuilder + Send + Sync + 'static,
+{
+    if let Ok(mut guard) = REQUEST_HOOK.write() {
+        *guard = Some(Arc::new(hook));
+    }
+}
+
+/// Remove the hook registered with `set_request_hook`, if any
+pub fn clear_request_hook() {
+    if let Ok(mut guard) = REQUEST_HOOK.write() {
+        *guard = None;
+    }
+}
+
 /// Given the path to a file, open the file in append mode (create it if it doesn't exist) and
 /// return a reference to the buffered file
 pub fn open_file(filename: &str) -> Result<BufWriter<fs::File>> {
@@ -128,7 +161,18 @@ pub async fn make_request(
         tx_stats
     );
 
-    match client.get(url.to_owned()).send().await {
+    let mut request = client.get(url.to_owned());
+
+    // clone the hook out of the lock so it isn't held across the .await below
+    let hook = REQUEST_HOOK.read().ok().and_then(|guard| guard.clone());
+
+    if let Some(hook) = hook {
+        request = hook(url, request);
+    }
+
+    let start = Instant::now();
+
+    match request.send().await {
         Err(e) => {
             log::trace!("exit: make_request -> {}", e);
 
@@ -170,6 +214,10 @@ pub async fn make_request(
         }
         Ok(resp) => {
             log::trace!("exit: make_request -> {:?}", resp);
+            send_command!(
+                tx_stats,
+                AddResponseTime(url.to_string(), start.elapsed().as_secs_f64())
+            );
             send_command!(tx_stats, AddStatus(resp.status()));
             Ok(resp)
         }
@@ -287,6 +335,14 @@ where
     Ok(())
 }
 
+/// Given a csv row (or header) and a reference to a locked buffered file, write the row and
+/// flush the buffer to disk (--output-format csv)
+pub fn write_csv_row(row: &str, file: &mut io::BufWriter<fs::File>) -> Result<()> {
+    file.write_all(row.as_bytes())?;
+    file.flush()?;
+    Ok(())
+}
+
 /// determines whether or not a given url should be denied based on the user-supplied --dont-scan
 /// flag
 pub fn should_deny_url(url: &Url, handles: Arc<Handles>) -> Result<bool> {
@@ -296,14 +352,23 @@ pub fn should_deny_url(url: &Url, handles: Arc<Handles>) -> Result<bool> {
         handles.config.url_denylist,
         handles.ferox_scans()?
     );
+    if handles.config.dont_scan_globs.is_match(url.path()) {
+        // --dont-scan values that aren't urls are globs matched against the path, ex: *.png
+        log::trace!("exit: should_deny_url -> true");
+        return Ok(true);
+    }
+
     // normalization for comparison is to remove the trailing / if one exists, this is done for
     // the given url and any url to which it's compared
     let normed_url = Url::parse(&url.to_string().trim_end_matches('/'))?;
 
     for deny_url in &handles.config.url_denylist {
-        // parse the denying url for easier comparison
-        let denier = Url::parse(deny_url.trim_end_matches('/'))
-            .with_context(|| format!("Could not parse {} as a url", deny_url))?;
+        // parse the denying url for easier comparison; anything that isn't a url is a glob,
+        // which was already checked above
+        let denier = match Url::parse(deny_url.trim_end_matches('/')) {
+            Ok(denier) => denier,
+            Err(_) => continue,
+        };
 
         // simplest case is an exact match, check for it first
         if normed_url == denier {
@@ -385,11 +450,67 @@ pub fn should_deny_url(url: &Url, handles: Arc<Handles>) -> Result<bool> {
     Ok(false)
 }
 
+/// expand each of the given words into the case variations requested by --transform-case
+///
+/// the original word is always kept, and the resulting list is deduplicated while preserving the
+/// order in which words were first seen (i.e. `admin` won't be requested twice)
+///
+/// valid transforms are `lower`, `upper`, `capitalize`, and `all`
+pub fn transform_case(words: Vec<String>, transforms: &[String]) -> Vec<String> {
+    log::trace!(
+        "enter: transform_case(wordlist[{} words...], {:?})",
+        words.len(),
+        transforms
+    );
+
+    let all = transforms.iter().any(|t| t == "all");
+    let lower = all || transforms.iter().any(|t| t == "lower");
+    let upper = all || transforms.iter().any(|t| t == "upper");
+    let capitalize = all || transforms.iter().any(|t| t == "capitalize");
+
+    let mut seen = HashSet::new();
+    let mut transformed = Vec::with_capacity(words.len());
+
+    for word in words {
+        let mut variants = Vec::with_capacity(4);
+
+        if lower {
+            variants.push(word.to_lowercase());
+        }
+
+        if upper {
+            variants.push(word.to_uppercase());
+        }
+
+        if capitalize {
+            let mut chars = word.chars();
+
+            if let Some(first) = chars.next() {
+                let rest = chars.as_str().to_lowercase();
+                variants.push(format!("{}{}", first.to_uppercase(), rest));
+            }
+        }
+
+        for candidate in std::iter::once(word).chain(variants) {
+            if seen.insert(candidate.clone()) {
+                transformed.push(candidate);
+            }
+        }
+    }
+
+    log::trace!(
+        "exit: transform_case -> wordlist[{} words...]",
+        transformed.len()
+    );
+    transformed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::Configuration;
     use crate::scan_manager::{FeroxScans, ScanOrder};
+    use httpmock::{Method::GET, MockServer};
 
     #[test]
     /// set_open_file_limit with a low requested limit succeeds
@@ -467,6 +588,38 @@ mod tests {
         assert_eq!(status_colorizer("farfignewton"), "farfignewton".to_string());
     }
 
+    #[test]
+    /// transform_case should keep the original word, add the requested variants, and dedupe
+    fn transform_case_expands_and_dedupes_words() {
+        let words = vec![
+            "admin".to_string(),
+            "Admin".to_string(),
+            "loGin".to_string(),
+        ];
+
+        let upper = transform_case(words.clone(), &["upper".to_string()]);
+        assert_eq!(upper, vec!["admin", "ADMIN", "Admin", "loGin", "LOGIN"]);
+
+        let lower = transform_case(words.clone(), &["lower".to_string()]);
+        assert_eq!(lower, vec!["admin", "Admin", "loGin", "login"]);
+
+        let capitalize = transform_case(words.clone(), &["capitalize".to_string()]);
+        assert_eq!(capitalize, vec!["admin", "Admin", "loGin", "Login"]);
+
+        let all = transform_case(words, &["all".to_string()]);
+        assert_eq!(
+            all,
+            vec!["admin", "ADMIN", "Admin", "loGin", "login", "LOGIN", "Login"]
+        );
+    }
+
+    #[test]
+    /// transform_case with no transforms should only dedupe the words
+    fn transform_case_without_transforms_only_dedupes() {
+        let words = vec!["admin".to_string(), "admin".to_string()];
+        assert_eq!(transform_case(words, &[]), vec!["admin"]);
+    }
+
     #[test]
     /// provide a url that should be blocked where the denier is an exact match for the tested url
     /// expect true
@@ -487,6 +640,36 @@ mod tests {
         assert!(should_deny_url(&tested_url, handles).unwrap());
     }
 
+    #[test]
+    /// --dont-scan values that aren't urls are globs matched against the url's path, expect true
+    /// for a matching path and false otherwise
+    fn should_deny_url_blocks_glob_matches() {
+        let scan_url = "https://testdomain.com/";
+
+        let scans = Arc::new(FeroxScans::default());
+        scans.add_directory_scan(&scan_url, ScanOrder::Initial);
+
+        let mut builder = globset::GlobSetBuilder::new();
+        builder.add(globset::Glob::new("**/*.woff").unwrap());
+        builder.add(globset::Glob::new("/cdn/**").unwrap());
+
+        let mut config = Configuration::new().unwrap();
+        config.url_denylist = vec![String::from("*.woff"), String::from("/cdn/**")];
+        config.dont_scan_globs = builder.build().unwrap();
+        let config = Arc::new(config);
+
+        let handles = Arc::new(Handles::for_testing(Some(scans), Some(config)).0);
+
+        let denied = Url::parse("https://testdomain.com/fonts/font.woff").unwrap();
+        assert!(should_deny_url(&denied, handles.clone()).unwrap());
+
+        let denied = Url::parse("https://testdomain.com/cdn/lib.js").unwrap();
+        assert!(should_deny_url(&denied, handles.clone()).unwrap());
+
+        let allowed = Url::parse("https://testdomain.com/fonts/").unwrap();
+        assert!(!should_deny_url(&allowed, handles).unwrap());
+    }
+
     #[test]
     /// provide a url that has a different host than the denier but the same path, expect false
     fn should_deny_url_doesnt_compare_mismatched_domains() {
@@ -642,4 +825,52 @@ mod tests {
 
         assert!(!should_deny_url(&tested_url, handles).unwrap());
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    /// a registered request hook should be able to add values computed at request time, and
+    /// requests should go out unmodified once it's cleared
+    async fn make_request_routes_requests_through_request_hook() {
+        let srv = MockServer::start();
+        let (tx_stats, _rx_stats) = tokio::sync::mpsc::unbounded_channel::<Command>();
+
+        let hooked = srv.mock(|when, then| {
+            when.method(GET)
+                .path("/request-hook")
+                .header("x-nonce", "1")
+                .query_param("sig", "/request-hook");
+            then.status(200);
+        });
+
+        let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let hook_counter = counter.clone();
+
+        set_request_hook(move |url, request| {
+            if url.path() != "/request-hook" {
+                // other tests make requests at the same time, leave theirs alone
+                return request;
+            }
+            let nonce = hook_counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            request
+                .header("x-nonce", nonce.to_string())
+                .query(&[("sig", url.path())])
+        });
+
+        let client = Client::new();
+        let url = Url::parse(&srv.url("/request-hook")).unwrap();
+
+        let response = make_request(&client, &url, OutputLevel::Default, tx_stats.clone())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        clear_request_hook();
+
+        let response = make_request(&client, &url, OutputLevel::Default, tx_stats)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        assert_eq!(hooked.hits(), 1);
+        assert_eq!(counter.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
 }
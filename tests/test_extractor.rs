@@ -402,6 +402,8 @@ fn extractor_recurses_into_403_directories() -> Result<(), Box<dyn std::error::E
         .arg("--extract-links")
         .arg("--depth") // need to go past default 4 directories
         .arg("0")
+        .arg("--max-requests-per-dir") // required alongside unlimited recursion
+        .arg("1000")
         .unwrap();
 
     cmd.assert().success().stdout(
@@ -420,3 +422,101 @@ fn extractor_recurses_into_403_directories() -> Result<(), Box<dyn std::error::E
     teardown_tmp_directory(tmp_dir);
     Ok(())
 }
+
+#[test]
+/// --collect-intel should report an email address and an html comment once, even when they're
+/// found in multiple responses, and should never request either of them
+fn extractor_collects_intel_once() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "about".to_string()], "wordlist")?;
+
+    let body =
+        "<html><!-- TODO: remove\n admin backdoor --><p>contact admin@localhost.com</p></html>";
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200).body(body);
+    });
+
+    let mock_two = srv.mock(|when, then| {
+        when.method(GET).path("/about");
+        then.status(200).body(body);
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--extract-links")
+        .arg("--collect-intel")
+        .unwrap();
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("INT")
+            .count(2)
+            .and(predicate::str::contains("admin@localhost.com").count(1))
+            .and(predicate::str::contains("TODO: remove admin backdoor").count(1)),
+    );
+
+    assert_eq!(mock.hits(), 1);
+    assert_eq!(mock_two.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-intel combined with --json should write intel entries to the output file
+fn extractor_collects_intel_as_json() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+    let outfile = tmp_dir.path().join("output");
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path("/LICENSE");
+        then.status(200)
+            .body("<p>questions? ask admin@localhost.com</p>");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--extract-links")
+        .arg("--collect-intel")
+        .arg("--json")
+        .arg("--output")
+        .arg(outfile.as_os_str())
+        .unwrap();
+
+    let contents = std::fs::read_to_string(outfile)?;
+
+    assert!(contents
+        .contains(r#"{"type":"intel","kind":"email","value":"admin@localhost.com","url":""#));
+    assert_eq!(mock.hits(), 1);
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --collect-intel without --extract-links should fail
+fn extractor_collect_intel_requires_extract_links() -> Result<(), Box<dyn std::error::Error>> {
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg("http://localhost")
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--collect-intel")
+        .assert()
+        .failure();
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
@@ -242,6 +242,74 @@ fn test_dynamic_wildcard_request_found() {
     assert_eq!(mock2.hits(), 1);
 }
 
+#[test]
+/// a target that serves anything should get a warning before it's scanned, which mentions how to
+/// filter the catch-all responses
+fn heuristics_catch_all_target_prints_warning() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("^/[a-zA-Z0-9]{32}$").unwrap());
+        then.status(200).body("catch all");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .unwrap();
+
+    teardown_tmp_directory(tmp_dir);
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("WLD")
+            .and(predicate::str::contains("WARNING"))
+            .and(predicate::str::contains("looks like a catch-all"))
+            .and(predicate::str::contains("--filter-similar-to")),
+    );
+
+    // the startup probe + the initial directory's wildcard test
+    assert_eq!(mock.hits(), 2);
+    Ok(())
+}
+
+#[test]
+/// --ignore-wildcard-warning should skip the startup probe entirely
+fn heuristics_catch_all_target_with_ignore_wildcard_warning(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) = setup_tmp_directory(&["LICENSE".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("^/[a-zA-Z0-9]{32}$").unwrap());
+        then.status(200).body("catch all");
+    });
+
+    let cmd = Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--ignore-wildcard-warning")
+        .unwrap();
+
+    teardown_tmp_directory(tmp_dir);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("looks like a catch-all").not());
+
+    // only the initial directory's wildcard test
+    assert_eq!(mock.hits(), 1);
+    Ok(())
+}
+
 #[test]
 /// uses dont_filter, so the normal wildcard test should never happen
 fn heuristics_static_wildcard_request_with_dont_filter() -> Result<(), Box<dyn std::error::Error>> {
@@ -153,3 +153,72 @@ fn main_parallel_spawns_children() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+/// --dry-run should print each planned url to stdout and exit without sending any requests, not
+/// even the connectivity test
+fn main_dry_run_prints_urls_without_requesting_them() -> Result<(), Box<dyn std::error::Error>> {
+    let srv = MockServer::start();
+    let (tmp_dir, file) =
+        setup_tmp_directory(&["LICENSE".to_string(), "admin".to_string()], "wordlist")?;
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET).path_matches(Regex::new(".*").unwrap());
+        then.status(200).body("this is a test");
+    });
+
+    let expected = format!(
+        "{url}LICENSE\n{url}LICENSE.php\n{url}admin\n{url}admin.php\n",
+        url = srv.url("/")
+    );
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--extensions")
+        .arg("php")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stdout(predicate::eq(expected.as_str()))
+        .stderr(predicate::str::contains("Target Url").not());
+
+    assert_eq!(mock.hits(), 0);
+
+    teardown_tmp_directory(tmp_dir);
+    Ok(())
+}
+
+#[test]
+/// --json without anywhere to send the json (--output, --debug-log, or --silent) is an error
+fn main_json_requires_output_or_silent() {
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg("http://localhost")
+        .arg("--json")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--json requires --output, --debug-log, or --silent",
+        ));
+}
+
+#[test]
+/// --output-format csv without --output is an error, there's nowhere to write the rows
+fn main_output_format_csv_requires_output() {
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg("http://localhost")
+        .arg("--output-format")
+        .arg("csv")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--output-format csv requires --output",
+        ));
+}
@@ -430,3 +430,74 @@ fn auto_tune_slows_scan_with_general_errors() {
     assert!(error_mock.hits() <= 180); // may or may not see all other error requests
     assert!(start.elapsed().as_millis() >= 7000); // scan should hit time limit due to limiting
 }
+
+#[test]
+/// --auto-tune should lower the number of concurrent requests when the target responds w/ 429s
+/// and raise it when the target's responses are healthy, logging each adjustment
+fn auto_tune_adjusts_concurrency() {
+    let srv = MockServer::start();
+    let words: Vec<_> = (0..40).map(|i| format!("word{}", i)).collect();
+    let (tmp_dir, file) = setup_tmp_directory(&words, "wordlist").unwrap();
+    let (log_dir, logfile) = setup_tmp_directory(&[], "debug-log").unwrap();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("/word[0-9]+$").unwrap());
+        then.status(429).body("slow down");
+    });
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--auto-tune")
+        .arg("--threads")
+        .arg("4")
+        .arg("--debug-log")
+        .arg(logfile.as_os_str())
+        .arg("-vvvv")
+        .assert()
+        .success();
+
+    let debug_log = read_to_string(&logfile).unwrap();
+    assert!(debug_log.contains("auto-tune: lowered concurrency for"));
+    assert!(debug_log.contains("from 4 to 2 (10 of 10 responses were errors/429s/503s)"));
+    assert!(!debug_log.contains("auto-tune: raised concurrency"));
+    assert_eq!(mock.hits(), 40);
+
+    let srv = MockServer::start();
+
+    let mock = srv.mock(|when, then| {
+        when.method(GET)
+            .path_matches(Regex::new("/word[0-9]+$").unwrap());
+        then.status(200).body("all good");
+    });
+
+    write(&logfile, "").unwrap();
+
+    Command::cargo_bin("feroxbuster")
+        .unwrap()
+        .arg("--url")
+        .arg(srv.url("/"))
+        .arg("--wordlist")
+        .arg(file.as_os_str())
+        .arg("--auto-tune")
+        .arg("--threads")
+        .arg("4")
+        .arg("--debug-log")
+        .arg(logfile.as_os_str())
+        .arg("-vvvv")
+        .assert()
+        .success();
+
+    let debug_log = read_to_string(&logfile).unwrap();
+    assert!(debug_log.contains("auto-tune: raised concurrency for"));
+    assert!(debug_log.contains("from 4 to 5 (0 of 10 responses were errors/429s/503s)"));
+    assert!(!debug_log.contains("auto-tune: lowered concurrency"));
+    assert_eq!(mock.hits(), 40);
+
+    teardown_tmp_directory(tmp_dir);
+    teardown_tmp_directory(log_dir);
+}